@@ -1,38 +1,80 @@
 use crate::{
     books::{
         map::{OrderBookMap, OrderBookMapMulti},
-        OrderBook,
+        Level, OrderBook,
     },
     error::DataError,
+    event::MarketEvent,
     exchange::StreamSelector,
     instrument::InstrumentData,
     streams::{consumer::MarketStreamEvent, reconnect::stream::ReconnectingStream, Streams},
     subscription::{
-        book::{OrderBookEvent, OrderBooksL2},
+        book::{OrderBookEvent, OrderBookL1, OrderBooksL2},
         Subscription,
     },
     Identifier,
 };
+use barter_integration::channel::{mpsc_unbounded, ChannelTxDroppable, UnboundedRx, UnboundedTx};
+use chrono::{DateTime, Utc};
 use fnv::FnvHashMap;
 use futures::Stream;
 use futures_util::StreamExt;
-use parking_lot::RwLock;
-use std::{fmt::Debug, hash::Hash, sync::Arc};
+use parking_lot::{RwLock, RwLockWriteGuard};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, hash::Hash, sync::Arc, time::Duration};
 use tracing::warn;
 
 /// Maintains a set of local L2 [`OrderBook`]s by applying streamed [`OrderBookEvent`]s to the
 /// associated [`OrderBook`] in the [`OrderBookMap`].
+///
+/// Optionally publishes derived [`OrderBookNotification`]s after each update if configured
+/// (see [`Self::with_notifications`]).
 #[derive(Debug)]
-pub struct OrderBookL2Manager<St, BookMap> {
+pub struct OrderBookL2Manager<St, BookMap, Notifier = ()> {
     pub stream: St,
     pub books: BookMap,
+    notifier: Notifier,
+}
+
+impl<St, BookMap> OrderBookL2Manager<St, BookMap> {
+    /// Construct a new [`OrderBookL2Manager`] that does not publish any [`OrderBookNotification`]s.
+    pub fn new(stream: St, books: BookMap) -> Self {
+        Self {
+            stream,
+            books,
+            notifier: (),
+        }
+    }
+
+    /// Configure the [`OrderBookL2Manager`] to publish [`OrderBookNotification`]s after each
+    /// [`OrderBook`] update, returning the [`UnboundedRx`] they can be consumed from.
+    #[allow(clippy::type_complexity)]
+    pub fn with_notifications(
+        self,
+        config: OrderBookNotifierConfig,
+    ) -> (
+        OrderBookL2Manager<St, BookMap, OrderBookNotifier<BookMap::Key>>,
+        UnboundedRx<MarketEvent<BookMap::Key, OrderBookNotification>>,
+    )
+    where
+        BookMap: OrderBookMap,
+    {
+        let (tx, rx) = mpsc_unbounded();
+        let manager = OrderBookL2Manager {
+            stream: self.stream,
+            books: self.books,
+            notifier: OrderBookNotifier::new(config, tx),
+        };
+        (manager, rx)
+    }
 }
 
-impl<St, BookMap> OrderBookL2Manager<St, BookMap>
+impl<St, BookMap, Notifier> OrderBookL2Manager<St, BookMap, Notifier>
 where
     St: Stream<Item = MarketStreamEvent<BookMap::Key, OrderBookEvent>> + Unpin,
     BookMap: OrderBookMap,
     BookMap::Key: Debug,
+    Notifier: OrderBookUpdateNotifier<BookMap::Key>,
 {
     /// Manage local L2 [`OrderBook`]s.
     pub async fn run(mut self) {
@@ -55,12 +97,213 @@ where
                 continue;
             };
 
+            let MarketEvent {
+                time_exchange,
+                time_received,
+                exchange,
+                instrument,
+                kind,
+            } = event;
+
             let mut book_lock = book.write();
-            book_lock.update(event.kind);
+            book_lock.update(kind);
+
+            // Derive OrderBookNotifications while readers are allowed back in, and only publish
+            // them once the lock has been released
+            let book_lock = RwLockWriteGuard::downgrade(book_lock);
+            let pending = self.notifier.prepare(
+                MarketEvent {
+                    time_exchange,
+                    time_received,
+                    exchange,
+                    instrument,
+                    kind: (),
+                },
+                &book_lock,
+            );
+            drop(book_lock);
+
+            self.notifier.publish(pending);
         }
     }
 }
 
+/// Derives and publishes notifications from an [`OrderBook`] that has just been updated by an
+/// [`OrderBookL2Manager`].
+///
+/// Derivation is split from publishing so the [`OrderBook`] lock is not held while publishing.
+/// The `()` implementation publishes nothing.
+pub trait OrderBookUpdateNotifier<InstrumentKey> {
+    type Pending;
+
+    /// Derive any notifications due for the provided updated [`OrderBook`].
+    fn prepare(&mut self, meta: MarketEvent<InstrumentKey, ()>, book: &OrderBook) -> Self::Pending;
+
+    /// Publish notifications previously derived via [`Self::prepare`].
+    fn publish(&mut self, pending: Self::Pending);
+}
+
+impl<InstrumentKey> OrderBookUpdateNotifier<InstrumentKey> for () {
+    type Pending = ();
+
+    fn prepare(&mut self, _: MarketEvent<InstrumentKey, ()>, _: &OrderBook) -> Self::Pending {}
+
+    fn publish(&mut self, _: Self::Pending) {}
+}
+
+/// Derived [`OrderBook`] event published by an [`OrderBookL2Manager`] after an update.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum OrderBookNotification {
+    /// [`OrderBookL1`] synthesised from the best bid and ask of the L2 [`OrderBook`].
+    L1(OrderBookL1),
+
+    /// At least one side of the L2 [`OrderBook`] is empty, so no [`OrderBookL1`] can be
+    /// synthesised. Contains the best [`Level`] of each side, if any.
+    L1SideEmpty {
+        best_bid: Option<Level>,
+        best_ask: Option<Level>,
+    },
+
+    /// [`OrderBook`] snapshot with a maximum depth.
+    Snapshot(OrderBook),
+}
+
+/// Configures which [`OrderBookNotification`]s an [`OrderBookNotifier`] publishes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderBookNotifierConfig {
+    /// Publish [`OrderBookNotification::L1`]s, if configured.
+    pub l1: Option<L1NotificationMode>,
+
+    /// Publish throttled [`OrderBookNotification::Snapshot`]s, if configured.
+    pub snapshot: Option<SnapshotNotificationConfig>,
+}
+
+/// Determines when an [`OrderBookNotification::L1`] is published.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum L1NotificationMode {
+    /// Publish only when the best bid or best ask [`Level`] changes.
+    TopOfBookChange,
+
+    /// Publish after every [`OrderBook`] update.
+    EveryUpdate,
+}
+
+/// Configures the depth and throttle of published [`OrderBookNotification::Snapshot`]s.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct SnapshotNotificationConfig {
+    /// Maximum number of [`Level`]s on each side of the snapshot.
+    pub depth: usize,
+
+    /// Minimum duration between snapshots of the same instrument, measured using the
+    /// `time_received` of the [`MarketEvent`]s.
+    pub throttle: Duration,
+}
+
+/// Publishes [`OrderBookNotification`]s derived from updated [`OrderBook`]s, according to the
+/// provided [`OrderBookNotifierConfig`].
+#[derive(Debug)]
+pub struct OrderBookNotifier<InstrumentKey> {
+    pub config: OrderBookNotifierConfig,
+    tx: ChannelTxDroppable<UnboundedTx<MarketEvent<InstrumentKey, OrderBookNotification>>>,
+    states: FnvHashMap<InstrumentKey, NotifierState>,
+}
+
+/// Per instrument state used to determine if an [`OrderBookNotification`] should be published.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+struct NotifierState {
+    top_of_book: Option<(Option<Level>, Option<Level>)>,
+    last_snapshot: Option<DateTime<Utc>>,
+}
+
+/// [`OrderBookNotification`]s derived by an [`OrderBookNotifier`] that are pending publishing.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PendingOrderBookNotifications<InstrumentKey> {
+    pub l1: Option<MarketEvent<InstrumentKey, OrderBookNotification>>,
+    pub snapshot: Option<MarketEvent<InstrumentKey, OrderBookNotification>>,
+}
+
+impl<InstrumentKey> OrderBookNotifier<InstrumentKey> {
+    /// Construct a new [`OrderBookNotifier`] that publishes via the provided [`UnboundedTx`].
+    pub fn new(
+        config: OrderBookNotifierConfig,
+        tx: UnboundedTx<MarketEvent<InstrumentKey, OrderBookNotification>>,
+    ) -> Self {
+        Self {
+            config,
+            tx: ChannelTxDroppable::new(tx),
+            states: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<InstrumentKey> OrderBookNotifier<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash + Send,
+{
+    /// Publish any [`OrderBookNotification`]s due for the provided updated [`OrderBook`].
+    pub fn notify(&mut self, meta: MarketEvent<InstrumentKey, ()>, book: &OrderBook) {
+        let pending = self.prepare(meta, book);
+        self.publish(pending);
+    }
+}
+
+impl<InstrumentKey> OrderBookUpdateNotifier<InstrumentKey> for OrderBookNotifier<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash + Send,
+{
+    type Pending = PendingOrderBookNotifications<InstrumentKey>;
+
+    fn prepare(&mut self, meta: MarketEvent<InstrumentKey, ()>, book: &OrderBook) -> Self::Pending {
+        let state = self.states.entry(meta.instrument.clone()).or_default();
+
+        let l1 = self.config.l1.and_then(|mode| {
+            let (best_bid, best_ask) = (book.bids().best().copied(), book.asks().best().copied());
+            let top_of_book = Some((best_bid, best_ask));
+            let changed = state.top_of_book != top_of_book;
+            state.top_of_book = top_of_book;
+
+            (changed || mode == L1NotificationMode::EveryUpdate).then(|| {
+                meta.clone().map_kind(|_| match (best_bid, best_ask) {
+                    (Some(best_bid), Some(best_ask)) => OrderBookNotification::L1(OrderBookL1 {
+                        last_update_time: meta.time_exchange,
+                        best_bid,
+                        best_ask,
+                    }),
+                    (best_bid, best_ask) => {
+                        OrderBookNotification::L1SideEmpty { best_bid, best_ask }
+                    }
+                })
+            })
+        });
+
+        let snapshot =
+            self.config
+                .snapshot
+                .and_then(|SnapshotNotificationConfig { depth, throttle }| {
+                    let due = match state.last_snapshot {
+                        None => true,
+                        Some(last) => (meta.time_received - last)
+                            .to_std()
+                            .is_ok_and(|elapsed| elapsed >= throttle),
+                    };
+
+                    due.then(|| {
+                        state.last_snapshot = Some(meta.time_received);
+                        meta.map_kind(|_| OrderBookNotification::Snapshot(book.snapshot(depth)))
+                    })
+                });
+
+        PendingOrderBookNotifications { l1, snapshot }
+    }
+
+    fn publish(&mut self, pending: Self::Pending) {
+        let PendingOrderBookNotifications { l1, snapshot } = pending;
+        l1.into_iter()
+            .chain(snapshot)
+            .for_each(|notification| self.tx.send(notification));
+    }
+}
+
 /// Initialise a [`OrderBookL2Manager`] using the provided batches of [`OrderBooksL2`]
 /// [`Subscription`]s.
 ///
@@ -115,8 +358,211 @@ where
             )
         });
 
-    Ok(OrderBookL2Manager {
+    Ok(OrderBookL2Manager::new(
         stream,
-        books: OrderBookMapMulti::new(books),
-    })
+        OrderBookMapMulti::new(books),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+    use rust_decimal_macros::dec;
+
+    fn meta(time_received: DateTime<Utc>) -> MarketEvent<&'static str, ()> {
+        MarketEvent {
+            time_exchange: time_received,
+            time_received,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind: (),
+        }
+    }
+
+    fn book(best_bid: Level, best_ask: Level) -> OrderBook {
+        OrderBook::new(
+            0,
+            None,
+            vec![best_bid, Level::new(dec!(90), dec!(1))],
+            vec![best_ask, Level::new(dec!(110), dec!(1))],
+        )
+    }
+
+    fn drain<T>(rx: &mut UnboundedRx<T>) -> Vec<T> {
+        std::iter::from_fn(|| rx.rx.try_recv().ok()).collect()
+    }
+
+    #[test]
+    fn test_notify_l1_top_of_book_change() {
+        let (tx, mut rx) = mpsc_unbounded();
+        let mut notifier = OrderBookNotifier::new(
+            OrderBookNotifierConfig {
+                l1: Some(L1NotificationMode::TopOfBookChange),
+                snapshot: None,
+            },
+            tx,
+        );
+
+        let time = DateTime::<Utc>::MIN_UTC;
+        let best_bid = Level::new(dec!(99), dec!(1));
+        let best_ask = Level::new(dec!(101), dec!(1));
+
+        // First update always publishes
+        notifier.notify(meta(time), &book(best_bid, best_ask));
+        assert_eq!(
+            drain(&mut rx)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>(),
+            vec![OrderBookNotification::L1(OrderBookL1 {
+                last_update_time: time,
+                best_bid,
+                best_ask,
+            })]
+        );
+
+        // Unchanged top of book does not publish
+        notifier.notify(meta(time), &book(best_bid, best_ask));
+        assert!(drain(&mut rx).is_empty());
+
+        // Changed best bid amount publishes
+        let best_bid = Level::new(dec!(99), dec!(2));
+        notifier.notify(meta(time), &book(best_bid, best_ask));
+        assert_eq!(drain(&mut rx).len(), 1);
+    }
+
+    #[test]
+    fn test_notify_snapshot_throttle() {
+        let (tx, mut rx) = mpsc_unbounded();
+        let mut notifier = OrderBookNotifier::new(
+            OrderBookNotifierConfig {
+                l1: None,
+                snapshot: Some(SnapshotNotificationConfig {
+                    depth: 1,
+                    throttle: Duration::from_secs(1),
+                }),
+            },
+            tx,
+        );
+
+        let time = DateTime::<Utc>::MIN_UTC;
+        let book = book(
+            Level::new(dec!(99), dec!(1)),
+            Level::new(dec!(101), dec!(1)),
+        );
+
+        // First update always publishes, snapshot limited to configured depth
+        notifier.notify(meta(time), &book);
+        assert_eq!(
+            drain(&mut rx)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>(),
+            vec![OrderBookNotification::Snapshot(book.snapshot(1))]
+        );
+
+        // Update within the throttle does not publish
+        notifier.notify(meta(time + chrono::TimeDelta::milliseconds(500)), &book);
+        assert!(drain(&mut rx).is_empty());
+
+        // Update after the throttle publishes
+        notifier.notify(meta(time + chrono::TimeDelta::seconds(1)), &book);
+        assert_eq!(drain(&mut rx).len(), 1);
+    }
+
+    #[test]
+    fn test_notify_l1_side_empty() {
+        let (tx, mut rx) = mpsc_unbounded();
+        let mut notifier = OrderBookNotifier::new(
+            OrderBookNotifierConfig {
+                l1: Some(L1NotificationMode::TopOfBookChange),
+                snapshot: None,
+            },
+            tx,
+        );
+
+        let time = DateTime::<Utc>::MIN_UTC;
+        let best_bid = Level::new(dec!(99), dec!(1));
+        let best_ask = Level::new(dec!(101), dec!(1));
+
+        notifier.notify(meta(time), &book(best_bid, best_ask));
+        assert_eq!(drain(&mut rx).len(), 1);
+
+        // Asks side empties, so the stale top of book is replaced with an explicit empty state
+        let asks_empty = OrderBook::new(0, None, vec![best_bid], Vec::<Level>::new());
+        notifier.notify(meta(time), &asks_empty);
+        assert_eq!(
+            drain(&mut rx)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>(),
+            vec![OrderBookNotification::L1SideEmpty {
+                best_bid: Some(best_bid),
+                best_ask: None,
+            }]
+        );
+
+        // Unchanged empty side does not publish
+        notifier.notify(meta(time), &asks_empty);
+        assert!(drain(&mut rx).is_empty());
+
+        // Asks side repopulating with the previous best ask publishes an L1 again
+        notifier.notify(meta(time), &book(best_bid, best_ask));
+        assert_eq!(
+            drain(&mut rx)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>(),
+            vec![OrderBookNotification::L1(OrderBookL1 {
+                last_update_time: time,
+                best_bid,
+                best_ask,
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_manager_run_publishes_notifications() {
+        let time = DateTime::<Utc>::MIN_UTC;
+        let best_bid = Level::new(dec!(99), dec!(1));
+        let best_ask = Level::new(dec!(101), dec!(1));
+
+        let stream = futures::stream::iter(vec![MarketStreamEvent::Item(MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind: OrderBookEvent::Snapshot(book(best_bid, best_ask)),
+        })]);
+
+        let books = OrderBookMapMulti::new(FnvHashMap::from_iter([(
+            "btc_usdt",
+            Arc::new(RwLock::new(OrderBook::default())),
+        )]));
+
+        let (manager, mut rx) = OrderBookL2Manager::new(stream, books.clone()).with_notifications(
+            OrderBookNotifierConfig {
+                l1: Some(L1NotificationMode::EveryUpdate),
+                snapshot: None,
+            },
+        );
+        manager.run().await;
+
+        assert_eq!(
+            drain(&mut rx)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>(),
+            vec![OrderBookNotification::L1(OrderBookL1 {
+                last_update_time: time,
+                best_bid,
+                best_ask,
+            })]
+        );
+        assert_eq!(
+            books.find(&"btc_usdt").unwrap().read().bids().best(),
+            Some(&best_bid)
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use crate::subscription::book::OrderBookEvent;
+use barter_integration::Side;
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use rust_decimal::Decimal;
@@ -116,6 +117,65 @@ impl OrderBook {
             (None, None) => None,
         }
     }
+
+    /// Calculate the absolute spread between the best ask and best bid prices.
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.asks.best()?.price - self.bids.best()?.price)
+    }
+
+    /// Calculate the spread between the best ask and best bid prices in basis points of the
+    /// mid-price.
+    pub fn spread_bps(&self) -> Option<Decimal> {
+        let (best_bid, best_ask) = (self.bids.best()?, self.asks.best()?);
+        bps(
+            best_ask.price - best_bid.price,
+            mid_price(best_bid.price, best_ask.price),
+        )
+    }
+
+    /// Calculate the cumulative bid and ask amount available within `bps` basis points of the
+    /// mid-price.
+    pub fn depth_within_bps(&self, bps: Decimal) -> Option<BookDepth> {
+        let mid_price = self.mid_price()?;
+        Some(BookDepth {
+            bids: self.bids.amount_within_bps(mid_price, bps),
+            asks: self.asks.amount_within_bps(mid_price, bps),
+        })
+    }
+
+    /// Calculate the bid/ask amount imbalance across the best `depth` [`Level`]s of each side.
+    ///
+    /// Result is in the range [-1, 1], where positive values indicate more bid amount than ask
+    /// amount. Returns `None` if there is no amount on either side.
+    pub fn imbalance(&self, depth: usize) -> Option<Decimal> {
+        let bids = self.bids.amount_to_depth(depth);
+        let asks = self.asks.amount_to_depth(depth);
+        let total = bids + asks;
+        (!total.is_zero()).then(|| (bids - asks) / total)
+    }
+
+    /// Calculate the impact cost in basis points of the mid-price incurred by immediately
+    /// executing the provided quote `notional` against this [`OrderBook`].
+    ///
+    /// A [`Side::Buy`] consumes the asks, and a [`Side::Sell`] consumes the bids. Returns `None`
+    /// if the [`OrderBook`] is empty, or does not have enough liquidity to fill the `notional`.
+    pub fn impact_cost_bps(&self, side: Side, notional: Decimal) -> Option<Decimal> {
+        let mid_price = self.mid_price()?;
+        let fill_price = match side {
+            Side::Buy => self.asks.average_fill_price_for_notional(notional)?,
+            Side::Sell => self.bids.average_fill_price_for_notional(notional)?,
+        };
+        bps((fill_price - mid_price).abs(), mid_price)
+    }
+}
+
+/// Cumulative bid and ask amount available in an [`OrderBook`].
+///
+/// See [`OrderBook::depth_within_bps`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct BookDepth {
+    pub bids: Decimal,
+    pub asks: Decimal,
 }
 
 /// Normalised Barter [`Level`]s for one [`Side`] of the [`OrderBook`].
@@ -179,7 +239,7 @@ impl OrderBookSide<Asks> {
         L: Into<Level>,
     {
         let mut levels = levels.into_iter().map(L::into).collect::<Vec<_>>();
-        levels.sort_unstable_by(|a, b| a.price.cmp(&b.price));
+        levels.sort_unstable_by_key(|level| level.price);
 
         Self { side: Asks, levels }
     }
@@ -206,6 +266,105 @@ where
         &self.levels
     }
 
+    /// Return a reference to the best [`Level`] of this [`OrderBookSide`], if any.
+    pub fn best(&self) -> Option<&Level> {
+        self.levels.first()
+    }
+
+    /// Calculate the cumulative amount across the best `depth` [`Level`]s.
+    pub fn amount_to_depth(&self, depth: usize) -> Decimal {
+        self.levels
+            .iter()
+            .take(depth)
+            .map(|level| level.amount)
+            .sum()
+    }
+
+    /// Calculate the cumulative amount across all [`Level`]s priced within `bps` basis points of
+    /// the provided `reference_price`.
+    pub fn amount_within_bps(&self, reference_price: Decimal, bps: Decimal) -> Decimal {
+        let max_distance = reference_price.abs() * bps / BPS_PER_UNIT;
+        self.levels
+            .iter()
+            .take_while(|level| (level.price - reference_price).abs() <= max_distance)
+            .map(|level| level.amount)
+            .sum()
+    }
+
+    /// Calculate the volume weighted average price across the best `depth` [`Level`]s.
+    ///
+    /// Returns `None` if the [`Level`]s considered have no amount.
+    pub fn vwap(&self, depth: usize) -> Option<Decimal> {
+        let (notional, amount) = self.levels.iter().take(depth).fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(notional, amount), level| {
+                (notional + level.price * level.amount, amount + level.amount)
+            },
+        );
+
+        (!amount.is_zero()).then(|| notional / amount)
+    }
+
+    /// Calculate the average price achieved by immediately filling the provided `amount` against
+    /// this [`OrderBookSide`].
+    ///
+    /// Returns `None` if the `amount` is not positive, or there is not enough liquidity to fill it.
+    pub fn average_fill_price(&self, amount: Decimal) -> Option<Decimal> {
+        if amount <= Decimal::ZERO {
+            return None;
+        }
+
+        let mut remaining = amount;
+        let mut notional = Decimal::ZERO;
+        for level in &self.levels {
+            let filled = remaining.min(level.amount);
+            notional += filled * level.price;
+            remaining -= filled;
+
+            if remaining.is_zero() {
+                return Some(notional / amount);
+            }
+        }
+
+        None
+    }
+
+    /// Calculate the average price achieved by immediately spending the provided quote `notional`
+    /// against this [`OrderBookSide`].
+    ///
+    /// Returns `None` if the `notional` is not positive, or there is not enough liquidity to
+    /// fill it.
+    pub fn average_fill_price_for_notional(&self, notional: Decimal) -> Option<Decimal> {
+        if notional <= Decimal::ZERO {
+            return None;
+        }
+
+        let mut remaining = notional;
+        let mut amount = Decimal::ZERO;
+        for level in &self.levels {
+            let level_notional = level.price * level.amount;
+            if level_notional >= remaining {
+                amount += remaining / level.price;
+                return Some(notional / amount);
+            }
+
+            amount += level.amount;
+            remaining -= level_notional;
+        }
+
+        None
+    }
+
+    /// Calculate the slippage in basis points of the best [`Level`] price incurred by immediately
+    /// filling the provided `amount` against this [`OrderBookSide`].
+    ///
+    /// Returns `None` if there is not enough liquidity to fill the `amount`.
+    pub fn slippage_bps(&self, amount: Decimal) -> Option<Decimal> {
+        let best_price = self.best()?.price;
+        let fill_price = self.average_fill_price(amount)?;
+        bps((fill_price - best_price).abs(), best_price)
+    }
+
     /// Upsert a single [`Level`] into this [`OrderBookSide`].
     ///
     /// ### Upsert Scenarios
@@ -295,6 +454,16 @@ impl Level {
     }
 }
 
+/// Number of basis points in one unit (ie/ 100%).
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Express the provided `value` in basis points of the `reference` value.
+///
+/// Returns `None` if the `reference` is zero.
+fn bps(value: Decimal, reference: Decimal) -> Option<Decimal> {
+    (!reference.is_zero()).then(|| value / reference * BPS_PER_UNIT)
+}
+
 /// Calculate the mid-price by taking the average of the best bid and ask prices.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
@@ -566,6 +735,175 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_spread_bps() {
+            struct TestCase {
+                input: OrderBook,
+                expected: Option<Decimal>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no asks in the books so no spread
+                    input: OrderBook::new(
+                        0,
+                        Default::default(),
+                        vec![Level::new(dec!(100.0), dec!(1.0))],
+                        vec![],
+                    ),
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: spread of 2 around a mid-price of 100
+                    input: OrderBook::new(
+                        0,
+                        Default::default(),
+                        vec![Level::new(dec!(99.0), dec!(1.0))],
+                        vec![Level::new(dec!(101.0), dec!(1.0))],
+                    ),
+                    expected: Some(dec!(200)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(test.input.spread_bps(), test.expected, "TC{index} failed")
+            }
+        }
+
+        #[test]
+        fn test_imbalance() {
+            struct TestCase {
+                input: OrderBook,
+                depth: usize,
+                expected: Option<Decimal>,
+            }
+
+            let book = OrderBook::new(
+                0,
+                Default::default(),
+                vec![
+                    Level::new(dec!(99.0), dec!(3.0)),
+                    Level::new(dec!(98.0), dec!(5.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(102.0), dec!(1.0)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no levels so no imbalance
+                    input: OrderBook::default(),
+                    depth: 5,
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: best level only
+                    input: book.clone(),
+                    depth: 1,
+                    expected: Some(dec!(0.5)),
+                },
+                TestCase {
+                    // TC2: depth greater than number of levels
+                    input: book,
+                    depth: 10,
+                    expected: Some(dec!(0.6)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.imbalance(test.depth),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_depth_within_bps() {
+            let book = OrderBook::new(
+                0,
+                Default::default(),
+                vec![
+                    Level::new(dec!(99.0), dec!(1.0)),
+                    Level::new(dec!(98.0), dec!(2.0)),
+                    Level::new(dec!(90.0), dec!(4.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(110.0), dec!(2.0)),
+                ],
+            );
+
+            assert_eq!(
+                book.depth_within_bps(dec!(200)),
+                Some(BookDepth {
+                    bids: dec!(3.0),
+                    asks: dec!(1.0),
+                })
+            );
+            assert_eq!(OrderBook::default().depth_within_bps(dec!(200)), None);
+        }
+
+        #[test]
+        fn test_impact_cost_bps() {
+            struct TestCase {
+                side: Side,
+                notional: Decimal,
+                expected: Option<Decimal>,
+            }
+
+            let book = OrderBook::new(
+                0,
+                Default::default(),
+                vec![
+                    Level::new(dec!(99.0), dec!(1.0)),
+                    Level::new(dec!(98.0), dec!(1.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(102.0), dec!(1.0)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: buy filled entirely at the best ask
+                    side: Side::Buy,
+                    notional: dec!(101.0),
+                    expected: Some(dec!(100)),
+                },
+                TestCase {
+                    // TC1: sell filled entirely at the best bid
+                    side: Side::Sell,
+                    notional: dec!(99.0),
+                    expected: Some(dec!(100)),
+                },
+                TestCase {
+                    // TC2: buy walks both ask levels
+                    side: Side::Buy,
+                    notional: dec!(203.0),
+                    expected: Some(dec!(150)),
+                },
+                TestCase {
+                    // TC3: not enough liquidity to fill the notional
+                    side: Side::Buy,
+                    notional: dec!(1000.0),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    book.impact_cost_bps(test.side, test.notional),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
     }
 
     mod order_book_side {
@@ -646,5 +984,76 @@ mod tests {
                 assert_eq!(test.book_side, test.expected, "TC{} failed", index);
             }
         }
+
+        #[test]
+        fn test_vwap() {
+            let side = OrderBookSide::asks(vec![
+                Level::new(dec!(100), dec!(1)),
+                Level::new(dec!(110), dec!(3)),
+                Level::new(dec!(120), dec!(6)),
+            ]);
+
+            assert_eq!(side.vwap(0), None);
+            assert_eq!(side.vwap(1), Some(dec!(100)));
+            assert_eq!(side.vwap(2), Some(dec!(107.5)));
+            assert_eq!(side.vwap(10), Some(dec!(115)));
+        }
+
+        #[test]
+        fn test_average_fill_price() {
+            struct TestCase {
+                amount: Decimal,
+                expected: Option<Decimal>,
+            }
+
+            let side = OrderBookSide::bids(vec![
+                Level::new(dec!(100), dec!(1)),
+                Level::new(dec!(90), dec!(1)),
+                Level::new(dec!(80), dec!(2)),
+            ]);
+
+            let tests = vec![
+                TestCase {
+                    // TC0: zero amount cannot be filled
+                    amount: dec!(0),
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: partial fill of the best level
+                    amount: dec!(0.5),
+                    expected: Some(dec!(100)),
+                },
+                TestCase {
+                    // TC2: fill walks multiple levels
+                    amount: dec!(3),
+                    expected: Some(dec!(90)),
+                },
+                TestCase {
+                    // TC3: not enough liquidity
+                    amount: dec!(5),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    side.average_fill_price(test.amount),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_slippage_bps() {
+            let side = OrderBookSide::asks(vec![
+                Level::new(dec!(100), dec!(1)),
+                Level::new(dec!(102), dec!(1)),
+            ]);
+
+            assert_eq!(side.slippage_bps(dec!(1)), Some(dec!(0)));
+            assert_eq!(side.slippage_bps(dec!(2)), Some(dec!(100)));
+            assert_eq!(side.slippage_bps(dec!(3)), None);
+        }
     }
 }
@@ -21,6 +21,21 @@ pub enum DataError {
     #[error("SocketError: {0}")]
     Socket(String),
 
+    #[error("ExchangeTransformer does not support runtime instrument Map updates")]
+    InstrumentMapUpdateUnsupported,
+
+    #[error("exchange does not support runtime unsubscriptions: {0}")]
+    UnsubscribeUnsupported(ExchangeId),
+
+    #[error("runtime subscription already in progress for this connection")]
+    SubscriptionInProgress,
+
+    #[error("no active connection for exchange: {exchange}, kind: {sub_kind}")]
+    ConnectionNotFound {
+        exchange: ExchangeId,
+        sub_kind: SubKind,
+    },
+
     #[error("unsupported dynamic Subscription for exchange: {exchange}, kind: {sub_kind}")]
     Unsupported {
         exchange: ExchangeId,
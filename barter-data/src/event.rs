@@ -5,6 +5,7 @@ use crate::{
         book::{OrderBookEvent, OrderBookL1},
         candle::Candle,
         liquidation::Liquidation,
+        ticker::Ticker,
         trade::PublicTrade,
     },
 };
@@ -80,6 +81,7 @@ pub enum DataKind {
     OrderBook(OrderBookEvent),
     Candle(Candle),
     Liquidation(Liquidation),
+    Ticker(Ticker),
 }
 
 impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, PublicTrade>>
@@ -161,3 +163,19 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, Liquidation>>
         value.map_kind(Liquidation::into)
     }
 }
+
+impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, Ticker>>
+    for MarketStreamResult<InstrumentKey, DataKind>
+{
+    fn from(value: MarketStreamResult<InstrumentKey, Ticker>) -> Self {
+        value.map_ok(MarketEvent::from)
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, Ticker>>
+    for MarketEvent<InstrumentKey, DataKind>
+{
+    fn from(value: MarketEvent<InstrumentKey, Ticker>) -> Self {
+        value.map_kind(Ticker::into)
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     subscription::{
         book::{OrderBooksL1, OrderBooksL2},
         liquidation::Liquidations,
+        ticker::Tickers,
         trade::PublicTrades,
         Subscription,
     },
@@ -46,6 +47,12 @@ impl BinanceChannel {
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`Binance`] rolling window 24h ticker channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+    pub const TICKERS: Self = Self("@ticker");
 }
 
 impl<Server, Instrument> Identifier<BinanceChannel>
@@ -80,6 +87,14 @@ impl<Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, Tickers>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,18 +1,21 @@
 use self::{
     book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    subscription::BinanceSubResponse, ticker::BinanceTicker, trade::BinanceTrade,
 };
 use crate::{
-    exchange::{Connector, ExchangeServer, ExchangeSub, StreamSelector},
+    exchange::{ConnectionLimits, Connector, ExchangeServer, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
+    subscription::{book::OrderBooksL1, ticker::Tickers, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, num::NonZeroU32};
 use url::Url;
 
 /// OrderBook types common to both [`BinanceSpot`](spot::BinanceSpot) and
@@ -40,6 +43,10 @@ pub mod spot;
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod subscription;
 
+/// Ticker types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod ticker;
+
 /// Public trade types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
@@ -94,8 +101,60 @@ where
         )]
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        let stream_names = exchange_subs
+            .into_iter()
+            .map(|sub| {
+                format!(
+                    "{}{}",
+                    sub.market.as_ref().to_lowercase(),
+                    sub.channel.as_ref()
+                )
+            })
+            .collect::<Vec<String>>();
+
+        Some(vec![WsMessage::Text(
+            serde_json::json!({
+                "method": "UNSUBSCRIBE",
+                "params": stream_names,
+                "id": 1
+            })
+            .to_string(),
+        )])
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        // Unsubscribe responses share the format of subscription responses
+        validate_response_message::<BinanceSubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscribe request
+        Self::connection_limits().num_requests(num_subscriptions)
+    }
+
+    fn connection_limits() -> ConnectionLimits {
+        // See docs: <https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams>
+        // See docs: <https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams>
+        match Server::ID {
+            ExchangeId::BinanceFuturesUsd => ConnectionLimits {
+                max_subscriptions: Some(200),
+                max_subscriptions_per_request: None,
+                max_messages_per_second: NonZeroU32::new(10),
+            },
+            _ => ConnectionLimits {
+                max_subscriptions: Some(1024),
+                max_subscriptions_per_request: None,
+                max_messages_per_second: NonZeroU32::new(5),
+            },
+        }
+    }
+
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        // One response per subscription request
+        Self::connection_limits().num_requests(map.0.len())
     }
 }
 
@@ -120,6 +179,16 @@ where
     >;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, Tickers> for Binance<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, Tickers, BinanceTicker>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
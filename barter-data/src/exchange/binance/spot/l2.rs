@@ -16,7 +16,7 @@ use crate::{
         book::{OrderBookEvent, OrderBooksL2},
         Map, Subscription,
     },
-    transformer::ExchangeTransformer,
+    transformer::{ExchangeTransformer, InstrumentMapUpdate, UpdateInstrumentMap},
     Identifier, SnapshotFetcher,
 };
 use async_trait::async_trait;
@@ -120,6 +120,30 @@ where
     }
 }
 
+impl<InstrumentKey> UpdateInstrumentMap for BinanceSpotOrderBooksL2Transformer<InstrumentKey> {
+    type InstrumentKey = InstrumentKey;
+
+    // New OrderBooksL2 subscriptions require an initial snapshot to sequence updates
+    fn insert_supported() -> bool {
+        false
+    }
+
+    fn update_instrument_map(
+        &mut self,
+        update: InstrumentMapUpdate<Self::InstrumentKey>,
+    ) -> Result<(), DataError> {
+        match update {
+            InstrumentMapUpdate::Insert(_) => Err(DataError::InstrumentMapUpdateUnsupported),
+            InstrumentMapUpdate::Remove(subscription_ids) => {
+                for subscription_id in subscription_ids {
+                    self.instrument_map.0.remove(&subscription_id);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl<InstrumentKey> Transformer for BinanceSpotOrderBooksL2Transformer<InstrumentKey>
 where
     InstrumentKey: Clone,
@@ -0,0 +1,223 @@
+use super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Binance rolling window 24h ticker message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+/// #### Spot Ticker
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "x": "0.0009",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "b": "0.0024",
+///     "B": "10",
+///     "a": "0.0026",
+///     "A": "100",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+///
+/// #### FuturePerpetual Ticker
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 123456789,
+///     "s": "BTCUSDT",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(alias = "s", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quote_volume: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceTicker)>
+    for MarketIter<InstrumentKey, Ticker>
+{
+    fn from((exchange_id, instrument, ticker): (ExchangeId, InstrumentKey, BinanceTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: ticker.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: Ticker {
+                last_price: ticker.last_price,
+                volume_24h: ticker.volume,
+                quote_volume_24h: Some(ticker.quote_volume),
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                mark_price: None,
+                index_price: None,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@ticker|BTCUSDT").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::TICKERS, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Spot ticker valid
+                    input: r#"
+                    {
+                        "e":"24hrTicker","E":1672515782136,"s":"BNBBTC","p":"0.0015",
+                        "P":"250.00","w":"0.0018","x":"0.0009","c":"0.0025","Q":"10",
+                        "b":"0.0024","B":"10","a":"0.0026","A":"100","o":"0.0010",
+                        "h":"0.0025","l":"0.0010","v":"10000","q":"18","O":0,
+                        "C":86400000,"F":0,"L":18150,"n":18151
+                    }
+                    "#,
+                    expected: Ok(BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last_price: 0.0025,
+                        high: 0.0025,
+                        low: 0.0010,
+                        volume: 10000.0,
+                        quote_volume: 18.0,
+                    }),
+                },
+                TestCase {
+                    // TC1: FuturePerpetual ticker valid
+                    input: r#"
+                    {
+                        "e":"24hrTicker","E":123456789,"s":"BTCUSDT","p":"0.0015",
+                        "P":"250.00","w":"0.0018","c":"0.0025","Q":"10","o":"0.0010",
+                        "h":"0.0025","l":"0.0010","v":"10000","q":"18","O":0,
+                        "C":86400000,"F":0,"L":18150,"n":18151
+                    }
+                    "#,
+                    expected: Ok(BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(123456789)),
+                        last_price: 0.0025,
+                        high: 0.0025,
+                        low: 0.0010,
+                        volume: 10000.0,
+                        quote_volume: 18.0,
+                    }),
+                },
+                TestCase {
+                    // TC2: Ticker malformed w/ non-numeric last price
+                    input: r#"
+                    {
+                        "e":"24hrTicker","E":123456789,"s":"BTCUSDT","c":"last",
+                        "h":"0.0025","l":"0.0010","v":"10000","q":"18"
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
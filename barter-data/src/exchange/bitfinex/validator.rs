@@ -142,4 +142,21 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
             }
         }
     }
+
+    /// Runtime subscriptions are rejected since the [`SubscriptionId`] of each actioned
+    /// subscription must be replaced with the [`BitfinexChannelId`](super::subscription::BitfinexChannelId)
+    /// contained in the response, which is not possible once the connection is running.
+    fn validate_response<Exchange>(message: WsMessage) -> Option<Result<(), SocketError>>
+    where
+        Exchange: Connector,
+    {
+        match Self::Parser::parse::<BitfinexPlatformEvent>(Ok(message)) {
+            Some(Ok(BitfinexPlatformEvent::Subscribed(_) | BitfinexPlatformEvent::Error(_))) => {
+                Some(Err(SocketError::Subscribe(
+                    "Bitfinex does not support runtime subscriptions".to_string(),
+                )))
+            }
+            _ => None,
+        }
+    }
 }
@@ -1,14 +1,19 @@
 use crate::{
     exchange::{
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
+            channel::BitmexChannel,
+            market::BitmexMarket,
+            subscription::{BitmexSubResponse, BitmexUnsubResponse},
             trade::BitmexTrade,
         },
         subscription::ExchangeSub,
         Connector, StreamSelector,
     },
     instrument::InstrumentData,
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
     subscription::{trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
@@ -72,8 +77,35 @@ impl Connector for Bitmex {
         )]
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        let stream_names = exchange_subs
+            .into_iter()
+            .map(|sub| format!("{}:{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .collect::<Vec<String>>();
+
+        Some(vec![WsMessage::Text(
+            serde_json::json!({
+                "op": "unsubscribe",
+                "args": stream_names
+            })
+            .to_string(),
+        )])
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        validate_response_message::<BitmexUnsubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscribe request
+        Self::connection_limits().num_requests(num_subscriptions)
+    }
+
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        // One response per subscription request
+        Self::connection_limits().num_requests(map.0.len())
     }
 }
 
@@ -38,9 +38,48 @@ impl Validator for BitmexSubResponse {
     }
 }
 
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+/// #### Unsubscribe response payload
+/// ```json
+/// {
+///     "success": true,
+///     "unsubscribe": "trade:XBTUSD",
+///     "request": {
+///         "op":"unsubscribe",
+///         "args":[
+///             "trade:XBTUSD"
+///         ]
+///     }
+/// }
+///```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitmexUnsubResponse {
+    success: bool,
+    unsubscribe: String,
+}
+
+impl Validator for BitmexUnsubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure unsubscribe response for {} subscription",
+                self.unsubscribe
+            )))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscriber::validator::validate_response_message;
+    use barter_integration::protocol::websocket::WsMessage;
 
     mod de {
         use super::*;
@@ -136,4 +175,37 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_validate_bitmex_unsub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: successful unsubscribe
+                input: r#"{"success":true,"unsubscribe":"trade:XBTUSD","request":{"op":"unsubscribe","args":["trade:XBTUSD"]}}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: failed unsubscribe
+                input: r#"{"success":false,"unsubscribe":"trade:XBTUSD"}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: subscription response is not an unsubscribe response
+                input: r#"{"success":true,"subscribe":"trade:XBTUSD"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                validate_response_message::<BitmexUnsubResponse>(WsMessage::text(test.input))
+                    .map(|outcome| outcome.is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
 }
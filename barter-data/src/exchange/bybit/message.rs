@@ -74,6 +74,10 @@ where
             "{}|{market}",
             BybitChannel::TRADES.0
         ))),
+        (Some("tickers"), Some(market), None) => Ok(SubscriptionId::from(format!(
+            "{}|{market}",
+            BybitChannel::TICKERS.0
+        ))),
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
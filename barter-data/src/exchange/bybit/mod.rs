@@ -1,15 +1,21 @@
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
-            subscription::BybitResponse,
+            channel::BybitChannel,
+            market::BybitMarket,
+            message::BybitMessage,
+            subscription::{BybitResponse, BybitUnsubResponse},
+            ticker::BybitTickersTransformer,
         },
         subscription::ExchangeSub,
-        Connector, ExchangeServer, PingInterval, StreamSelector,
+        ConnectionLimits, Connector, ExchangeServer, PingInterval, StreamSelector,
     },
     instrument::InstrumentData,
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
+    subscription::{ticker::Tickers, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -45,6 +51,10 @@ pub mod spot;
 /// and [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod subscription;
 
+/// Ticker types and the stateful [`BybitTickersTransformer`] common to both
+/// [`BybitSpot`](spot::BybitSpot) and [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod ticker;
+
 /// Public trade types common to both [`BybitSpot`](spot::BybitSpot) and
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod trade;
@@ -103,8 +113,47 @@ where
         )]
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        let stream_names = exchange_subs
+            .into_iter()
+            .map(|sub| format!("{}.{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .collect::<Vec<String>>();
+
+        Some(vec![WsMessage::Text(
+            serde_json::json!({
+                "op": "unsubscribe",
+                "args": stream_names
+            })
+            .to_string(),
+        )])
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        validate_response_message::<BybitUnsubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscribe request
+        Self::connection_limits().num_requests(num_subscriptions)
+    }
+
+    fn connection_limits() -> ConnectionLimits {
+        // See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+        match Server::ID {
+            ExchangeId::BybitSpot => ConnectionLimits {
+                max_subscriptions: None,
+                max_subscriptions_per_request: Some(10),
+                max_messages_per_second: None,
+            },
+            _ => ConnectionLimits::UNLIMITED,
+        }
+    }
+
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        // One response per subscription request
+        Self::connection_limits().num_requests(map.0.len())
     }
 }
 
@@ -118,6 +167,15 @@ where
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, BybitMessage>>;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, Tickers> for Bybit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<BybitTickersTransformer<Server, Instrument::Key>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
 where
     Server: ExchangeServer,
@@ -69,9 +69,45 @@ impl Validator for BybitResponse {
     }
 }
 
+/// [`Bybit`](super::Bybit) unsubscribe response message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-subscribe-to-topics>
+/// #### Unsubscribe Success
+/// ```json
+/// {
+///     "success": true,
+///     "ret_msg": "",
+///     "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+///     "req_id": "",
+///     "op": "unsubscribe"
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum BybitUnsubResponse {
+    Unsubscribe { success: bool },
+}
+
+impl Validator for BybitUnsubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::Unsubscribe { success: true } => Ok(self),
+            Self::Unsubscribe { success: false } => Err(SocketError::Subscribe(
+                "received failure unsubscribe response".to_owned(),
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscriber::validator::validate_response_message;
+    use barter_integration::protocol::websocket::WsMessage;
 
     mod de {
         use super::*;
@@ -173,4 +209,37 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_validate_bybit_unsub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: successful unsubscribe
+                input: r#"{"success":true,"ret_msg":"","conn_id":"id","req_id":"","op":"unsubscribe"}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: failed unsubscribe
+                input: r#"{"success":false,"ret_msg":"","conn_id":"id","req_id":"","op":"unsubscribe"}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: subscription response is not an unsubscribe response
+                input: r#"{"success":true,"ret_msg":"subscribe","conn_id":"id","req_id":"","op":"subscribe"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                validate_response_message::<BybitUnsubResponse>(WsMessage::text(test.input))
+                    .map(|outcome| outcome.is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
 }
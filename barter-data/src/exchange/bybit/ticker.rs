@@ -0,0 +1,394 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{
+        bybit::{message::BybitPayload, subscription::BybitResponse, Bybit},
+        Connector, ExchangeServer,
+    },
+    subscription::{
+        ticker::{Ticker, Tickers},
+        Map,
+    },
+    transformer::{ExchangeTransformer, InstrumentMapUpdate, UpdateInstrumentMap},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    protocol::websocket::WsMessage, subscription::SubscriptionId, Transformer,
+};
+use chrono::{DateTime, Utc};
+use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// Terse type alias for an [`Bybit`] real-time ticker WebSocket message.
+pub type BybitTicker = BybitPayload<BybitTickerInner>;
+
+/// [`Bybit`] ticker stream message, supporting both [`BybitTicker`] and [`BybitResponse`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BybitTickerMessage {
+    Response(BybitResponse),
+    Ticker(BybitTicker),
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitTickerMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitTickerMessage::Ticker(ticker) => Some(ticker.subscription_id.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// [`Bybit`] ticker data.
+///
+/// [`BybitSpot`](super::spot::BybitSpot) only sends "snapshot" messages, whereas
+/// [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd) sends an initial "snapshot"
+/// followed by "delta" messages that only contain the fields that have changed. Every field is
+/// therefore optional, and deltas are merged into the last known state by the
+/// [`BybitTickersTransformer`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+/// #### Spot Snapshot
+/// ```json
+/// {
+///     "topic": "tickers.BTCUSDT",
+///     "ts": 1673853746003,
+///     "type": "snapshot",
+///     "cs": 2588407389,
+///     "data": {
+///         "symbol": "BTCUSDT",
+///         "lastPrice": "21109.77",
+///         "highPrice24h": "21426.99",
+///         "lowPrice24h": "20575",
+///         "prevPrice24h": "20704.93",
+///         "volume24h": "6780.866843",
+///         "turnover24h": "141946527.22907118",
+///         "price24hPcnt": "0.0196",
+///         "usdIndexPrice": "21120.2400136"
+///     }
+/// }
+/// ```
+///
+/// #### Perpetual Delta
+/// ```json
+/// {
+///     "topic": "tickers.BTCUSDT",
+///     "type": "delta",
+///     "data": {
+///         "symbol": "BTCUSDT",
+///         "markPrice": "17217.33",
+///         "indexPrice": "17227.36"
+///     },
+///     "cs": 24987956059,
+///     "ts": 1673272861686
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct BybitTickerInner {
+    #[serde(rename = "symbol")]
+    pub market: String,
+    #[serde(rename = "lastPrice", default, deserialize_with = "de_option_str_f64")]
+    pub last_price: Option<f64>,
+    #[serde(
+        rename = "highPrice24h",
+        default,
+        deserialize_with = "de_option_str_f64"
+    )]
+    pub high: Option<f64>,
+    #[serde(
+        rename = "lowPrice24h",
+        default,
+        deserialize_with = "de_option_str_f64"
+    )]
+    pub low: Option<f64>,
+    #[serde(rename = "volume24h", default, deserialize_with = "de_option_str_f64")]
+    pub volume: Option<f64>,
+    #[serde(
+        rename = "turnover24h",
+        default,
+        deserialize_with = "de_option_str_f64"
+    )]
+    pub turnover: Option<f64>,
+    #[serde(rename = "markPrice", default, deserialize_with = "de_option_str_f64")]
+    pub mark_price: Option<f64>,
+    #[serde(rename = "indexPrice", default, deserialize_with = "de_option_str_f64")]
+    pub index_price: Option<f64>,
+}
+
+impl BybitTickerInner {
+    /// Merge the fields present in a "delta" [`BybitTickerInner`] into [`Self`].
+    pub fn merge(&mut self, delta: Self) {
+        let Self {
+            market: _,
+            last_price,
+            high,
+            low,
+            volume,
+            turnover,
+            mark_price,
+            index_price,
+        } = delta;
+
+        self.last_price = last_price.or(self.last_price);
+        self.high = high.or(self.high);
+        self.low = low.or(self.low);
+        self.volume = volume.or(self.volume);
+        self.turnover = turnover.or(self.turnover);
+        self.mark_price = mark_price.or(self.mark_price);
+        self.index_price = index_price.or(self.index_price);
+    }
+
+    /// Construct a normalised [`Ticker`] if all of the mandatory fields are known.
+    pub fn to_ticker(&self, time: DateTime<Utc>) -> Option<Ticker> {
+        Some(Ticker {
+            last_price: self.last_price?,
+            volume_24h: self.volume?,
+            quote_volume_24h: self.turnover,
+            high_24h: self.high?,
+            low_24h: self.low?,
+            mark_price: self.mark_price,
+            index_price: self.index_price,
+            time,
+        })
+    }
+}
+
+/// Stateful [`ExchangeTransformer`] that merges [`Bybit`] ticker "delta" messages into the last
+/// known [`BybitTickerInner`] state for each subscription, yielding a complete [`Ticker`].
+#[derive(Debug)]
+pub struct BybitTickersTransformer<Server, InstrumentKey> {
+    instrument_map: Map<InstrumentKey>,
+    tickers: FnvHashMap<SubscriptionId, BybitTickerInner>,
+    phantom: PhantomData<Server>,
+}
+
+#[async_trait]
+impl<Server, InstrumentKey> ExchangeTransformer<Bybit<Server>, InstrumentKey, Tickers>
+    for BybitTickersTransformer<Server, InstrumentKey>
+where
+    Server: ExchangeServer + Send,
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, Ticker>],
+        _: mpsc::UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            tickers: FnvHashMap::default(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Server, InstrumentKey> UpdateInstrumentMap for BybitTickersTransformer<Server, InstrumentKey> {
+    type InstrumentKey = InstrumentKey;
+
+    fn update_instrument_map(
+        &mut self,
+        update: InstrumentMapUpdate<InstrumentKey>,
+    ) -> Result<(), DataError> {
+        match update {
+            InstrumentMapUpdate::Insert(map) => self.instrument_map.0.extend(map.0),
+            InstrumentMapUpdate::Remove(subscription_ids) => {
+                for subscription_id in subscription_ids {
+                    self.instrument_map.0.remove(&subscription_id);
+                    self.tickers.remove(&subscription_id);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Server, InstrumentKey> Transformer for BybitTickersTransformer<Server, InstrumentKey>
+where
+    Server: ExchangeServer,
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = BybitTickerMessage;
+    type Output = MarketEvent<InstrumentKey, Ticker>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let ticker = match input {
+            BybitTickerMessage::Ticker(ticker) => ticker,
+            BybitTickerMessage::Response(_) => return vec![],
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&ticker.subscription_id) {
+            Ok(instrument) => instrument.clone(),
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        // Replace state on "snapshot", otherwise merge "delta" into last known state
+        let state = self.tickers.entry(ticker.subscription_id).or_default();
+        if ticker.r#type == "snapshot" {
+            *state = ticker.data;
+        } else {
+            state.merge(ticker.data);
+        }
+
+        // Only yield once the mandatory Ticker fields are known
+        match state.to_ticker(ticker.time) {
+            Some(kind) => vec![Ok(MarketEvent {
+                time_exchange: ticker.time,
+                time_received: Utc::now(),
+                exchange: Bybit::<Server>::ID,
+                instrument,
+                kind,
+            })],
+            None => vec![],
+        }
+    }
+}
+
+/// Deserialize an optional `String` (eg/ "21109.77") as an `Option<f64>`.
+fn de_option_str_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bybit::futures::BybitPerpetualsUsd;
+    use barter_instrument::exchange::ExchangeId;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_bybit_ticker_message() {
+        let input = r#"
+        {
+            "topic": "tickers.BTCUSDT",
+            "ts": 1673853746003,
+            "type": "snapshot",
+            "cs": 2588407389,
+            "data": {
+                "symbol": "BTCUSDT",
+                "lastPrice": "21109.77",
+                "highPrice24h": "21426.99",
+                "lowPrice24h": "20575",
+                "prevPrice24h": "20704.93",
+                "volume24h": "6780.866843",
+                "turnover24h": "141946527.22907118",
+                "price24hPcnt": "0.0196",
+                "usdIndexPrice": "21120.2400136"
+            }
+        }
+        "#;
+
+        let BybitTickerMessage::Ticker(actual) =
+            serde_json::from_str::<BybitTickerMessage>(input).unwrap()
+        else {
+            panic!("expected BybitTickerMessage::Ticker");
+        };
+
+        assert_eq!(
+            actual.subscription_id,
+            SubscriptionId::from("tickers|BTCUSDT")
+        );
+        assert_eq!(
+            actual.data,
+            BybitTickerInner {
+                market: "BTCUSDT".to_string(),
+                last_price: Some(21109.77),
+                high: Some(21426.99),
+                low: Some(20575.0),
+                volume: Some(6780.866843),
+                turnover: Some(141946527.22907118),
+                mark_price: None,
+                index_price: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bybit_tickers_transformer_merges_deltas() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Ticker>,
+        }
+
+        let time = |ms| datetime_utc_from_epoch_duration(Duration::from_millis(ms));
+
+        let instrument_map = Map::from_iter([(SubscriptionId::from("tickers|BTCUSDT"), 1u64)]);
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = <BybitTickersTransformer<_, u64> as ExchangeTransformer<
+            BybitPerpetualsUsd,
+            u64,
+            Tickers,
+        >>::init(instrument_map, &[], ws_sink_tx)
+        .await
+        .unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: delta before any snapshot is missing mandatory fields
+                input: r#"{"topic":"tickers.BTCUSDT","type":"delta","ts":1,"cs":1,"data":{"symbol":"BTCUSDT","markPrice":"100.5"}}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC1: snapshot yields complete Ticker
+                input: r#"{"topic":"tickers.BTCUSDT","type":"snapshot","ts":2,"cs":2,"data":{"symbol":"BTCUSDT","lastPrice":"100.0","highPrice24h":"110.0","lowPrice24h":"90.0","volume24h":"5.0","turnover24h":"500.0","markPrice":"100.1","indexPrice":"100.2"}}"#,
+                expected: Some(Ticker {
+                    last_price: 100.0,
+                    volume_24h: 5.0,
+                    quote_volume_24h: Some(500.0),
+                    high_24h: 110.0,
+                    low_24h: 90.0,
+                    mark_price: Some(100.1),
+                    index_price: Some(100.2),
+                    time: time(2),
+                }),
+            },
+            TestCase {
+                // TC2: delta only updates the provided fields
+                input: r#"{"topic":"tickers.BTCUSDT","type":"delta","ts":3,"cs":3,"data":{"symbol":"BTCUSDT","lastPrice":"101.0","markPrice":"101.1"}}"#,
+                expected: Some(Ticker {
+                    last_price: 101.0,
+                    volume_24h: 5.0,
+                    quote_volume_24h: Some(500.0),
+                    high_24h: 110.0,
+                    low_24h: 90.0,
+                    mark_price: Some(101.1),
+                    index_price: Some(100.2),
+                    time: time(3),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<BybitTickerMessage>(test.input).unwrap();
+            let actual = transformer
+                .transform(input)
+                .into_iter()
+                .next()
+                .map(|result| result.unwrap());
+
+            match (actual, test.expected) {
+                (None, None) => {}
+                (Some(actual), Some(expected)) => {
+                    assert_eq!(actual.exchange, ExchangeId::BybitPerpetualsUsd);
+                    assert_eq!(actual.instrument, 1);
+                    assert_eq!(actual.kind, expected, "TC{index} failed");
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
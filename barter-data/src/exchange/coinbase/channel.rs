@@ -1,6 +1,6 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{ticker::Tickers, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] real-time ticker channel.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+    pub const TICKERS: Self = Self("ticker");
 }
 
 impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrument, PublicTrades> {
@@ -25,6 +30,12 @@ impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrume
     }
 }
 
+impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrument, Tickers> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,12 +1,15 @@
 use self::{
     channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
-    trade::CoinbaseTrade,
+    ticker::CoinbaseTicker, trade::CoinbaseTrade,
 };
 use crate::{
     exchange::{Connector, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
+    subscription::{ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -28,6 +31,9 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Coinbase`].
 pub mod subscription;
 
+/// Ticker types for [`Coinbase`].
+pub mod ticker;
+
 /// Public trade types for [`Coinbase`].
 pub mod trade;
 
@@ -71,6 +77,37 @@ impl Connector for Coinbase {
             })
             .collect()
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        Some(
+            exchange_subs
+                .into_iter()
+                .map(|ExchangeSub { channel, market }| {
+                    WsMessage::Text(
+                        json!({
+                            "type": "unsubscribe",
+                            "product_ids": [market.as_ref()],
+                            "channels": [channel.as_ref()],
+                        })
+                        .to_string(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        // Unsubscribe responses list the remaining subscriptions, sharing the
+        // format of subscription responses
+        validate_response_message::<CoinbaseSubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscription
+        num_subscriptions
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Coinbase
@@ -81,3 +118,12 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, CoinbaseTrade>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for Coinbase
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, Tickers, CoinbaseTicker>>;
+}
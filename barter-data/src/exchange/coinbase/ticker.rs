@@ -0,0 +1,168 @@
+use super::CoinbaseChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Coinbase real-time ticker WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+/// ```json
+/// {
+///     "type": "ticker",
+///     "sequence": 37475248783,
+///     "product_id": "ETH-USD",
+///     "price": "1285.22",
+///     "open_24h": "1310.79",
+///     "volume_24h": "245532.79269678",
+///     "low_24h": "1280.52",
+///     "high_24h": "1313.8",
+///     "volume_30d": "9788783.60117027",
+///     "best_bid": "1285.04",
+///     "best_bid_size": "0.46688654",
+///     "best_ask": "1285.27",
+///     "best_ask_size": "1.56637040",
+///     "side": "buy",
+///     "time": "2022-10-19T23:28:22.061769Z",
+///     "trade_id": 370843401,
+///     "last_size": "11.4396987"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseTicker {
+    #[serde(alias = "product_id", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    #[serde(alias = "price", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(
+        alias = "volume_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume: f64,
+    #[serde(
+        alias = "high_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: f64,
+    #[serde(alias = "low_24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, CoinbaseTicker)>
+    for MarketIter<InstrumentKey, Ticker>
+{
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, InstrumentKey, CoinbaseTicker),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: ticker.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: Ticker {
+                last_price: ticker.last_price,
+                volume_24h: ticker.volume,
+                quote_volume_24h: None,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                mark_price: None,
+                index_price: None,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`CoinbaseTicker`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("ticker|BTC-USD").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::TICKERS, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::error::SocketError;
+    use chrono::NaiveDateTime;
+    use serde::de::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_de_coinbase_ticker() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<CoinbaseTicker, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: invalid CoinbaseTicker w/ missing "time"
+                input: r#"{
+                    "type": "ticker", "sequence": 37475248783, "product_id": "ETH-USD",
+                    "price": "1285.22", "volume_24h": "245532.79269678", "low_24h": "1280.52",
+                    "high_24h": "1313.8"
+                }"#,
+                expected: Err(SocketError::Deserialise {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: valid CoinbaseTicker
+                input: r#"{
+                    "type": "ticker", "sequence": 37475248783, "product_id": "ETH-USD",
+                    "price": "1285.22", "open_24h": "1310.79", "volume_24h": "245532.79269678",
+                    "low_24h": "1280.52", "high_24h": "1313.8", "volume_30d": "9788783.60117027",
+                    "best_bid": "1285.04", "best_bid_size": "0.46688654", "best_ask": "1285.27",
+                    "best_ask_size": "1.56637040", "side": "buy",
+                    "time": "2022-10-19T23:28:22.061769Z", "trade_id": 370843401,
+                    "last_size": "11.4396987"
+                }"#,
+                expected: Ok(CoinbaseTicker {
+                    subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+                    time: NaiveDateTime::from_str("2022-10-19T23:28:22.061769")
+                        .unwrap()
+                        .and_utc(),
+                    last_price: 1285.22,
+                    volume: 245532.79269678,
+                    high: 1313.8,
+                    low: 1280.52,
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CoinbaseTicker>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
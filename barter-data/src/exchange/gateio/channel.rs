@@ -1,6 +1,6 @@
 use crate::{
     instrument::InstrumentData,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{ticker::Tickers, trade::PublicTrades, Subscription},
     Identifier,
 };
 use barter_instrument::instrument::market_data::kind::MarketDataInstrumentKind;
@@ -29,6 +29,22 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
     pub const OPTION_TRADES: Self = Self("options.trades");
+
+    /// Gateio [`MarketDataInstrumentKind::Spot`] real-time tickers channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#tickers-channel>
+    pub const SPOT_TICKERS: Self = Self("spot.tickers");
+
+    /// Gateio [`MarketDataInstrumentKind::Future`] & [`MarketDataInstrumentKind::Perpetual`] real-time tickers channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-subscription>
+    /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#tickers-subscription>
+    pub const FUTURE_TICKERS: Self = Self("futures.tickers");
+
+    /// Gateio [`MarketDataInstrumentKind::Option`] real-time contract tickers channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#contract-tickers-channel>
+    pub const OPTION_TICKERS: Self = Self("options.contract_tickers");
 }
 
 impl<GateioExchange, Instrument> Identifier<GateioChannel>
@@ -47,6 +63,22 @@ where
     }
 }
 
+impl<GateioExchange, Instrument> Identifier<GateioChannel>
+    for Subscription<GateioExchange, Instrument, Tickers>
+where
+    Instrument: InstrumentData,
+{
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind() {
+            MarketDataInstrumentKind::Spot => GateioChannel::SPOT_TICKERS,
+            MarketDataInstrumentKind::Future(_) | MarketDataInstrumentKind::Perpetual => {
+                GateioChannel::FUTURE_TICKERS
+            }
+            MarketDataInstrumentKind::Option(_) => GateioChannel::OPTION_TICKERS,
+        }
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
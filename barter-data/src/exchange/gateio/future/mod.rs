@@ -1,10 +1,13 @@
 use crate::{
     exchange::{
-        gateio::{perpetual::trade::GateioFuturesTrades, Gateio},
+        gateio::{
+            perpetual::{ticker::GateioFuturesTickers, trade::GateioFuturesTrades},
+            Gateio,
+        },
         ExchangeServer, StreamSelector,
     },
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -40,6 +43,16 @@ where
     >;
 }
 
+impl<Instrument> StreamSelector<Instrument, Tickers> for GateioFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, Tickers, GateioFuturesTickers>,
+    >;
+}
+
 /// [`GateioFuturesBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/>
@@ -69,3 +82,13 @@ where
         StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioFuturesTrades>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for GateioFuturesBtc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, Tickers, GateioFuturesTickers>,
+    >;
+}
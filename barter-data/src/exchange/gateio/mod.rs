@@ -1,7 +1,14 @@
-use self::{channel::GateioChannel, market::GateioMarket, subscription::GateioSubResponse};
+use self::{
+    channel::GateioChannel,
+    market::GateioMarket,
+    subscription::{GateioSubResponse, GateioUnsubResponse},
+};
 use crate::{
     exchange::{subscription::ExchangeSub, Connector, ExchangeServer},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -88,6 +95,36 @@ where
             })
             .collect()
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        Some(
+            exchange_subs
+                .into_iter()
+                .map(|ExchangeSub { channel, market }| {
+                    WsMessage::Text(
+                        json!({
+                            "time": chrono::Utc::now().timestamp_millis(),
+                            "channel": channel.as_ref(),
+                            "event": "unsubscribe",
+                            "payload": [market.as_ref()]
+                        })
+                        .to_string(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        validate_response_message::<GateioUnsubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscription
+        num_subscriptions
+    }
 }
 
 impl<'de, Server> serde::Deserialize<'de> for Gateio<Server>
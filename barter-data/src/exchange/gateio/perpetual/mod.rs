@@ -1,14 +1,17 @@
-use self::trade::GateioFuturesTrades;
+use self::{ticker::GateioFuturesTickers, trade::GateioFuturesTrades};
 use super::Gateio;
 use crate::{
     exchange::{ExchangeServer, StreamSelector},
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
 use barter_instrument::exchange::ExchangeId;
 
+/// Ticker types.
+pub mod ticker;
+
 /// Public trades types.
 pub mod trade;
 
@@ -42,6 +45,16 @@ where
     >;
 }
 
+impl<Instrument> StreamSelector<Instrument, Tickers> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, Tickers, GateioFuturesTickers>,
+    >;
+}
+
 /// [`GateioPerpetualsBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
@@ -71,3 +84,13 @@ where
         StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioFuturesTrades>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, Tickers, GateioFuturesTickers>,
+    >;
+}
@@ -0,0 +1,170 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a
+/// [`GateioFuturesUsdt`](super::super::futures::GateioFuturesUsdt),
+/// [`GateioFuturesBtc`](super::super::futures::GateioFuturesBtc),
+/// [`GateioPerpetualUsdt`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](super::GateioPerpetualsBtc) real-time tickers WebSocket message.
+pub type GateioFuturesTickers = GateioMessage<Vec<GateioFuturesTickerInner>>;
+
+/// [`GateioFuturesUsdt`](super::super::futures::GateioFuturesUsdt),
+/// [`GateioFuturesBtc`](super::super::futures::GateioFuturesBtc),
+/// [`GateioPerpetualUsdt`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](super::GateioPerpetualsBtc) real-time ticker WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+/// ```json
+/// {
+///   "contract": "BTC_USD",
+///   "last": "118.4",
+///   "change_percentage": "0.77",
+///   "funding_rate": "-0.000114",
+///   "funding_rate_indicative": "0.01875",
+///   "mark_price": "118.35",
+///   "index_price": "118.36",
+///   "total_size": "73648",
+///   "volume_24h": "745487577",
+///   "volume_24h_btc": "117",
+///   "volume_24h_usd": "419950",
+///   "quanto_base_rate": "",
+///   "volume_24h_quote": "1665006",
+///   "volume_24h_settle": "178",
+///   "volume_24h_base": "5526",
+///   "low_24h": "99.2",
+///   "high_24h": "132.5"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTickerInner {
+    #[serde(rename = "contract")]
+    pub market: String,
+    #[serde(rename = "last", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(
+        rename = "high_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: f64,
+    #[serde(
+        rename = "low_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub low: f64,
+    #[serde(
+        rename = "volume_24h_base",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume: f64,
+    #[serde(
+        rename = "volume_24h_quote",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub quote_volume: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesTickers {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|ticker| ExchangeSub::from((&self.channel, &ticker.market)).id())
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, GateioFuturesTickers)>
+    for MarketIter<InstrumentKey, Ticker>
+{
+    fn from(
+        (exchange, instrument, tickers): (ExchangeId, InstrumentKey, GateioFuturesTickers),
+    ) -> Self {
+        // Gateio ticker payloads do not contain a timestamp, so use the time received
+        let time = Utc::now();
+
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    time_exchange: time,
+                    time_received: time,
+                    exchange,
+                    instrument: instrument.clone(),
+                    kind: Ticker {
+                        last_price: ticker.last_price,
+                        volume_24h: ticker.volume,
+                        quote_volume_24h: Some(ticker.quote_volume),
+                        high_24h: ticker.high,
+                        low_24h: ticker.low,
+                        mark_price: Some(ticker.mark_price),
+                        index_price: Some(ticker.index_price),
+                        time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_futures_tickers() {
+            let input = r#"
+            {
+                "time": 1541659086,
+                "time_ms": 1541659086123,
+                "channel": "futures.tickers",
+                "event": "update",
+                "result": [
+                    {
+                        "contract": "BTC_USD",
+                        "last": "118.4",
+                        "change_percentage": "0.77",
+                        "funding_rate": "-0.000114",
+                        "funding_rate_indicative": "0.01875",
+                        "mark_price": "118.35",
+                        "index_price": "118.36",
+                        "total_size": "73648",
+                        "volume_24h": "745487577",
+                        "volume_24h_btc": "117",
+                        "volume_24h_usd": "419950",
+                        "quanto_base_rate": "",
+                        "volume_24h_quote": "1665006",
+                        "volume_24h_settle": "178",
+                        "volume_24h_base": "5526",
+                        "low_24h": "99.2",
+                        "high_24h": "132.5"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioFuturesTickers>(input).unwrap();
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.tickers|BTC_USD"))
+            );
+            assert_eq!(actual.data[0].mark_price, 118.35);
+            assert_eq!(actual.data[0].volume, 5526.0);
+        }
+    }
+}
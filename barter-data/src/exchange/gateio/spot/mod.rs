@@ -1,15 +1,18 @@
-use self::trade::GateioSpotTrade;
+use self::{ticker::GateioSpotTicker, trade::GateioSpotTrade};
 use super::Gateio;
 use crate::{
     exchange::{ExchangeServer, StreamSelector},
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_macro::{DeExchange, SerExchange};
 
+/// Ticker types.
+pub mod ticker;
+
 /// Public trades types.
 pub mod trade;
 
@@ -44,3 +47,12 @@ where
         StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioSpotTrade>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for GateioSpot
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, Tickers, GateioSpotTicker>>;
+}
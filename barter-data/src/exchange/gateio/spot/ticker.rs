@@ -0,0 +1,133 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`GateioSpot`](super::GateioSpot) real-time tickers WebSocket message.
+pub type GateioSpotTicker = GateioMessage<GateioSpotTickerInner>;
+
+/// [`GateioSpot`](super::GateioSpot) real-time ticker WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#tickers-channel>
+/// ```json
+/// {
+///   "currency_pair": "BTC_USDT",
+///   "last": "19106.55",
+///   "lowest_ask": "19108",
+///   "highest_bid": "19106.55",
+///   "change_percentage": "3.66",
+///   "base_volume": "2811.3042155865",
+///   "quote_volume": "53441606.52411221454674732293",
+///   "high_24h": "19417.74",
+///   "low_24h": "18434.21"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioSpotTickerInner {
+    #[serde(rename = "currency_pair")]
+    pub market: String,
+    #[serde(rename = "last", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(
+        rename = "high_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: f64,
+    #[serde(
+        rename = "low_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub low: f64,
+    #[serde(
+        rename = "base_volume",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume: f64,
+    #[serde(
+        rename = "quote_volume",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub quote_volume: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioSpotTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioSpotTicker)>
+    for MarketIter<InstrumentKey, Ticker>
+{
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, InstrumentKey, GateioSpotTicker),
+    ) -> Self {
+        // Gateio ticker payloads do not contain a timestamp, so use the time received
+        let time = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: exchange_id,
+            instrument,
+            kind: Ticker {
+                last_price: ticker.data.last_price,
+                volume_24h: ticker.data.volume,
+                quote_volume_24h: Some(ticker.data.quote_volume),
+                high_24h: ticker.data.high,
+                low_24h: ticker.data.low,
+                mark_price: None,
+                index_price: None,
+                time,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_spot_ticker() {
+            let input = r#"
+            {
+                "time": 1669107766,
+                "time_ms": 1669107766406,
+                "channel": "spot.tickers",
+                "event": "update",
+                "result": {
+                    "currency_pair": "BTC_USDT",
+                    "last": "19106.55",
+                    "lowest_ask": "19108",
+                    "highest_bid": "19106.55",
+                    "change_percentage": "3.66",
+                    "base_volume": "2811.3042155865",
+                    "quote_volume": "53441606.52411221454674732293",
+                    "high_24h": "19417.74",
+                    "low_24h": "18434.21"
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioSpotTicker>(input).unwrap();
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("spot.tickers|BTC_USDT"))
+            );
+            assert_eq!(actual.data.last_price, 19106.55);
+            assert_eq!(actual.data.volume, 2811.3042155865);
+        }
+    }
+}
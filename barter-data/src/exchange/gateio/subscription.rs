@@ -1,4 +1,4 @@
-use super::message::GateioMessage;
+use super::message::{GateioError, GateioMessage};
 use barter_integration::{error::SocketError, Validator};
 use serde::{Deserialize, Serialize};
 
@@ -32,10 +32,57 @@ impl Validator for GateioSubResponse {
     }
 }
 
+/// Expected [`Gateio`](super::Gateio) unsubscribe response.
+///
+/// ### Raw Payload Examples
+/// #### Unsubscribe Trades Success
+/// ```json
+/// {
+///     "time": 1606292218,
+///     "time_ms": 1606292218231,
+///     "channel": "spot.trades",
+///     "event": "unsubscribe",
+///     "result": {
+///         "status": "success"
+///     }
+/// }
+/// ```
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-response>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GateioUnsubResponse {
+    pub channel: String,
+    pub event: GateioUnsubEvent,
+    pub error: Option<GateioError>,
+}
+
+/// [`GateioUnsubResponse`] event, used to distinguish it from other [`GateioMessage`]s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GateioUnsubEvent {
+    Unsubscribe,
+}
+
+impl Validator for GateioUnsubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self.error {
+            None => Ok(self),
+            Some(failure) => Err(SocketError::Subscribe(format!(
+                "received failure unsubscribe response code: {} with message: {}",
+                failure.code, failure.message,
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::exchange::gateio::message::GateioError;
+    use crate::subscriber::validator::validate_response_message;
+    use barter_integration::protocol::websocket::WsMessage;
 
     mod de {
         use super::*;
@@ -127,4 +174,37 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_validate_gateio_unsub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: successful unsubscribe
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"unsubscribe","result":{"status":"success"}}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: failed unsubscribe
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"unsubscribe","error":{"code":2,"message":"unknown currency pair"},"result":null}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: subscription response is not an unsubscribe response
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"subscribe","result":{"status":"success"}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                validate_response_message::<GateioUnsubResponse>(WsMessage::text(test.input))
+                    .map(|outcome| outcome.is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
 }
@@ -1,12 +1,20 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::l1::KrakenOrderBookL1,
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    subscription::{KrakenSubResponse, KrakenUnsubResponse},
+    ticker::KrakenTicker,
+    trade::KrakenTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
+    subscription::{book::OrderBooksL1, ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -34,6 +42,9 @@ pub mod message;
 /// [`Validator`](barter_integration) for [`Kraken`].
 pub mod subscription;
 
+/// Ticker types for [`Kraken`].
+pub mod ticker;
+
 /// Public trade types for [`Kraken`].
 pub mod trade;
 
@@ -79,6 +90,37 @@ impl Connector for Kraken {
             })
             .collect()
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        Some(
+            exchange_subs
+                .into_iter()
+                .map(|ExchangeSub { channel, market }| {
+                    WsMessage::Text(
+                        json!({
+                            "event": "unsubscribe",
+                            "pair": [market.as_ref()],
+                            "subscription": {
+                                "name": channel.as_ref()
+                            }
+                        })
+                        .to_string(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        validate_response_message::<KrakenUnsubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscription
+        num_subscriptions
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Kraken
@@ -99,3 +141,12 @@ where
         StatelessTransformer<Self, Instrument::Key, OrderBooksL1, KrakenOrderBookL1>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for Kraken
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, Tickers, KrakenTicker>>;
+}
@@ -60,9 +60,50 @@ impl Validator for KrakenSubResponse {
     }
 }
 
+/// [`Kraken`](super::Kraken) message received in response to WebSocket unsubscribe requests.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-subscriptionStatus>
+/// #### Unsubscribe Trade Success
+/// ```json
+/// {
+///   "channelID": 10001,
+///   "channelName": "trade",
+///   "event": "subscriptionStatus",
+///   "pair": "XBT/EUR",
+///   "status": "unsubscribed",
+///   "subscription": {
+///     "name": "trade"
+///   }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum KrakenUnsubResponse {
+    Unsubscribed { pair: String },
+    Error(KrakenError),
+}
+
+impl Validator for KrakenUnsubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            KrakenUnsubResponse::Unsubscribed { .. } => Ok(self),
+            KrakenUnsubResponse::Error(error) => Err(SocketError::Subscribe(format!(
+                "received failure unsubscribe response: {}",
+                error.message
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscriber::validator::validate_response_message;
+    use barter_integration::protocol::websocket::WsMessage;
 
     mod de {
         use super::*;
@@ -163,4 +204,37 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_validate_kraken_unsub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: successful unsubscribe
+                input: r#"{"channelID":10001,"channelName":"trade","event":"subscriptionStatus","pair":"XBT/EUR","status":"unsubscribed","subscription":{"name":"trade"}}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: failed unsubscribe
+                input: r#"{"errorMessage":"Subscription Not Found","event":"subscriptionStatus","pair":"XBT/USD","status":"error","subscription":{"name":"trade"}}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: subscription response is not an unsubscribe response
+                input: r#"{"channelID":10001,"channelName":"trade","event":"subscriptionStatus","pair":"XBT/EUR","status":"subscribed","subscription":{"name":"trade"}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                validate_response_message::<KrakenUnsubResponse>(WsMessage::text(test.input))
+                    .map(|outcome| outcome.is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
 }
@@ -0,0 +1,253 @@
+use super::KrakenMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{de::extract_next, subscription::SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Kraken`](super::Kraken) real-time ticker WebSocket message.
+pub type KrakenTicker = KrakenMessage<KrakenTickerInner>;
+
+/// [`Kraken`](super::Kraken) real-time ticker data and the associated [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+/// ```json
+/// [
+///     0,
+///     {
+///         "a": ["5525.40000", 1, "1.000"],
+///         "b": ["5525.10000", 1, "1.000"],
+///         "c": ["5525.10000", "0.00398963"],
+///         "v": ["2634.11501494", "3591.17907851"],
+///         "p": ["5631.44067", "5653.78939"],
+///         "t": [11493, 16267],
+///         "l": ["5505.00000", "5505.00000"],
+///         "h": ["5783.00000", "5783.00000"],
+///         "o": ["5760.70000", "5763.40000"]
+///     },
+///     "ticker",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenTickerInner {
+    pub subscription_id: SubscriptionId,
+    pub stats: KrakenTickerStats,
+}
+
+/// [`Kraken`](super::Kraken) ticker statistics.
+///
+/// Kraken provides "today" and "last 24 hours" values for each statistic, only the latter
+/// are deserialised.
+///
+/// See [`KrakenTickerInner`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenTickerStats {
+    #[serde(rename = "c", deserialize_with = "de_first_str_f64")]
+    pub last_price: f64,
+    #[serde(rename = "v", deserialize_with = "de_last_str_f64")]
+    pub volume: f64,
+    #[serde(rename = "h", deserialize_with = "de_last_str_f64")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "de_last_str_f64")]
+    pub low: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenTickerInner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KrakenTicker)>
+    for MarketIter<InstrumentKey, Ticker>
+{
+    fn from((exchange_id, instrument, ticker): (ExchangeId, InstrumentKey, KrakenTicker)) -> Self {
+        match ticker {
+            KrakenTicker::Data(ticker) => {
+                // Kraken does not provide a ticker timestamp, so use the time received
+                let time = Utc::now();
+
+                Self(vec![Ok(MarketEvent {
+                    time_exchange: time,
+                    time_received: time,
+                    exchange: exchange_id,
+                    instrument,
+                    kind: Ticker {
+                        last_price: ticker.stats.last_price,
+                        volume_24h: ticker.stats.volume,
+                        quote_volume_24h: None,
+                        high_24h: ticker.stats.high,
+                        low_24h: ticker.stats.low,
+                        mark_price: None,
+                        index_price: None,
+                        time,
+                    },
+                })])
+            }
+            KrakenTicker::Event(_) => MarketIter(vec![]),
+        }
+    }
+}
+
+/// Deserialize the first element of a [`Kraken`](super::Kraken) `[<value>, ...]` string array
+/// as an `f64`.
+fn de_first_str_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (value, _): (String, serde::de::IgnoredAny) = Deserialize::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+/// Deserialize the last element of a [`Kraken`](super::Kraken) `[<today>, <last_24h>]` string
+/// array as an `f64`.
+fn de_last_str_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (_, value): (serde::de::IgnoredAny, String) = Deserialize::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenTickerInner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenTickerInner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenTickerInner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenTickerInner Sequence Format:
+                // [channelID, {a, b, c, v, p, t, l, h, o}, channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-ticker>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract ticker statistics
+                let stats = extract_next(&mut seq, "ticker")?;
+
+                // Extract channelName (eg/ "ticker") & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelName")?;
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "ticker|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((KrakenChannel::TICKERS, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenTickerInner {
+                    subscription_id,
+                    stats,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenTickerInner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::kraken::message::{KrakenError, KrakenEvent};
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenTicker::Data(KrakenTickerInner)
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "a": ["5525.40000", 1, "1.000"],
+                            "b": ["5525.10000", 1, "1.000"],
+                            "c": ["5525.10000", "0.00398963"],
+                            "v": ["2634.11501494", "3591.17907851"],
+                            "p": ["5631.44067", "5653.78939"],
+                            "t": [11493, 16267],
+                            "l": ["5505.00000", "5500.00000"],
+                            "h": ["5783.00000", "5790.00000"],
+                            "o": ["5760.70000", "5763.40000"]
+                        },
+                        "ticker",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenTicker::Data(KrakenTickerInner {
+                        subscription_id: SubscriptionId::from("ticker|XBT/USD"),
+                        stats: KrakenTickerStats {
+                            last_price: 5525.1,
+                            volume: 3591.17907851,
+                            high: 5790.0,
+                            low: 5500.0,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: valid KrakenTicker::Event(KrakenEvent::Error)
+                    input: r#"
+                    {
+                        "errorMessage": "Malformed request",
+                        "event": "error"
+                    }
+                    "#,
+                    expected: Ok(KrakenTicker::Event(KrakenEvent::Error(KrakenError {
+                        message: "Malformed request".to_string(),
+                    }))),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
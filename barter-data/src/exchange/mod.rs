@@ -8,7 +8,7 @@ use crate::{
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Validator};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::{fmt::Debug, time::Duration};
+use std::{fmt::Debug, num::NonZeroU32, time::Duration};
 use url::Url;
 
 /// `BinanceSpot` & `BinanceFuturesUsd` [`Connector`] and [`StreamSelector`] implementations.
@@ -122,6 +122,34 @@ where
     /// subscription payloads sent to the exchange server.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
 
+    /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
+    /// unsubscribe payloads sent to the exchange server.
+    ///
+    /// Defaults to `None`, meaning that runtime unsubscriptions are not supported.
+    fn unsubscribe_requests(
+        _: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        None
+    }
+
+    /// Validate a single message received in response to [`Self::unsubscribe_requests`] sent
+    /// on a running connection.
+    ///
+    /// Returns `None` if the message is not an unsubscribe response (eg/ a market event).
+    /// Defaults to `None` for every message, meaning unsubscribe responses are not validated.
+    fn validate_unsubscribe_response(_: WsMessage) -> Option<Result<(), SocketError>> {
+        None
+    }
+
+    /// Number of unsubscribe responses expected from the exchange server in response to the
+    /// [`Self::unsubscribe_requests`] for `num_subscriptions`
+    /// [`Subscription`](subscription::Subscription)s.
+    ///
+    /// Defaults to `0`, consistent with the default [`Self::validate_unsubscribe_response`].
+    fn expected_unsubscribe_responses(_num_subscriptions: usize) -> usize {
+        0
+    }
+
     /// Number of [`Subscription`](subscription::Subscription) responses expected from the
     /// exchange server in responses to the requests send. Used to validate all
     /// [`Subscription`](subscription::Subscription)s were accepted.
@@ -134,6 +162,86 @@ where
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// Defines the [`ConnectionLimits`] imposed by the exchange server on a single connection.
+    ///
+    /// Used to shard [`Subscription`](subscription::Subscription)s across multiple connections,
+    /// and to rate limit subscription requests. Defaults to [`ConnectionLimits::UNLIMITED`].
+    fn connection_limits() -> ConnectionLimits {
+        ConnectionLimits::UNLIMITED
+    }
+}
+
+/// Limits imposed by an exchange server on a single connection.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ConnectionLimits {
+    /// Maximum number of [`Subscription`](subscription::Subscription)s (streams) that a single
+    /// connection can serve.
+    pub max_subscriptions: Option<usize>,
+
+    /// Maximum number of [`ExchangeSub`]s that can be included in a single subscription request.
+    pub max_subscriptions_per_request: Option<usize>,
+
+    /// Maximum number of messages (eg/ subscription requests) that can be sent to the exchange
+    /// server per second.
+    pub max_messages_per_second: Option<NonZeroU32>,
+}
+
+impl ConnectionLimits {
+    /// [`ConnectionLimits`] for exchange servers that do not impose any limits.
+    pub const UNLIMITED: Self = Self {
+        max_subscriptions: None,
+        max_subscriptions_per_request: None,
+        max_messages_per_second: None,
+    };
+
+    /// Split a collection of [`Subscription`](subscription::Subscription)s into shards that can
+    /// each be served by a single connection.
+    pub fn shard<T>(&self, subscriptions: Vec<T>) -> Vec<Vec<T>> {
+        Self::chunk(subscriptions, self.max_subscriptions)
+    }
+
+    /// Split a collection of [`ExchangeSub`]s into the batches included in each subscription
+    /// request.
+    pub fn batch_requests<T>(&self, exchange_subs: Vec<T>) -> Vec<Vec<T>> {
+        Self::chunk(exchange_subs, self.max_subscriptions_per_request)
+    }
+
+    /// Number of subscription requests required to action the provided number of
+    /// [`ExchangeSub`]s. Always at-least one.
+    pub fn num_requests(&self, num_exchange_subs: usize) -> usize {
+        match self.max_subscriptions_per_request {
+            Some(max) if max > 0 => num_exchange_subs.div_ceil(max).max(1),
+            _ => 1,
+        }
+    }
+
+    /// Minimum [`Duration`] to wait between consecutive messages to abide by the
+    /// `max_messages_per_second` limit.
+    pub fn message_interval(&self) -> Option<Duration> {
+        self.max_messages_per_second
+            .map(|rate| Duration::from_secs(1) / rate.get())
+    }
+
+    fn chunk<T>(items: Vec<T>, max: Option<usize>) -> Vec<Vec<T>> {
+        match max {
+            Some(max) if max > 0 && items.len() > max => {
+                let mut items = items.into_iter().peekable();
+                let mut chunks = Vec::new();
+                while items.peek().is_some() {
+                    chunks.push(items.by_ref().take(max).collect());
+                }
+                chunks
+            }
+            _ => vec![items],
+        }
+    }
+}
+
+impl Default for ConnectionLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
 }
 
 /// Used when an exchange has servers different
@@ -156,3 +264,114 @@ pub struct PingInterval {
     pub interval: tokio::time::Interval,
     pub ping: fn() -> WsMessage,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_limits_shard() {
+        struct TestCase {
+            limits: ConnectionLimits,
+            input: Vec<u32>,
+            expected: Vec<Vec<u32>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: unlimited connection serves every subscription
+                limits: ConnectionLimits::UNLIMITED,
+                input: vec![1, 2, 3],
+                expected: vec![vec![1, 2, 3]],
+            },
+            TestCase {
+                // TC1: subscriptions within limit are served by a single connection
+                limits: ConnectionLimits {
+                    max_subscriptions: Some(3),
+                    ..ConnectionLimits::UNLIMITED
+                },
+                input: vec![1, 2, 3],
+                expected: vec![vec![1, 2, 3]],
+            },
+            TestCase {
+                // TC2: subscriptions exceeding limit are sharded
+                limits: ConnectionLimits {
+                    max_subscriptions: Some(2),
+                    ..ConnectionLimits::UNLIMITED
+                },
+                input: vec![1, 2, 3, 4, 5],
+                expected: vec![vec![1, 2], vec![3, 4], vec![5]],
+            },
+            TestCase {
+                // TC3: empty subscriptions yield a single empty shard
+                limits: ConnectionLimits {
+                    max_subscriptions: Some(2),
+                    ..ConnectionLimits::UNLIMITED
+                },
+                input: vec![],
+                expected: vec![vec![]],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.limits.shard(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_connection_limits_num_requests() {
+        struct TestCase {
+            limits: ConnectionLimits,
+            input: usize,
+            expected: usize,
+        }
+
+        let limited = ConnectionLimits {
+            max_subscriptions_per_request: Some(10),
+            ..ConnectionLimits::UNLIMITED
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: unlimited always requires one request
+                limits: ConnectionLimits::UNLIMITED,
+                input: 25,
+                expected: 1,
+            },
+            TestCase {
+                // TC1: limited with exact multiple
+                limits: limited,
+                input: 20,
+                expected: 2,
+            },
+            TestCase {
+                // TC2: limited with remainder
+                limits: limited,
+                input: 21,
+                expected: 3,
+            },
+            TestCase {
+                // TC3: limited with no subscriptions
+                limits: limited,
+                input: 0,
+                expected: 1,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.limits.num_requests(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_connection_limits_message_interval() {
+        let limits = ConnectionLimits {
+            max_messages_per_second: NonZeroU32::new(5),
+            ..ConnectionLimits::UNLIMITED
+        };
+        assert_eq!(limits.message_interval(), Some(Duration::from_millis(200)));
+        assert_eq!(ConnectionLimits::UNLIMITED.message_interval(), None);
+    }
+}
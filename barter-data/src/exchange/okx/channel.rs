@@ -1,6 +1,6 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{ticker::Tickers, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time tickers channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-tickers-channel>
+    pub const TICKERS: Self = Self("tickers");
 }
 
 impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, PublicTrades> {
@@ -25,6 +30,12 @@ impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Public
     }
 }
 
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Tickers> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,11 +1,18 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    channel::OkxChannel,
+    market::OkxMarket,
+    subscription::{OkxSubResponse, OkxUnsubResponse},
+    ticker::OkxTickers,
+    trade::OkxTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
     instrument::InstrumentData,
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
+    subscriber::{
+        validator::{validate_response_message, WebSocketSubValidator},
+        WebSocketSubscriber,
+    },
+    subscription::{ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -28,6 +35,9 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
 
+/// Ticker types for [`Okx`].
+pub mod ticker;
+
 /// Public trade types for [`Okx`].
 pub mod trade;
 
@@ -77,6 +87,27 @@ impl Connector for Okx {
             .to_string(),
         )]
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Option<Vec<WsMessage>> {
+        Some(vec![WsMessage::Text(
+            json!({
+                "op": "unsubscribe",
+                "args": &exchange_subs,
+            })
+            .to_string(),
+        )])
+    }
+
+    fn validate_unsubscribe_response(message: WsMessage) -> Option<Result<(), SocketError>> {
+        validate_response_message::<OkxUnsubResponse>(message)
+    }
+
+    fn expected_unsubscribe_responses(num_subscriptions: usize) -> usize {
+        // One response per unsubscription
+        num_subscriptions
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Okx
@@ -87,3 +118,12 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, OkxTrades>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, Tickers, OkxTickers>>;
+}
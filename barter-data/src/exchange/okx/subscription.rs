@@ -66,9 +66,53 @@ impl Validator for OkxSubResponse {
     }
 }
 
+/// [`Okx`](super::Okx) WebSocket unsubscribe response.
+///
+/// ### Raw Payload Examples
+/// #### Unsubscribe Trades Ok Response
+/// ```json
+/// {
+///   "event": "unsubscribe",
+///   "arg": {
+///     "channel": "trades",
+///     "instId": "BTC-USDT"
+///   },
+///   "connId": "a4d3ae55"
+/// }
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-unsubscribe>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum OkxUnsubResponse {
+    #[serde(rename = "unsubscribe")]
+    Unsubscribed,
+    Error {
+        code: String,
+        #[serde(rename = "msg")]
+        message: String,
+    },
+}
+
+impl Validator for OkxUnsubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::Unsubscribed => Ok(self),
+            Self::Error { code, message } => Err(SocketError::Subscribe(format!(
+                "received failure unsubscribe response code: {code} with message: {message}",
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscriber::validator::validate_response_message;
+    use barter_integration::protocol::websocket::WsMessage;
 
     mod de {
         use super::*;
@@ -153,4 +197,36 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_validate_okx_unsub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: successful unsubscribe
+                input: r#"{"event":"unsubscribe","arg":{"channel":"trades","instId":"BTC-USDT"},"connId":"a4d3ae55"}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: failed unsubscribe
+                input: r#"{"event":"error","code":"60012","msg":"Invalid request"}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: subscription response is not an unsubscribe response
+                input: r#"{"event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = validate_response_message::<OkxUnsubResponse>(WsMessage::text(test.input))
+                .map(|outcome| outcome.is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
 }
@@ -0,0 +1,181 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::ticker::Ticker,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time tickers WebSocket message.
+pub type OkxTickers = OkxMessage<OkxTicker>;
+
+/// [`Okx`](super::Okx) real-time ticker WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instType": "SPOT",
+///       "instId": "BTC-USDT",
+///       "last": "9999.99",
+///       "lastSz": "0.1",
+///       "askPx": "9999.99",
+///       "askSz": "11",
+///       "bidPx": "8888.88",
+///       "bidSz": "5",
+///       "open24h": "9000",
+///       "high24h": "10000",
+///       "low24h": "8888.88",
+///       "volCcy24h": "2222",
+///       "vol24h": "2222",
+///       "sodUtc0": "2222",
+///       "sodUtc8": "2222",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// ### Notes
+/// For `SPOT` & `MARGIN` instruments "vol24h" is the base volume and "volCcy24h" the quote
+/// volume. For derivatives "vol24h" is denominated in contracts and "volCcy24h" in the base
+/// currency.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxTicker {
+    #[serde(rename = "instType")]
+    pub instrument_kind: String,
+    #[serde(rename = "last", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(
+        rename = "high24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: f64,
+    #[serde(rename = "low24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(rename = "vol24h", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(
+        rename = "volCcy24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_currency: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxTickers)>
+    for MarketIter<InstrumentKey, Ticker>
+{
+    fn from((exchange, instrument, tickers): (ExchangeId, InstrumentKey, OkxTickers)) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                let (volume_24h, quote_volume_24h) = match ticker.instrument_kind.as_str() {
+                    "SPOT" | "MARGIN" => (ticker.volume, Some(ticker.volume_currency)),
+                    _ => (ticker.volume_currency, None),
+                };
+
+                Ok(MarketEvent {
+                    time_exchange: ticker.time,
+                    time_received: Utc::now(),
+                    exchange,
+                    instrument: instrument.clone(),
+                    kind: Ticker {
+                        last_price: ticker.last_price,
+                        volume_24h,
+                        quote_volume_24h,
+                        high_24h: ticker.high,
+                        low_24h: ticker.low,
+                        mark_price: None,
+                        index_price: None,
+                        time: ticker.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, subscription::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_tickers() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "tickers",
+                    "instId": "BTC-USDT"
+                },
+                "data": [
+                    {
+                        "instType": "SPOT",
+                        "instId": "BTC-USDT",
+                        "last": "9999.99",
+                        "lastSz": "0.1",
+                        "askPx": "9999.99",
+                        "askSz": "11",
+                        "bidPx": "8888.88",
+                        "bidSz": "5",
+                        "open24h": "9000",
+                        "high24h": "10000",
+                        "low24h": "8888.88",
+                        "volCcy24h": "2222",
+                        "vol24h": "2222",
+                        "sodUtc0": "2222",
+                        "sodUtc8": "2222",
+                        "ts": "1597026383085"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxTickers>(input);
+            let expected: Result<OkxTickers, SocketError> = Ok(OkxTickers {
+                subscription_id: SubscriptionId::from("tickers|BTC-USDT"),
+                data: vec![OkxTicker {
+                    instrument_kind: "SPOT".to_string(),
+                    last_price: 9999.99,
+                    high: 10000.0,
+                    low: 8888.88,
+                    volume: 2222.0,
+                    volume_currency: 2222.0,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1597026383085)),
+                }],
+            });
+
+            match (actual, expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC failed")
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
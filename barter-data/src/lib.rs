@@ -91,16 +91,17 @@ use crate::{
     event::MarketEvent,
     exchange::{Connector, PingInterval},
     instrument::InstrumentData,
+    streams::control::{ConnectionControl, ConnectionControlSlot, ControlledWsStream},
     subscriber::{Subscribed, Subscriber},
     subscription::{Subscription, SubscriptionKind},
-    transformer::ExchangeTransformer,
+    transformer::{ControlledTransformer, ExchangeTransformer, UpdateInstrumentMap},
 };
 use async_trait::async_trait;
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
     error::SocketError,
     protocol::{
-        websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+        websocket::{WebSocketParser, WsMessage, WsSink},
         StreamParser,
     },
     ExchangeStream, Transformer,
@@ -158,7 +159,11 @@ pub mod transformer;
 
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+///
+/// The [`ControlledWsStream`] and [`ControlledTransformer`] enable subscriptions to be actioned
+/// on the running connection via a [`ConnectionControl`].
+pub type ExchangeWsStream<Transformer> =
+    ExchangeStream<WebSocketParser, ControlledWsStream, ControlledTransformer<Transformer>>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -185,6 +190,17 @@ where
         SnapFetcher: SnapshotFetcher<Exchange, Kind>,
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// Initialise a new [`Self`], populating the provided [`ConnectionControlSlot`] with the
+    /// [`ConnectionControl`] of the new connection.
+    async fn init_with_control<SnapFetcher>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        control: &ConnectionControlSlot<Instrument::Key>,
+    ) -> Result<Self, DataError>
+    where
+        SnapFetcher: SnapshotFetcher<Exchange, Kind>,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
 }
 
 /// Defines how to fetch market data snapshots for a collection of [`Subscription`]s.
@@ -213,12 +229,27 @@ where
     Exchange: Connector + Send + Sync,
     Instrument: InstrumentData,
     Kind: SubscriptionKind + Send + Sync,
-    Transformer: ExchangeTransformer<Exchange, Instrument::Key, Kind> + Send,
+    Transformer: ExchangeTransformer<Exchange, Instrument::Key, Kind>
+        + UpdateInstrumentMap<InstrumentKey = Instrument::Key>
+        + Send,
     Kind::Event: Send,
 {
     async fn init<SnapFetcher>(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Self, DataError>
+    where
+        SnapFetcher: SnapshotFetcher<Exchange, Kind>,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Self::init_with_control::<SnapFetcher>(subscriptions, &ConnectionControlSlot::default())
+            .await
+    }
+
+    async fn init_with_control<SnapFetcher>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        control: &ConnectionControlSlot<Instrument::Key>,
+    ) -> Result<Self, DataError>
     where
         SnapFetcher: SnapshotFetcher<Exchange, Kind>,
         Subscription<Exchange, Instrument, Kind>:
@@ -255,8 +286,13 @@ where
         }
 
         // Initialise Transformer associated with this Exchange and SubscriptionKind
-        let mut transformer =
-            Transformer::init(instrument_map, &initial_snapshots, ws_sink_tx).await?;
+        let transformer =
+            Transformer::init(instrument_map, &initial_snapshots, ws_sink_tx.clone()).await?;
+
+        // Enable runtime subscriptions via a ConnectionControl for this connection
+        let (connection_control, ws_stream, mut transformer) =
+            ConnectionControl::init(ws_stream, ws_sink_tx, transformer);
+        control.set(connection_control);
 
         // Process any buffered active subscription events received during Subscription validation
         let mut processed = process_buffered_events::<WebSocketParser, _>(
@@ -15,20 +15,27 @@ use crate::{
         },
         kraken::{market::KrakenMarket, Kraken},
         okx::{market::OkxMarket, Okx},
+        ConnectionLimits, StreamSelector,
     },
     instrument::InstrumentData,
     streams::{
-        consumer::{init_market_stream, MarketStreamResult, STREAM_RECONNECTION_POLICY},
+        consumer::{
+            init_controlled_market_stream, MarketStreamResult, Shard, StreamKey,
+            STREAM_RECONNECTION_POLICY,
+        },
+        control::MarketStreamController,
         reconnect::stream::ReconnectingStream,
     },
     subscription::{
         book::{OrderBookEvent, OrderBookL1, OrderBooksL1},
         liquidation::{Liquidation, Liquidations},
+        ticker::{Ticker, Tickers},
         trade::{PublicTrade, PublicTrades},
-        SubKind, Subscription,
+        SubKind, Subscription, SubscriptionKind,
     },
     Identifier,
 };
+use async_trait::async_trait;
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
     channel::{mpsc_unbounded, UnboundedRx, UnboundedTx},
@@ -42,6 +49,7 @@ use futures::{
 };
 use futures_util::{future::try_join_all, StreamExt};
 use itertools::Itertools;
+use parking_lot::RwLock;
 use std::sync::Arc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vecmap::VecMap;
@@ -58,6 +66,8 @@ pub struct DynamicStreams<InstrumentKey> {
     >,
     pub liquidations:
         VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    pub tickers:
+        VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Ticker>>>,
 }
 
 impl<InstrumentKey> DynamicStreams<InstrumentKey> {
@@ -99,6 +109,72 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
         Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
         Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+        Subscription<BinanceSpot, Instrument, Tickers>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Tickers>: Identifier<BinanceMarket>,
+        Subscription<BybitSpot, Instrument, Tickers>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, Tickers>: Identifier<BybitMarket>,
+        Subscription<Coinbase, Instrument, Tickers>: Identifier<CoinbaseMarket>,
+        Subscription<GateioSpot, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesBtc, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<Kraken, Instrument, Tickers>: Identifier<KrakenMarket>,
+        Subscription<Okx, Instrument, Tickers>: Identifier<OkxMarket>,
+    {
+        Self::init_with_controller(subscription_batches)
+            .await
+            .map(|(streams, _)| streams)
+    }
+
+    /// Initialise a set of `Streams` by providing one or more [`Subscription`] batches, also
+    /// returning a [`DynamicStreamsController`] that can subscribe and unsubscribe instruments
+    /// on the running connections.
+    ///
+    /// Events for runtime [`Subscription`]s are delivered via the existing `select_*` streams,
+    /// so only [`ExchangeId`]-[`SubKind`] combinations present in the initial batches can be
+    /// subscribed to at runtime.
+    pub async fn init_with_controller<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+    ) -> Result<(Self, DynamicStreamsController<Instrument>), DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + 'static,
+        InstrumentKey: Clone + Send + 'static,
+        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+        Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+        Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+        Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+        Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+        Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+        Subscription<BinanceSpot, Instrument, Tickers>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Tickers>: Identifier<BinanceMarket>,
+        Subscription<BybitSpot, Instrument, Tickers>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, Tickers>: Identifier<BybitMarket>,
+        Subscription<Coinbase, Instrument, Tickers>: Identifier<CoinbaseMarket>,
+        Subscription<GateioSpot, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesBtc, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, Tickers>: Identifier<GateioMarket>,
+        Subscription<Kraken, Instrument, Tickers>: Identifier<KrakenMarket>,
+        Subscription<Okx, Instrument, Tickers>: Identifier<OkxMarket>,
     {
         // Validate & dedup Subscription batches
         let batches = validate_batches(subscription_batches)?;
@@ -106,409 +182,325 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         // Generate required Channels from Subscription batches
         let channels = Channels::try_from(&batches)?;
 
-        let futures =
-            batches.into_iter().map(|mut batch| {
-                batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
-                let by_exchange_by_sub_kind =
-                    batch.into_iter().chunk_by(|sub| (sub.exchange, sub.kind));
-
-                let batch_futures =
-                    by_exchange_by_sub_kind
-                        .into_iter()
-                        .map(|((exchange, sub_kind), subs)| {
-                            let subs = subs.into_iter().collect::<Vec<_>>();
-                            let txs = Arc::clone(&channels.txs);
-                            async move {
-                                match (exchange, sub_kind) {
-                                    (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceSpot::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::<_, Instrument, _>::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::<_, Instrument, _>::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        Liquidations,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.liquidations.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Bitfinex,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Bitmex, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Bitmex,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitSpot::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitPerpetualsUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Coinbase, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Coinbase,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioSpot::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioFuturesBtc::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioPerpetualsUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioPerpetualsBtc::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioOptions::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Kraken, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Kraken,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Kraken,
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.boxed().forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Okx, SubKind::PublicTrades) => init_market_stream(
-                                        STREAM_RECONNECTION_POLICY,
-                                        subs.into_iter()
-                                            .map(|sub| {
-                                                Subscription::new(Okx, sub.instrument, PublicTrades)
-                                            })
-                                            .collect(),
+        let futures = batches.into_iter().map(|mut batch| {
+            batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
+            let by_exchange_by_sub_kind =
+                batch.into_iter().chunk_by(|sub| (sub.exchange, sub.kind));
+
+            let batch_futures =
+                by_exchange_by_sub_kind
+                    .into_iter()
+                    .map(|((exchange, sub_kind), subs)| {
+                        let subs = subs.into_iter().collect::<Vec<_>>();
+                        let txs = Arc::clone(&channels.txs);
+                        async move {
+                            match (exchange, sub_kind) {
+                                (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        BinanceSpot::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
                                     )
                                     .await
-                                    .map(|stream| {
-                                        tokio::spawn(
-                                            stream.boxed().forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ),
-                                        )
-                                    }),
-                                    (exchange, sub_kind) => {
-                                        Err(DataError::Unsupported { exchange, sub_kind })
-                                    }
+                                }
+                                (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
+                                    init_dynamic_connection(
+                                        BinanceSpot::default(),
+                                        OrderBooksL1,
+                                        subs,
+                                        txs.l1s.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        BinanceFuturesUsd::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
+                                    init_dynamic_connection(
+                                        BinanceFuturesUsd::default(),
+                                        OrderBooksL1,
+                                        subs,
+                                        txs.l1s.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
+                                    init_dynamic_connection(
+                                        BinanceFuturesUsd::default(),
+                                        Liquidations,
+                                        subs,
+                                        txs.liquidations.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        Bitfinex,
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Bitmex, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        Bitmex,
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        BybitSpot::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        BybitPerpetualsUsd::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Coinbase, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        Coinbase,
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        GateioSpot::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        GateioFuturesUsd::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        GateioFuturesBtc::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        GateioPerpetualsUsd::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        GateioPerpetualsBtc::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        GateioOptions::default(),
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Kraken, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        Kraken,
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
+                                    init_dynamic_connection(
+                                        Kraken,
+                                        OrderBooksL1,
+                                        subs,
+                                        txs.l1s.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Okx, SubKind::PublicTrades) => {
+                                    init_dynamic_connection(
+                                        Okx,
+                                        PublicTrades,
+                                        subs,
+                                        txs.trades.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BinanceSpot, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        BinanceSpot::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BinanceFuturesUsd, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        BinanceFuturesUsd::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BybitSpot, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        BybitSpot::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::BybitPerpetualsUsd, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        BybitPerpetualsUsd::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Coinbase, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        Coinbase,
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioSpot, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        GateioSpot::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioFuturesUsd, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        GateioFuturesUsd::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioFuturesBtc, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        GateioFuturesBtc::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioPerpetualsUsd, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        GateioPerpetualsUsd::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::GateioPerpetualsBtc, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        GateioPerpetualsBtc::default(),
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Kraken, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        Kraken,
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (ExchangeId::Okx, SubKind::Tickers) => {
+                                    init_dynamic_connection(
+                                        Okx,
+                                        Tickers,
+                                        subs,
+                                        txs.tickers.get(&exchange).unwrap().clone(),
+                                    )
+                                    .await
+                                }
+                                (exchange, sub_kind) => {
+                                    Err(DataError::Unsupported { exchange, sub_kind })
                                 }
                             }
-                        });
+                        }
+                    });
 
-                try_join_all(batch_futures)
-            });
+            try_join_all(batch_futures)
+        });
 
-        try_join_all(futures).await?;
+        let connections = try_join_all(futures)
+            .await?
+            .into_iter()
+            .flatten()
+            .flatten()
+            .fold(
+                FnvHashMap::<_, Vec<_>>::default(),
+                |mut connections, connection| {
+                    connections
+                        .entry((connection.stream_key().exchange, connection.sub_kind()))
+                        .or_default()
+                        .push(connection);
+                    connections
+                },
+            );
 
-        Ok(Self {
+        let streams = Self {
             trades: channels
                 .rxs
                 .trades
@@ -533,7 +525,20 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                 .into_iter()
                 .map(|(exchange, rx)| (exchange, rx.into_stream()))
                 .collect(),
-        })
+            tickers: channels
+                .rxs
+                .tickers
+                .into_iter()
+                .map(|(exchange, rx)| (exchange, rx.into_stream()))
+                .collect(),
+        };
+
+        Ok((
+            streams,
+            DynamicStreamsController {
+                connections: Arc::new(RwLock::new(connections)),
+            },
+        ))
     }
 
     /// Remove an exchange [`PublicTrade`] `Stream` from the [`DynamicStreams`] collection.
@@ -608,6 +613,24 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         select_all(std::mem::take(&mut self.liquidations).into_values())
     }
 
+    /// Remove an exchange [`Ticker`] `Stream` from the [`DynamicStreams`] collection.
+    ///
+    /// Note that calling this method will permanently remove this `Stream` from [`Self`].
+    pub fn select_tickers(
+        &mut self,
+        exchange: ExchangeId,
+    ) -> Option<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Ticker>>> {
+        self.tickers.remove(&exchange)
+    }
+
+    /// Select and merge every exchange [`Ticker`] `Stream` using
+    /// [`SelectAll`](futures_util::stream::select_all).
+    pub fn select_all_tickers(
+        &mut self,
+    ) -> SelectAll<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Ticker>>> {
+        select_all(std::mem::take(&mut self.tickers).into_values())
+    }
+
     /// Select and merge every exchange `Stream` for every data type using [`select_all`]
     ///
     /// Note that using [`MarketEvent<Instrument, DataKind>`] as the `Output` is suitable for most
@@ -620,12 +643,14 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         MarketStreamResult<InstrumentKey, OrderBookL1>: Into<Output>,
         MarketStreamResult<InstrumentKey, OrderBookEvent>: Into<Output>,
         MarketStreamResult<InstrumentKey, Liquidation>: Into<Output>,
+        MarketStreamResult<InstrumentKey, Ticker>: Into<Output>,
     {
         let Self {
             trades,
             l1s,
             l2s,
             liquidations,
+            tickers,
         } = self;
 
         let trades = trades
@@ -644,12 +669,401 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
             .into_values()
             .map(|stream| stream.map(MarketStreamResult::into).boxed());
 
-        let all = trades.chain(l1s).chain(l2s).chain(liquidations);
+        let tickers = tickers
+            .into_values()
+            .map(|stream| stream.map(MarketStreamResult::into).boxed());
+
+        let all = trades
+            .chain(l1s)
+            .chain(l2s)
+            .chain(liquidations)
+            .chain(tickers);
 
         select_all(all)
     }
 }
 
+/// Controls the [`Subscription`]s of the connections driving a [`DynamicStreams`].
+///
+/// Generated via [`DynamicStreams::init_with_controller`].
+pub struct DynamicStreamsController<Instrument> {
+    connections: Arc<RwLock<DynamicConnections<Instrument>>>,
+}
+
+type DynamicConnections<Instrument> =
+    FnvHashMap<(ExchangeId, SubKind), Vec<Arc<dyn DynamicConnection<Instrument>>>>;
+
+impl<Instrument> DynamicStreamsController<Instrument>
+where
+    Instrument: InstrumentData + Ord,
+{
+    /// Current [`Subscription`]s served by the running connections.
+    pub fn subscriptions(&self) -> Vec<Subscription<ExchangeId, Instrument, SubKind>> {
+        self.connections
+            .read()
+            .iter()
+            .flat_map(|(&(exchange, sub_kind), connections)| {
+                connections
+                    .iter()
+                    .flat_map(|connection| connection.instruments())
+                    .map(move |instrument| Subscription::new(exchange, instrument, sub_kind))
+            })
+            .collect()
+    }
+
+    /// Current connection [`Shard`]s, describing which connection serves which instruments.
+    pub fn shards(&self) -> Vec<Shard<Instrument>> {
+        self.connections
+            .read()
+            .values()
+            .flat_map(|connections| {
+                connections
+                    .iter()
+                    .enumerate()
+                    .map(|(index, connection)| Shard {
+                        stream: connection.stream_key(),
+                        index,
+                        instruments: connection.instruments(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Subscribe to the provided [`Subscription`]s on the running connections.
+    ///
+    /// Instruments that are already subscribed are ignored. New instruments are actioned on the
+    /// [`ExchangeId`]-[`SubKind`] connections serving the fewest instruments, and the exchange
+    /// responses are validated before returning. If every connection has reached the exchange
+    /// [`ConnectionLimits`](crate::exchange::ConnectionLimits), new connection [`Shard`]s are
+    /// initialised.
+    pub async fn subscribe<SubIter, Sub>(&self, subscriptions: SubIter) -> Result<(), DataError>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+    {
+        for (key, mut connections, instruments) in self.group(subscriptions)? {
+            let active = connections
+                .iter()
+                .flat_map(|connection| connection.instruments())
+                .collect::<Vec<_>>();
+
+            let mut remaining = instruments
+                .into_iter()
+                .filter(|instrument| !active.contains(instrument))
+                .collect::<Vec<_>>();
+
+            // Fill existing connections with spare capacity, starting with the least utilised
+            connections.sort_by_key(|connection| connection.instruments().len());
+            for connection in &connections {
+                if remaining.is_empty() {
+                    break;
+                }
+
+                let spare = connection
+                    .connection_limits()
+                    .max_subscriptions
+                    .map(|max| max.saturating_sub(connection.instruments().len()))
+                    .unwrap_or(usize::MAX);
+
+                if spare == 0 {
+                    continue;
+                }
+
+                let next = remaining.split_off(spare.min(remaining.len()));
+                let chunk = std::mem::replace(&mut remaining, next);
+                connection.subscribe(chunk).await?;
+            }
+
+            // Initialise new connection Shards for any remaining instruments
+            let Some(template) = connections.first() else {
+                continue;
+            };
+            for shard in template.connection_limits().shard(remaining) {
+                if shard.is_empty() {
+                    continue;
+                }
+
+                let connection = template.init_shard(shard).await?;
+                self.connections
+                    .write()
+                    .entry(key)
+                    .or_default()
+                    .push(connection);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unsubscribe from the provided [`Subscription`]s on the running connections.
+    ///
+    /// Instruments that are not currently subscribed are ignored.
+    pub async fn unsubscribe<SubIter, Sub>(&self, subscriptions: SubIter) -> Result<(), DataError>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+    {
+        for (_, connections, instruments) in self.group(subscriptions)? {
+            for connection in connections {
+                let active = connection.instruments();
+
+                let removed = instruments
+                    .iter()
+                    .filter(|instrument| active.contains(instrument))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if !removed.is_empty() {
+                    connection.unsubscribe(removed).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate & group [`Subscription`] instruments by the connections that can serve them.
+    #[allow(clippy::type_complexity)]
+    fn group<SubIter, Sub>(
+        &self,
+        subscriptions: SubIter,
+    ) -> Result<
+        Vec<(
+            (ExchangeId, SubKind),
+            Vec<Arc<dyn DynamicConnection<Instrument>>>,
+            Vec<Instrument>,
+        )>,
+        DataError,
+    >
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+    {
+        let subscriptions = validate_subscriptions(subscriptions)?;
+        let connections = self.connections.read();
+
+        subscriptions
+            .into_iter()
+            .into_group_map_by(|sub| (sub.exchange, sub.kind))
+            .into_iter()
+            .map(|((exchange, sub_kind), subs)| {
+                let connections = connections
+                    .get(&(exchange, sub_kind))
+                    .filter(|connections| !connections.is_empty())
+                    .ok_or(DataError::ConnectionNotFound { exchange, sub_kind })?;
+
+                Ok((
+                    (exchange, sub_kind),
+                    connections.clone(),
+                    subs.into_iter().map(|sub| sub.instrument).collect(),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl<Instrument> Clone for DynamicStreamsController<Instrument> {
+    fn clone(&self) -> Self {
+        Self {
+            connections: Arc::clone(&self.connections),
+        }
+    }
+}
+
+impl<Instrument> std::fmt::Debug for DynamicStreamsController<Instrument> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicStreamsController")
+            .field(
+                "connections",
+                &self
+                    .connections
+                    .read()
+                    .iter()
+                    .map(|(key, connections)| (*key, connections.len()))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Type erased [`MarketStreamController`] of a single [`DynamicStreams`] connection.
+#[async_trait]
+trait DynamicConnection<Instrument>: Send + Sync {
+    fn stream_key(&self) -> StreamKey;
+    fn sub_kind(&self) -> SubKind;
+    fn connection_limits(&self) -> ConnectionLimits;
+    fn instruments(&self) -> Vec<Instrument>;
+    async fn subscribe(&self, instruments: Vec<Instrument>) -> Result<(), DataError>;
+    async fn unsubscribe(&self, instruments: Vec<Instrument>) -> Result<(), DataError>;
+
+    /// Initialise a new connection [`Shard`] for the same `Exchange` & `SubscriptionKind`.
+    async fn init_shard(
+        &self,
+        instruments: Vec<Instrument>,
+    ) -> Result<Arc<dyn DynamicConnection<Instrument>>, DataError>;
+}
+
+struct ControlledConnection<Exchange, Instrument, Kind>
+where
+    Instrument: InstrumentData,
+    Kind: SubscriptionKind,
+{
+    exchange: Exchange,
+    kind: Kind,
+    sub_kind: SubKind,
+    tx: UnboundedTx<MarketStreamResult<Instrument::Key, Kind::Event>>,
+    controller: MarketStreamController<Exchange, Instrument, Kind>,
+}
+
+impl<Exchange, Instrument, Kind> ControlledConnection<Exchange, Instrument, Kind>
+where
+    Exchange: Clone,
+    Instrument: InstrumentData,
+    Kind: SubscriptionKind,
+{
+    fn subscriptions(
+        &self,
+        instruments: Vec<Instrument>,
+    ) -> Vec<Subscription<Exchange, Instrument, Kind>> {
+        instruments
+            .into_iter()
+            .map(|instrument| {
+                Subscription::new(self.exchange.clone(), instrument, self.kind.clone())
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<Exchange, Instrument, Kind> DynamicConnection<Instrument>
+    for ControlledConnection<Exchange, Instrument, Kind>
+where
+    Exchange: StreamSelector<Instrument, Kind> + Send + Sync + 'static,
+    Instrument: InstrumentData + 'static,
+    Kind: SubscriptionKind + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + PartialEq,
+{
+    fn stream_key(&self) -> StreamKey {
+        StreamKey {
+            exchange: Exchange::ID,
+            kind: self.kind.as_str(),
+        }
+    }
+
+    fn sub_kind(&self) -> SubKind {
+        self.sub_kind
+    }
+
+    fn connection_limits(&self) -> ConnectionLimits {
+        Exchange::connection_limits()
+    }
+
+    fn instruments(&self) -> Vec<Instrument> {
+        self.controller
+            .subscriptions()
+            .into_iter()
+            .map(|subscription| subscription.instrument)
+            .collect()
+    }
+
+    async fn subscribe(&self, instruments: Vec<Instrument>) -> Result<(), DataError> {
+        self.controller
+            .subscribe(self.subscriptions(instruments))
+            .await
+    }
+
+    async fn unsubscribe(&self, instruments: Vec<Instrument>) -> Result<(), DataError> {
+        self.controller
+            .unsubscribe(self.subscriptions(instruments))
+            .await
+    }
+
+    async fn init_shard(
+        &self,
+        instruments: Vec<Instrument>,
+    ) -> Result<Arc<dyn DynamicConnection<Instrument>>, DataError> {
+        init_controlled_connection(
+            self.exchange.clone(),
+            self.kind.clone(),
+            self.sub_kind,
+            self.subscriptions(instruments),
+            self.tx.clone(),
+        )
+        .await
+    }
+}
+
+/// Initialise controlled [`MarketStream`](crate::MarketStream)s for the provided dynamic
+/// [`Subscription`]s, sharded according to the `Exchange`
+/// [`ConnectionLimits`], and forwarding events to the provided [`UnboundedTx`].
+async fn init_dynamic_connection<Exchange, Instrument, Kind>(
+    exchange: Exchange,
+    kind: Kind,
+    subscriptions: Vec<Subscription<ExchangeId, Instrument, SubKind>>,
+    tx: UnboundedTx<MarketStreamResult<Instrument::Key, Kind::Event>>,
+) -> Result<Vec<Arc<dyn DynamicConnection<Instrument>>>, DataError>
+where
+    Exchange: StreamSelector<Instrument, Kind> + Send + Sync + 'static,
+    Instrument: InstrumentData + 'static,
+    Kind: SubscriptionKind + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + PartialEq,
+{
+    let sub_kind = subscriptions
+        .first()
+        .map(|subscription| subscription.kind)
+        .ok_or(DataError::SubscriptionsEmpty)?;
+
+    let subscriptions = subscriptions
+        .into_iter()
+        .map(|sub| Subscription::new(exchange.clone(), sub.instrument, kind.clone()))
+        .collect();
+
+    let mut connections = Vec::new();
+    for shard in Exchange::connection_limits().shard(subscriptions) {
+        connections.push(
+            init_controlled_connection(exchange.clone(), kind.clone(), sub_kind, shard, tx.clone())
+                .await?,
+        );
+    }
+
+    Ok(connections)
+}
+
+/// Initialise a single controlled [`MarketStream`](crate::MarketStream) connection, forwarding
+/// events to the provided [`UnboundedTx`].
+async fn init_controlled_connection<Exchange, Instrument, Kind>(
+    exchange: Exchange,
+    kind: Kind,
+    sub_kind: SubKind,
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+    tx: UnboundedTx<MarketStreamResult<Instrument::Key, Kind::Event>>,
+) -> Result<Arc<dyn DynamicConnection<Instrument>>, DataError>
+where
+    Exchange: StreamSelector<Instrument, Kind> + Send + Sync + 'static,
+    Instrument: InstrumentData + 'static,
+    Kind: SubscriptionKind + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + PartialEq,
+{
+    let (controller, stream) =
+        init_controlled_market_stream(STREAM_RECONNECTION_POLICY, subscriptions).await?;
+
+    tokio::spawn(stream.boxed().forward_to(tx.clone()));
+
+    Ok(Arc::new(ControlledConnection {
+        exchange,
+        kind,
+        sub_kind,
+        tx,
+        controller,
+    }))
+}
+
 pub fn validate_batches<SubBatchIter, SubIter, Sub, Instrument>(
     batches: SubBatchIter,
 ) -> Result<Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>, DataError>
@@ -742,6 +1156,16 @@ where
                         rxs.liquidations.insert(sub.exchange, rx);
                     }
                 }
+                SubKind::Tickers => {
+                    if let (None, None) = (
+                        txs.tickers.get(&sub.exchange),
+                        rxs.tickers.get(&sub.exchange),
+                    ) {
+                        let (tx, rx) = mpsc_unbounded();
+                        txs.tickers.insert(sub.exchange, tx);
+                        rxs.tickers.insert(sub.exchange, rx);
+                    }
+                }
                 unsupported => return Err(DataError::UnsupportedSubKind(unsupported)),
             }
         }
@@ -759,6 +1183,7 @@ struct Txs<InstrumentKey> {
     l2s: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
     liquidations:
         FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    tickers: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, Ticker>>>,
 }
 
 impl<InstrumentKey> Default for Txs<InstrumentKey> {
@@ -768,6 +1193,7 @@ impl<InstrumentKey> Default for Txs<InstrumentKey> {
             l1s: Default::default(),
             l2s: Default::default(),
             liquidations: Default::default(),
+            tickers: Default::default(),
         }
     }
 }
@@ -778,6 +1204,7 @@ struct Rxs<InstrumentKey> {
     l2s: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
     liquidations:
         FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    tickers: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, Ticker>>>,
 }
 
 impl<InstrumentKey> Default for Rxs<InstrumentKey> {
@@ -787,6 +1214,7 @@ impl<InstrumentKey> Default for Rxs<InstrumentKey> {
             l1s: Default::default(),
             l2s: Default::default(),
             liquidations: Default::default(),
+            tickers: Default::default(),
         }
     }
 }
@@ -4,7 +4,9 @@ use crate::{
     exchange::StreamSelector,
     instrument::InstrumentData,
     streams::{
-        consumer::{init_market_stream, MarketStreamResult, STREAM_RECONNECTION_POLICY},
+        consumer::{
+            init_market_stream, MarketStreamResult, Shard, StreamKey, STREAM_RECONNECTION_POLICY,
+        },
         reconnect::stream::ReconnectingStream,
     },
     subscription::{Subscription, SubscriptionKind},
@@ -16,7 +18,8 @@ use barter_integration::{
     Validator,
 };
 use futures_util::StreamExt;
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use parking_lot::Mutex;
+use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin, sync::Arc};
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
 /// initialising a common [`Streams<Output>`](Streams) from multiple
@@ -42,6 +45,7 @@ where
     pub channels:
         HashMap<ExchangeId, ExchangeChannel<MarketStreamResult<InstrumentKey, Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    pub shards: Arc<Mutex<Vec<Shard<InstrumentKey>>>>,
 }
 
 impl<InstrumentKey, Kind> Debug for StreamBuilder<InstrumentKey, Kind>
@@ -53,6 +57,7 @@ where
         f.debug_struct("StreamBuilder<InstrumentKey, SubscriptionKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("shards", &self.shards)
             .finish()
     }
 }
@@ -66,12 +71,17 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            shards: Arc::default(),
         }
     }
 
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
+    /// If the [`Subscription`]s exceed the exchange
+    /// [`ConnectionLimits`](crate::exchange::ConnectionLimits), they are sharded across multiple
+    /// connections (see [`StreamBuilder::init_with_shards`]).
+    ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
     pub fn subscribe<SubIter, Sub, Exchange, Instrument>(mut self, subscriptions: SubIter) -> Self
@@ -92,6 +102,7 @@ where
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubscriptionKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let shards = Arc::clone(&self.shards);
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
@@ -105,11 +116,33 @@ where
             subscriptions.sort();
             subscriptions.dedup();
 
-            // Initialise a MarketEvent `ReconnectingStream`
-            let stream = init_market_stream(STREAM_RECONNECTION_POLICY, subscriptions).await?;
-
-            // Forward MarketEvents to ExchangeTx
-            tokio::spawn(stream.boxed().forward_to(exchange_tx));
+            // Shard Subscriptions across connections according to the exchange limits
+            for shard in Exchange::connection_limits().shard(subscriptions) {
+                let stream_key = shard.first().map(|sub| StreamKey {
+                    exchange: Exchange::ID,
+                    kind: sub.kind.as_str(),
+                });
+                let instruments = shard
+                    .iter()
+                    .map(|sub| sub.instrument.key().clone())
+                    .collect::<Vec<_>>();
+
+                // Initialise a MarketEvent `ReconnectingStream`
+                let stream = init_market_stream(STREAM_RECONNECTION_POLICY, shard).await?;
+
+                // Forward MarketEvents to ExchangeTx
+                tokio::spawn(stream.boxed().forward_to(exchange_tx.clone()));
+
+                if let Some(stream) = stream_key {
+                    let mut shards = shards.lock();
+                    let index = shards.iter().filter(|shard| shard.stream == stream).count();
+                    shards.push(Shard {
+                        stream,
+                        index,
+                        instruments,
+                    });
+                }
+            }
 
             Ok(())
         }));
@@ -126,17 +159,35 @@ where
     pub async fn init(
         self,
     ) -> Result<Streams<MarketStreamResult<InstrumentKey, Kind::Event>>, DataError> {
+        self.init_with_shards().await.map(|(streams, _)| streams)
+    }
+
+    /// Initialise the [`StreamBuilder`] (see [`StreamBuilder::init`]), also returning the
+    /// [`Shard`]s describing which connection serves which instruments.
+    pub async fn init_with_shards(
+        self,
+    ) -> Result<
+        (
+            Streams<MarketStreamResult<InstrumentKey, Kind::Event>>,
+            Vec<Shard<InstrumentKey>>,
+        ),
+        DataError,
+    > {
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
         // Construct Streams using each ExchangeChannel receiver
-        Ok(Streams {
+        let streams = Streams {
             streams: self
                 .channels
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
-        })
+        };
+
+        let shards = std::mem::take(&mut *self.shards.lock());
+
+        Ok((streams, shards))
     }
 }
 
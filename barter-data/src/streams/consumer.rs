@@ -4,6 +4,7 @@ use crate::{
     exchange::StreamSelector,
     instrument::InstrumentData,
     streams::{
+        control::{ConnectionControlSlot, MarketStreamController},
         reconnect,
         reconnect::stream::{
             init_reconnecting_stream, ReconnectingStream, ReconnectionBackoffPolicy,
@@ -14,7 +15,9 @@ use crate::{
 };
 use barter_instrument::exchange::ExchangeId;
 use futures::Stream;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tracing::info;
 
 /// Default [`ReconnectionBackoffPolicy`] for a [`reconnecting`](`ReconnectingStream`) [`MarketStream`].
@@ -80,12 +83,86 @@ where
     .with_reconnection_events(exchange))
 }
 
+/// Initialises a [`reconnecting`](`ReconnectingStream`) [`MarketStream`] using a collection of
+/// [`Subscription`]s, returning a [`MarketStreamController`] that can subscribe and unsubscribe
+/// on the running connection.
+///
+/// Each reconnection uses the [`Subscription`]s currently held by the
+/// [`MarketStreamController`].
+pub async fn init_controlled_market_stream<Exchange, Instrument, Kind>(
+    policy: ReconnectionBackoffPolicy,
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+) -> Result<
+    (
+        MarketStreamController<Exchange, Instrument, Kind>,
+        impl Stream<Item = MarketStreamResult<Instrument::Key, Kind::Event>>,
+    ),
+    DataError,
+>
+where
+    Exchange: StreamSelector<Instrument, Kind>,
+    Instrument: InstrumentData,
+    Kind: SubscriptionKind,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + PartialEq,
+{
+    // Determine ExchangeId associated with these Subscriptions
+    let exchange = Exchange::ID;
+
+    // Determine StreamKey for use in logging
+    let stream_key = subscriptions
+        .first()
+        .map(|sub| StreamKey {
+            exchange,
+            kind: sub.kind.as_str(),
+        })
+        .ok_or(DataError::SubscriptionsEmpty)?;
+
+    info!(
+        %exchange,
+        ?subscriptions,
+        ?policy,
+        ?stream_key,
+        "controlled MarketStream with auto reconnect running"
+    );
+
+    let subscriptions = Arc::new(Mutex::new(subscriptions));
+    let control = ConnectionControlSlot::default();
+    let controller = MarketStreamController::new(Arc::clone(&subscriptions), control.clone());
+
+    let stream = init_reconnecting_stream(move || {
+        let subscriptions = subscriptions.lock().clone();
+        let control = control.clone();
+        async move {
+            Exchange::Stream::init_with_control::<Exchange::SnapFetcher>(&subscriptions, &control)
+                .await
+        }
+    })
+    .await?
+    .with_reconnect_backoff(policy, stream_key)
+    .with_termination_on_error(|error| error.is_terminal(), stream_key)
+    .with_reconnection_events(exchange);
+
+    Ok((controller, stream))
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub struct StreamKey<Kind = &'static str> {
     pub exchange: ExchangeId,
     pub kind: Kind,
 }
 
+/// Connection shard serving a subset of the [`Subscription`]s associated with a [`StreamKey`].
+///
+/// [`Subscription`]s are sharded across multiple connections according to the exchange
+/// [`ConnectionLimits`](crate::exchange::ConnectionLimits).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct Shard<Instrument> {
+    pub stream: StreamKey,
+    pub index: usize,
+    pub instruments: Vec<Instrument>,
+}
+
 impl std::fmt::Debug for StreamKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "market_stream-{}-{}", self.exchange, self.kind)
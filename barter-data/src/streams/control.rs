@@ -0,0 +1,860 @@
+use crate::{
+    error::DataError,
+    exchange::{subscription::ExchangeSub, Connector},
+    instrument::InstrumentData,
+    subscriber::{mapper::SubscriptionMapper, validator::SubscriptionValidator, Subscriber},
+    subscription::{Subscription, SubscriptionKind, SubscriptionMeta},
+    transformer::{ControlledTransformer, InstrumentMapUpdate, UpdateInstrumentMap},
+    Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WsMessage, WsStream},
+    subscription::SubscriptionId,
+};
+use futures::{Stream, StreamExt};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, info};
+
+/// Validates a single [`WsMessage`] received in response to a runtime subscription or
+/// unsubscription.
+///
+/// See [`SubscriptionValidator::validate_response`] and
+/// [`Connector::validate_unsubscribe_response`].
+pub type ResponseValidator = fn(WsMessage) -> Option<Result<(), SocketError>>;
+
+/// Handle to a connected [`MarketStream`](crate::MarketStream) that can action subscriptions
+/// and unsubscriptions without re-initialising the connection.
+///
+/// Subscription requests are sent via the connection `ws_sink_tx`, and the instrument [`Map`]
+/// used by the [`ControlledTransformer`] is updated via [`InstrumentMapUpdate`]s.
+pub struct ConnectionControl<InstrumentKey> {
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    instrument_map_tx: mpsc::UnboundedSender<InstrumentMapUpdate<InstrumentKey>>,
+    insert_supported: bool,
+    pending: Arc<Mutex<Option<PendingValidation>>>,
+}
+
+impl<InstrumentKey> ConnectionControl<InstrumentKey> {
+    /// Construct a new [`ConnectionControl`], wrapping the provided [`WsStream`] in a
+    /// [`ControlledWsStream`] that intercepts runtime subscription responses, and the provided
+    /// `transformer` in a [`ControlledTransformer`] that applies [`InstrumentMapUpdate`]s.
+    pub fn init<Transformer>(
+        ws_stream: WsStream,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        transformer: Transformer,
+    ) -> (Self, ControlledWsStream, ControlledTransformer<Transformer>)
+    where
+        Transformer: UpdateInstrumentMap<InstrumentKey = InstrumentKey>,
+    {
+        let pending = Arc::new(Mutex::new(None));
+        let (instrument_map_tx, instrument_map_rx) = mpsc::unbounded_channel();
+
+        let control = Self {
+            ws_sink_tx,
+            instrument_map_tx,
+            insert_supported: Transformer::insert_supported(),
+            pending: Arc::clone(&pending),
+        };
+
+        (
+            control,
+            ControlledWsStream::new(ws_stream, pending),
+            ControlledTransformer::new(transformer, instrument_map_rx),
+        )
+    }
+
+    /// Action new subscriptions on the connection, waiting for `expected_responses` successful
+    /// subscription responses before the `timeout` elapses.
+    ///
+    /// Returns [`DataError::InstrumentMapUpdateUnsupported`] without sending anything if the
+    /// connection [`ControlledTransformer`] cannot map new subscriptions (see
+    /// [`UpdateInstrumentMap::insert_supported`]).
+    ///
+    /// The provided `instrument_map` is inserted before the subscription requests are sent, and
+    /// removed again if validation fails.
+    pub async fn subscribe(
+        &self,
+        SubscriptionMeta {
+            instrument_map,
+            ws_subscriptions,
+        }: SubscriptionMeta<InstrumentKey>,
+        expected_responses: usize,
+        validator: ResponseValidator,
+        timeout: Duration,
+        message_interval: Option<Duration>,
+    ) -> Result<(), DataError> {
+        if !self.insert_supported {
+            return Err(DataError::InstrumentMapUpdateUnsupported);
+        }
+
+        let subscription_ids = instrument_map.0.keys().cloned().collect::<Vec<_>>();
+
+        // Register pending validation so responses are intercepted by the ControlledWsStream
+        let outcome_rx = self.register_pending(expected_responses, validator)?;
+
+        // Inserted SubscriptionIds must be mapped before the exchange starts sending events
+        let sent =
+            match self.send_instrument_map_update(InstrumentMapUpdate::Insert(instrument_map)) {
+                Ok(()) => {
+                    self.send_ws_messages(ws_subscriptions, message_interval)
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+
+        let outcome = self
+            .await_outcome(sent, expected_responses, outcome_rx, timeout)
+            .await;
+
+        if outcome.is_err() {
+            let _ = self
+                .instrument_map_tx
+                .send(InstrumentMapUpdate::Remove(subscription_ids));
+        }
+
+        outcome
+    }
+
+    /// Action unsubscriptions on the connection by sending the provided `ws_unsubscriptions`,
+    /// waiting for `expected_responses` successful unsubscription responses before the `timeout`
+    /// elapses.
+    ///
+    /// Unsubscription responses are consumed by the [`ControlledWsStream`] rather than being
+    /// passed downstream to the [`Transformer`](barter_integration::Transformer). The associated
+    /// [`SubscriptionId`]s are only removed from the instrument [`Map`] once validated.
+    pub async fn unsubscribe(
+        &self,
+        subscription_ids: Vec<SubscriptionId>,
+        ws_unsubscriptions: Vec<WsMessage>,
+        expected_responses: usize,
+        validator: ResponseValidator,
+        timeout: Duration,
+        message_interval: Option<Duration>,
+    ) -> Result<(), DataError> {
+        // Register pending validation so responses are intercepted by the ControlledWsStream
+        let outcome_rx = self.register_pending(expected_responses, validator)?;
+
+        let sent = self
+            .send_ws_messages(ws_unsubscriptions, message_interval)
+            .await;
+
+        self.await_outcome(sent, expected_responses, outcome_rx, timeout)
+            .await?;
+
+        self.send_instrument_map_update(InstrumentMapUpdate::Remove(subscription_ids))
+    }
+
+    /// Register a [`PendingValidation`] of `expected_responses` for the
+    /// [`ControlledWsStream`] to action, returning the receiver of the validation outcome.
+    fn register_pending(
+        &self,
+        expected_responses: usize,
+        validator: ResponseValidator,
+    ) -> Result<oneshot::Receiver<Result<(), SocketError>>, DataError> {
+        let mut pending = self.pending.lock();
+        if pending.is_some() {
+            return Err(DataError::SubscriptionInProgress);
+        }
+
+        let (outcome_tx, outcome_rx) = oneshot::channel();
+        if expected_responses > 0 {
+            *pending = Some(PendingValidation {
+                expected_responses,
+                success_responses: 0,
+                validator,
+                outcome_tx,
+            });
+        }
+
+        Ok(outcome_rx)
+    }
+
+    /// Await the outcome of a [`PendingValidation`] once the requests have been `sent`,
+    /// ensuring it is cleared if it did not complete.
+    async fn await_outcome(
+        &self,
+        sent: Result<(), DataError>,
+        expected_responses: usize,
+        outcome_rx: oneshot::Receiver<Result<(), SocketError>>,
+        timeout: Duration,
+    ) -> Result<(), DataError> {
+        let outcome = match sent {
+            Ok(()) if expected_responses == 0 => Ok(()),
+            Ok(()) => match tokio::time::timeout(timeout, outcome_rx).await {
+                Ok(Ok(outcome)) => outcome.map_err(DataError::from),
+                Ok(Err(_)) => Err(DataError::from(SocketError::Subscribe(
+                    "WebSocket stream terminated unexpectedly".to_string(),
+                ))),
+                Err(_) => Err(DataError::from(SocketError::Subscribe(format!(
+                    "subscription validation timeout reached: {timeout:?}"
+                )))),
+            },
+            Err(error) => Err(error),
+        };
+
+        self.pending.lock().take();
+
+        outcome
+    }
+
+    fn send_instrument_map_update(
+        &self,
+        update: InstrumentMapUpdate<InstrumentKey>,
+    ) -> Result<(), DataError> {
+        self.instrument_map_tx
+            .send(update)
+            .map_err(|_| DataError::from(SocketError::Sink))
+    }
+
+    /// Send [`WsMessage`]s to the exchange, waiting the optional `message_interval` between
+    /// consecutive messages to abide by any exchange rate limit.
+    async fn send_ws_messages(
+        &self,
+        messages: Vec<WsMessage>,
+        message_interval: Option<Duration>,
+    ) -> Result<(), DataError> {
+        for (index, message) in messages.into_iter().enumerate() {
+            if let Some(interval) = message_interval.filter(|_| index > 0) {
+                tokio::time::sleep(interval).await;
+            }
+
+            self.ws_sink_tx
+                .send(message)
+                .map_err(|_| DataError::from(SocketError::Sink))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<InstrumentKey> Clone for ConnectionControl<InstrumentKey> {
+    fn clone(&self) -> Self {
+        Self {
+            ws_sink_tx: self.ws_sink_tx.clone(),
+            instrument_map_tx: self.instrument_map_tx.clone(),
+            insert_supported: self.insert_supported,
+            pending: Arc::clone(&self.pending),
+        }
+    }
+}
+
+impl<InstrumentKey> Debug for ConnectionControl<InstrumentKey> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionControl")
+            .field("ws_sink_tx", &self.ws_sink_tx)
+            .field("insert_supported", &self.insert_supported)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// Shared slot populated with the [`ConnectionControl`] of the latest connection each time a
+/// [`MarketStream`](crate::MarketStream) is (re)initialised.
+pub struct ConnectionControlSlot<InstrumentKey>(
+    Arc<RwLock<Option<ConnectionControl<InstrumentKey>>>>,
+);
+
+impl<InstrumentKey> ConnectionControlSlot<InstrumentKey> {
+    /// Replace the current [`ConnectionControl`] with the one of a newly initialised connection.
+    pub fn set(&self, control: ConnectionControl<InstrumentKey>) {
+        *self.0.write() = Some(control);
+    }
+
+    /// Clone the [`ConnectionControl`] of the latest connection, if one has been initialised.
+    pub fn get(&self) -> Option<ConnectionControl<InstrumentKey>> {
+        self.0.read().clone()
+    }
+}
+
+impl<InstrumentKey> Default for ConnectionControlSlot<InstrumentKey> {
+    fn default() -> Self {
+        Self(Arc::new(RwLock::new(None)))
+    }
+}
+
+impl<InstrumentKey> Clone for ConnectionControlSlot<InstrumentKey> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<InstrumentKey> Debug for ConnectionControlSlot<InstrumentKey> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ConnectionControlSlot")
+            .field(&self.0.read())
+            .finish()
+    }
+}
+
+/// [`WsStream`] wrapper that consumes the responses of runtime subscriptions actioned via a
+/// [`ConnectionControl`], passing every other message through untouched.
+#[derive(Debug)]
+pub struct ControlledWsStream {
+    inner: WsStream,
+    pending: Arc<Mutex<Option<PendingValidation>>>,
+}
+
+impl ControlledWsStream {
+    fn new(inner: WsStream, pending: Arc<Mutex<Option<PendingValidation>>>) -> Self {
+        Self { inner, pending }
+    }
+}
+
+impl Stream for ControlledWsStream {
+    type Item = <WsStream as Stream>::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.inner.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(message))) if consume(&self.pending, &message) => continue,
+                poll => return poll,
+            }
+        }
+    }
+}
+
+/// Returns true if the message was consumed as a runtime subscription response.
+fn consume(pending: &Mutex<Option<PendingValidation>>, message: &WsMessage) -> bool {
+    let mut pending_lock = pending.lock();
+    let Some(pending) = pending_lock.as_mut() else {
+        return false;
+    };
+
+    let outcome = (pending.validator)(message.clone());
+    match outcome {
+        None => false,
+        Some(Ok(())) => {
+            pending.success_responses += 1;
+            if pending.success_responses >= pending.expected_responses {
+                if let Some(pending) = pending_lock.take() {
+                    let _ = pending.outcome_tx.send(Ok(()));
+                }
+            }
+            true
+        }
+        Some(Err(error)) => {
+            if let Some(pending) = pending_lock.take() {
+                let _ = pending.outcome_tx.send(Err(error));
+            }
+            true
+        }
+    }
+}
+
+struct PendingValidation {
+    expected_responses: usize,
+    success_responses: usize,
+    validator: ResponseValidator,
+    outcome_tx: oneshot::Sender<Result<(), SocketError>>,
+}
+
+impl Debug for PendingValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PendingValidation")
+            .field("expected_responses", &self.expected_responses)
+            .field("success_responses", &self.success_responses)
+            .finish()
+    }
+}
+
+/// Controls the [`Subscription`]s of a reconnecting [`MarketStream`](crate::MarketStream)
+/// initialised via
+/// [`init_controlled_market_stream`](super::consumer::init_controlled_market_stream).
+///
+/// Subscription changes are actioned on the live connection, and are also used when the
+/// [`MarketStream`](crate::MarketStream) next reconnects.
+pub struct MarketStreamController<Exchange, Instrument, Kind>
+where
+    Instrument: InstrumentData,
+{
+    subscriptions: Arc<Mutex<Vec<Subscription<Exchange, Instrument, Kind>>>>,
+    control: ConnectionControlSlot<Instrument::Key>,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<Exchange, Instrument, Kind> MarketStreamController<Exchange, Instrument, Kind>
+where
+    Exchange: Connector,
+    Instrument: InstrumentData,
+    Kind: SubscriptionKind,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Clone + PartialEq,
+{
+    /// Construct a new [`MarketStreamController`] from the shared [`Subscription`]s used to
+    /// (re)initialise a [`MarketStream`](crate::MarketStream), and the associated
+    /// [`ConnectionControlSlot`].
+    pub fn new(
+        subscriptions: Arc<Mutex<Vec<Subscription<Exchange, Instrument, Kind>>>>,
+        control: ConnectionControlSlot<Instrument::Key>,
+    ) -> Self {
+        Self {
+            subscriptions,
+            control,
+            lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Current [`Subscription`]s served by the [`MarketStream`](crate::MarketStream).
+    pub fn subscriptions(&self) -> Vec<Subscription<Exchange, Instrument, Kind>> {
+        self.subscriptions.lock().clone()
+    }
+
+    /// Subscribe to the provided [`Subscription`]s on the live connection, validating the
+    /// exchange responses using the `Exchange` [`SubscriptionValidator`].
+    ///
+    /// Already active [`Subscription`]s are ignored. If the connection is currently
+    /// reconnecting, the [`Subscription`]s are actioned when the connection is re-established.
+    pub async fn subscribe(
+        &self,
+        subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+    ) -> Result<(), DataError> {
+        let _guard = self.lock.lock().await;
+
+        let new = {
+            let mut current = self.subscriptions.lock();
+            let new = subscriptions
+                .into_iter()
+                .filter(|subscription| !current.contains(subscription))
+                .fold(Vec::new(), |mut new, subscription| {
+                    if !new.contains(&subscription) {
+                        new.push(subscription);
+                    }
+                    new
+                });
+
+            // Include new Subscriptions in any subsequent reconnections
+            current.extend(new.iter().cloned());
+            new
+        };
+
+        if new.is_empty() {
+            return Ok(());
+        }
+
+        let Some(control) = self.control.get() else {
+            info!(exchange = %Exchange::ID, subscriptions = ?new, "MarketStream not connected, Subscriptions will be actioned on connection");
+            return Ok(());
+        };
+
+        let meta =
+            <<Exchange::Subscriber as Subscriber>::SubMapper as SubscriptionMapper>::map(&new);
+        let expected_responses = Exchange::expected_responses(&meta.instrument_map);
+
+        let outcome = control
+            .subscribe(
+                meta,
+                expected_responses,
+                <Exchange::SubValidator as SubscriptionValidator>::validate_response::<Exchange>,
+                Exchange::subscription_timeout(),
+                Exchange::connection_limits().message_interval(),
+            )
+            .await;
+
+        match &outcome {
+            Ok(()) => {
+                debug!(exchange = %Exchange::ID, subscriptions = ?new, "actioned runtime Subscriptions")
+            }
+            Err(_) => self
+                .subscriptions
+                .lock()
+                .retain(|subscription| !new.contains(subscription)),
+        }
+
+        outcome
+    }
+
+    /// Unsubscribe from the provided [`Subscription`]s on the live connection, validating the
+    /// exchange responses using [`Connector::validate_unsubscribe_response`].
+    ///
+    /// Returns [`DataError::UnsubscribeUnsupported`] if the `Exchange` [`Connector`] does not
+    /// define [`Connector::unsubscribe_requests`].
+    pub async fn unsubscribe(
+        &self,
+        subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+    ) -> Result<(), DataError> {
+        let _guard = self.lock.lock().await;
+
+        let removed = self
+            .subscriptions
+            .lock()
+            .iter()
+            .filter(|subscription| subscriptions.contains(subscription))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        let limits = Exchange::connection_limits();
+        let (subscription_ids, ws_unsubscriptions) = {
+            let exchange_subs = removed.iter().map(ExchangeSub::new).collect::<Vec<_>>();
+            let subscription_ids = exchange_subs
+                .iter()
+                .map(Identifier::<SubscriptionId>::id)
+                .collect::<Vec<_>>();
+            let ws_unsubscriptions = limits
+                .batch_requests(exchange_subs)
+                .into_iter()
+                .map(Exchange::unsubscribe_requests)
+                .collect::<Option<Vec<_>>>()
+                .ok_or(DataError::UnsubscribeUnsupported(Exchange::ID))?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            (subscription_ids, ws_unsubscriptions)
+        };
+
+        // Exclude removed Subscriptions from any subsequent reconnections
+        self.subscriptions
+            .lock()
+            .retain(|subscription| !removed.contains(subscription));
+
+        let Some(control) = self.control.get() else {
+            return Ok(());
+        };
+
+        let expected_responses = Exchange::expected_unsubscribe_responses(subscription_ids.len());
+
+        let outcome = control
+            .unsubscribe(
+                subscription_ids,
+                ws_unsubscriptions,
+                expected_responses,
+                Exchange::validate_unsubscribe_response,
+                Exchange::subscription_timeout(),
+                limits.message_interval(),
+            )
+            .await;
+
+        match &outcome {
+            Ok(()) => {
+                debug!(exchange = %Exchange::ID, subscriptions = ?removed, "actioned runtime unsubscriptions")
+            }
+            // Subscriptions are still active, so must be included in subsequent reconnections
+            Err(_) => self.subscriptions.lock().extend(removed),
+        }
+
+        outcome
+    }
+}
+
+impl<Exchange, Instrument, Kind> Clone for MarketStreamController<Exchange, Instrument, Kind>
+where
+    Instrument: InstrumentData,
+{
+    fn clone(&self) -> Self {
+        Self {
+            subscriptions: Arc::clone(&self.subscriptions),
+            control: self.control.clone(),
+            lock: Arc::clone(&self.lock),
+        }
+    }
+}
+
+impl<Exchange, Instrument, Kind> Debug for MarketStreamController<Exchange, Instrument, Kind>
+where
+    Exchange: Debug,
+    Instrument: InstrumentData + Debug,
+    Kind: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketStreamController")
+            .field("subscriptions", &self.subscriptions)
+            .field("control", &self.control)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        distribute_messages_to_exchange,
+        error::DataError,
+        exchange::binance::{
+            spot::{l2::BinanceSpotOrderBooksL2Transformer, BinanceSpot},
+            trade::BinanceTrade,
+        },
+        subscription::{book::OrderBooksL2, trade::PublicTrades, Map},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        ExchangeWsStream,
+    };
+    use barter_instrument::instrument::market_data::{
+        kind::MarketDataInstrumentKind, MarketDataInstrument,
+    };
+    use barter_integration::protocol::websocket::connect;
+    use futures::SinkExt;
+    use std::collections::VecDeque;
+
+    fn validator(message: WsMessage) -> Option<Result<(), SocketError>> {
+        match message.to_text().unwrap_or_default() {
+            "ok" => Some(Ok(())),
+            "err" => Some(Err(SocketError::Subscribe("rejected".to_string()))),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_control_subscribe_timeout_removes_instrument_map() {
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let (map_tx, mut map_rx) = mpsc::unbounded_channel();
+        let control = ConnectionControl {
+            ws_sink_tx,
+            instrument_map_tx: map_tx,
+            insert_supported: true,
+            pending: Arc::new(Mutex::new(None)),
+        };
+
+        let subscription_id = SubscriptionId::from("trade|btcusdt");
+        let meta = SubscriptionMeta {
+            instrument_map: Map(FromIterator::from_iter([(subscription_id.clone(), 1)])),
+            ws_subscriptions: vec![WsMessage::text("subscribe")],
+        };
+
+        let outcome = control
+            .subscribe(meta, 1, validator, Duration::from_millis(10), None)
+            .await;
+        assert!(outcome.is_err());
+
+        assert_eq!(ws_sink_rx.recv().await, Some(WsMessage::text("subscribe")));
+        assert!(matches!(
+            map_rx.recv().await,
+            Some(InstrumentMapUpdate::Insert(_))
+        ));
+        assert_eq!(
+            map_rx.recv().await,
+            Some(InstrumentMapUpdate::Remove(vec![subscription_id]))
+        );
+        assert!(control.pending.lock().is_none());
+    }
+
+    #[test]
+    fn test_controlled_ws_stream_consume() {
+        struct TestCase {
+            expected_responses: usize,
+            input: Vec<&'static str>,
+            expected_consumed: Vec<bool>,
+            expected_outcome: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: market events pass through, valid responses consumed until expected
+                expected_responses: 2,
+                input: vec!["event", "ok", "event", "ok", "ok"],
+                expected_consumed: vec![false, true, false, true, false],
+                expected_outcome: Some(true),
+            },
+            TestCase {
+                // TC1: invalid response fails validation
+                expected_responses: 2,
+                input: vec!["ok", "err", "ok"],
+                expected_consumed: vec![true, true, false],
+                expected_outcome: Some(false),
+            },
+            TestCase {
+                // TC2: validation incomplete
+                expected_responses: 2,
+                input: vec!["event", "ok"],
+                expected_consumed: vec![false, true],
+                expected_outcome: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let pending = Arc::new(Mutex::new(None));
+            let (outcome_tx, mut outcome_rx) = oneshot::channel();
+            *pending.lock() = Some(PendingValidation {
+                expected_responses: test.expected_responses,
+                success_responses: 0,
+                validator,
+                outcome_tx,
+            });
+
+            let consumed = test
+                .input
+                .into_iter()
+                .map(|message| consume(&pending, &WsMessage::text(message)))
+                .collect::<Vec<_>>();
+            assert_eq!(consumed, test.expected_consumed, "TC{index} failed");
+
+            let outcome = outcome_rx.try_recv().ok().map(|outcome| outcome.is_ok());
+            assert_eq!(outcome, test.expected_outcome, "TC{index} failed");
+        }
+    }
+
+    /// Spawn a local exchange WebSocket server that forwards every received text message via
+    /// the returned receiver, and replies with the messages returned by `respond`.
+    async fn spawn_exchange_server(
+        respond: fn(&str) -> Vec<String>,
+    ) -> (String, mpsc::UnboundedReceiver<String>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (received_tx, received_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            loop {
+                let Some(Ok(message)) = websocket.next().await else {
+                    break;
+                };
+                let Ok(text) = message.to_text() else {
+                    continue;
+                };
+                for reply in respond(text) {
+                    websocket.send(WsMessage::text(reply)).await.unwrap();
+                }
+                let _ = received_tx.send(text.to_string());
+            }
+        });
+
+        (url, received_rx)
+    }
+
+    /// Connect to the local exchange server, returning a [`MarketStreamController`] for the
+    /// running [`ExchangeWsStream`], and a receiver of the stream items.
+    async fn init_controlled_stream<Kind, Transformer>(
+        url: String,
+        transformer: Transformer,
+    ) -> (
+        MarketStreamController<BinanceSpot, MarketDataInstrument, Kind>,
+        mpsc::UnboundedReceiver<Result<Transformer::Output, DataError>>,
+    )
+    where
+        Kind: SubscriptionKind,
+        Transformer: barter_integration::Transformer<Error = DataError>
+            + UpdateInstrumentMap<InstrumentKey = MarketDataInstrument>
+            + Send
+            + 'static,
+        Transformer::Input: for<'de> serde::Deserialize<'de>,
+        Transformer::Output: Send,
+        Transformer::OutputIter: Send,
+        Subscription<BinanceSpot, MarketDataInstrument, Kind>: Identifier<<BinanceSpot as Connector>::Channel>
+            + Identifier<<BinanceSpot as Connector>::Market>
+            + Clone
+            + PartialEq,
+    {
+        let (ws_sink, ws_stream) = connect(url).await.unwrap().split();
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        tokio::spawn(distribute_messages_to_exchange(
+            BinanceSpot::ID,
+            ws_sink,
+            ws_sink_rx,
+        ));
+
+        let control = ConnectionControlSlot::default();
+        let (connection_control, ws_stream, transformer) =
+            ConnectionControl::init(ws_stream, ws_sink_tx, transformer);
+        control.set(connection_control);
+
+        let mut stream = ExchangeWsStream::new(ws_stream, transformer, VecDeque::new());
+        let (items_tx, items_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                let Some(item) = stream.next().await else {
+                    break;
+                };
+                if items_tx.send(item).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let controller = MarketStreamController::new(Arc::new(Mutex::new(vec![])), control);
+        (controller, items_rx)
+    }
+
+    fn btc_usdt() -> MarketDataInstrument {
+        MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot))
+    }
+
+    #[tokio::test]
+    async fn test_runtime_subscribe_and_unsubscribe_supported_kind() {
+        let (url, mut received) = spawn_exchange_server(|request| {
+            let response = r#"{"result":null,"id":1}"#.to_string();
+            if request.contains("\"UNSUBSCRIBE\"") {
+                vec![response]
+            } else {
+                let trade = r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"10000.19","q":"0.239000","b":1,"a":2,"T":1749354825200,"m":false,"M":true}"#;
+                vec![response, trade.to_string()]
+            }
+        })
+        .await;
+
+        let transformer = StatelessTransformer::<BinanceSpot, _, PublicTrades, BinanceTrade>::init(
+            Map(FromIterator::from_iter([])),
+            &[],
+            mpsc::unbounded_channel().0,
+        )
+        .await
+        .unwrap();
+        let (controller, mut items) =
+            init_controlled_stream::<PublicTrades, _>(url, transformer).await;
+
+        let subscription = Subscription::new(BinanceSpot::default(), btc_usdt(), PublicTrades);
+
+        // Subscribe request is sent, and the response is consumed by the ControlledWsStream
+        controller
+            .subscribe(vec![subscription.clone()])
+            .await
+            .unwrap();
+        assert!(received.recv().await.unwrap().contains("btcusdt@trade"));
+        assert_eq!(controller.subscriptions(), vec![subscription.clone()]);
+
+        // Events for the new instrument are mapped by the ControlledTransformer
+        let event = items.recv().await.unwrap().unwrap();
+        assert_eq!(event.instrument, btc_usdt());
+
+        // Unsubscribe request is sent, and the response is validated & consumed
+        controller.unsubscribe(vec![subscription]).await.unwrap();
+        assert!(received.recv().await.unwrap().contains("UNSUBSCRIBE"));
+        assert!(controller.subscriptions().is_empty());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), items.recv())
+                .await
+                .is_err(),
+            "unsubscribe response was passed downstream to the Transformer"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runtime_subscribe_unsupported_kind_rejected_before_sending() {
+        let (url, mut received) = spawn_exchange_server(|_| vec![]).await;
+
+        let transformer = BinanceSpotOrderBooksL2Transformer::init(
+            Map(FromIterator::from_iter([])),
+            &[],
+            mpsc::unbounded_channel().0,
+        )
+        .await
+        .unwrap();
+        let (controller, _items) =
+            init_controlled_stream::<OrderBooksL2, _>(url, transformer).await;
+
+        let subscription = Subscription::new(BinanceSpot::default(), btc_usdt(), OrderBooksL2);
+
+        let outcome = controller.subscribe(vec![subscription]).await;
+        assert!(matches!(
+            outcome,
+            Err(DataError::InstrumentMapUpdateUnsupported)
+        ));
+        assert!(controller.subscriptions().is_empty());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), received.recv())
+                .await
+                .is_err(),
+            "subscription request was sent for an unsupported SubscriptionKind"
+        );
+    }
+}
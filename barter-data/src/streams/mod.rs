@@ -14,6 +14,11 @@ pub mod builder;
 /// drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// [`ConnectionControl`](control::ConnectionControl) and
+/// [`MarketStreamController`](control::MarketStreamController) used to subscribe and unsubscribe
+/// on running [`MarketStream`](super::MarketStream) connections.
+pub mod control;
+
 /// Defines a [`ReconnectingStream`] and associated logic for generating an auto reconnecting
 /// `Stream`.
 pub mod reconnect;
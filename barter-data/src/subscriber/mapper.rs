@@ -64,8 +64,12 @@ impl SubscriptionMapper for WebSocketSubMapper {
             })
             .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
 
-        // Construct WebSocket message subscriptions requests
-        let ws_subscriptions = Exchange::requests(exchange_subs);
+        // Construct WebSocket message subscriptions requests, batched by the exchange limits
+        let ws_subscriptions = Exchange::connection_limits()
+            .batch_requests(exchange_subs)
+            .into_iter()
+            .flat_map(Exchange::requests)
+            .collect();
 
         SubscriptionMeta {
             instrument_map,
@@ -82,8 +82,13 @@ impl Subscriber for WebSocketSubscriber {
             ws_subscriptions,
         } = Self::SubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
 
-        // Send Subscriptions over WebSocket
-        for subscription in ws_subscriptions {
+        // Send Subscriptions over WebSocket, abiding by any exchange message rate limit
+        let message_interval = Exchange::connection_limits().message_interval();
+        for (index, subscription) in ws_subscriptions.into_iter().enumerate() {
+            if let Some(interval) = message_interval.filter(|_| index > 0) {
+                tokio::time::sleep(interval).await;
+            }
+
             debug!(%exchange, payload = ?subscription, "sending exchange subscription");
             websocket.send(subscription).await?;
         }
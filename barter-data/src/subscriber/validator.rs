@@ -12,7 +12,7 @@ use barter_integration::{
     Validator,
 };
 use futures::StreamExt;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::debug;
 
 /// Defines how to validate that actioned market data
@@ -29,6 +29,14 @@ pub trait SubscriptionValidator {
         Exchange: Connector + Send,
         InstrumentKey: Send,
         Kind: SubscriptionKind + Send;
+
+    /// Validate a single message received in response to subscriptions actioned on an already
+    /// running connection (see [`ConnectionControl`](crate::streams::control::ConnectionControl)).
+    ///
+    /// Returns `None` if the message is not an `Exchange::SubResponse` (eg/ a market event).
+    fn validate_response<Exchange>(message: WsMessage) -> Option<Result<(), SocketError>>
+    where
+        Exchange: Connector;
 }
 
 /// Standard [`SubscriptionValidator`] for [`WebSocket`]s suitable for most exchanges.
@@ -116,4 +124,34 @@ impl SubscriptionValidator for WebSocketSubValidator {
             }
         }
     }
+
+    fn validate_response<Exchange>(message: WsMessage) -> Option<Result<(), SocketError>>
+    where
+        Exchange: Connector,
+    {
+        match Self::Parser::parse::<Exchange::SubResponse>(Ok(message)) {
+            Some(Ok(response)) => Some(response.validate().map(|response| {
+                debug!(
+                    exchange = %Exchange::ID,
+                    payload = ?response,
+                    "received valid Ok runtime subscription response",
+                );
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the provided [`WsMessage`] as an exchange `Response`, and validate it.
+///
+/// Returns `None` if the message is not a `Response` (eg/ a market event). Useful for
+/// implementing [`Connector::validate_unsubscribe_response`].
+pub fn validate_response_message<Response>(message: WsMessage) -> Option<Result<(), SocketError>>
+where
+    Response: Validator + DeserializeOwned,
+{
+    match WebSocketParser::parse::<Response>(Ok(message)) {
+        Some(Ok(response)) => Some(response.validate().map(|_| ())),
+        _ => None,
+    }
 }
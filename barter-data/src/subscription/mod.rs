@@ -23,6 +23,9 @@ pub mod candle;
 /// Liquidation [`SubscriptionKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Ticker [`SubscriptionKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubscriptionKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -56,6 +59,7 @@ pub enum SubKind {
     OrderBooksL3,
     Liquidations,
     Candles,
+    Tickers,
 }
 
 impl<Exchange, Instrument, Kind> std::fmt::Display for Subscription<Exchange, Instrument, Kind>
@@ -234,21 +238,23 @@ pub fn exchange_supports_instrument_kind_sub_kind(
     use SubKind::*;
 
     match (exchange_id, instrument_kind, sub_kind) {
-        (BinanceSpot, Spot, PublicTrades | OrderBooksL1) => true,
-        (BinanceFuturesUsd, Perpetual, PublicTrades | OrderBooksL1 | Liquidations) => true,
+        (BinanceSpot, Spot, PublicTrades | OrderBooksL1 | Tickers) => true,
+        (BinanceFuturesUsd, Perpetual, PublicTrades | OrderBooksL1 | Liquidations | Tickers) => {
+            true
+        }
         (Bitfinex, Spot, PublicTrades) => true,
         (Bitmex, Perpetual, PublicTrades) => true,
-        (BybitSpot, Spot, PublicTrades) => true,
-        (BybitPerpetualsUsd, Perpetual, PublicTrades) => true,
-        (Coinbase, Spot, PublicTrades) => true,
-        (GateioSpot, Spot, PublicTrades) => true,
-        (GateioFuturesUsd, Future(_), PublicTrades) => true,
-        (GateioFuturesBtc, Future(_), PublicTrades) => true,
-        (GateioPerpetualsUsd, Perpetual, PublicTrades) => true,
-        (GateioPerpetualsBtc, Perpetual, PublicTrades) => true,
+        (BybitSpot, Spot, PublicTrades | Tickers) => true,
+        (BybitPerpetualsUsd, Perpetual, PublicTrades | Tickers) => true,
+        (Coinbase, Spot, PublicTrades | Tickers) => true,
+        (GateioSpot, Spot, PublicTrades | Tickers) => true,
+        (GateioFuturesUsd, Future(_), PublicTrades | Tickers) => true,
+        (GateioFuturesBtc, Future(_), PublicTrades | Tickers) => true,
+        (GateioPerpetualsUsd, Perpetual, PublicTrades | Tickers) => true,
+        (GateioPerpetualsBtc, Perpetual, PublicTrades | Tickers) => true,
         (GateioOptions, Option(_), PublicTrades) => true,
-        (Kraken, Spot, PublicTrades | OrderBooksL1) => true,
-        (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades) => true,
+        (Kraken, Spot, PublicTrades | OrderBooksL1 | Tickers) => true,
+        (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades | Tickers) => true,
 
         (_, _, _) => false,
     }
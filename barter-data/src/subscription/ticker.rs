@@ -0,0 +1,48 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Display,
+)]
+pub struct Tickers;
+
+impl SubscriptionKind for Tickers {
+    type Event = Ticker;
+
+    fn as_str(&self) -> &'static str {
+        "tickers"
+    }
+}
+
+/// Normalised Barter rolling 24h [`Ticker`] model.
+///
+/// ### Notes
+/// - `volume_24h` is denominated in the base asset.
+/// - `quote_volume_24h`, `mark_price` and `index_price` are only populated if the exchange
+///   provides them on its ticker channel.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub last_price: f64,
+    pub volume_24h: f64,
+    pub quote_volume_24h: Option<f64>,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub mark_price: Option<f64>,
+    pub index_price: Option<f64>,
+    pub time: DateTime<Utc>,
+}
@@ -4,8 +4,11 @@ use crate::{
     subscription::{Map, SubscriptionKind},
 };
 use async_trait::async_trait;
-use barter_integration::{protocol::websocket::WsMessage, Transformer};
+use barter_integration::{
+    protocol::websocket::WsMessage, subscription::SubscriptionId, Transformer,
+};
 use tokio::sync::mpsc;
+use tracing::warn;
 
 /// Generic stateless [`ExchangeTransformer`] often used for transforming
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
@@ -29,3 +32,83 @@ where
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
     ) -> Result<Self, DataError>;
 }
+
+/// Runtime update to the instrument [`Map`] used by an [`ExchangeTransformer`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum InstrumentMapUpdate<InstrumentKey> {
+    /// Insert the [`SubscriptionId`] to `InstrumentKey` mappings of newly actioned subscriptions.
+    Insert(Map<InstrumentKey>),
+
+    /// Remove the [`SubscriptionId`]s of unsubscribed subscriptions.
+    Remove(Vec<SubscriptionId>),
+}
+
+/// Defines how a [`Transformer`] applies [`InstrumentMapUpdate`]s to its instrument [`Map`],
+/// enabling subscriptions to be added or removed from a running
+/// [`MarketStream`](super::MarketStream).
+pub trait UpdateInstrumentMap {
+    type InstrumentKey;
+
+    /// Determines if [`InstrumentMapUpdate::Insert`]s are supported, and therefore if new
+    /// subscriptions can be actioned on a running connection.
+    ///
+    /// Some implementations (eg/ those requiring initial snapshots) cannot be updated at runtime.
+    /// Defaults to `true`.
+    fn insert_supported() -> bool {
+        true
+    }
+
+    /// Apply the [`InstrumentMapUpdate`] to the instrument [`Map`] used by [`Self`].
+    ///
+    /// Returns [`DataError::InstrumentMapUpdateUnsupported`] for an [`InstrumentMapUpdate::Insert`]
+    /// if [`Self::insert_supported`] is `false`.
+    fn update_instrument_map(
+        &mut self,
+        update: InstrumentMapUpdate<Self::InstrumentKey>,
+    ) -> Result<(), DataError>;
+}
+
+/// [`Transformer`] wrapper that applies any pending [`InstrumentMapUpdate`]s to the inner
+/// [`Transformer`] before transforming each input.
+#[derive(Debug)]
+pub struct ControlledTransformer<T>
+where
+    T: UpdateInstrumentMap,
+{
+    pub inner: T,
+    updates: mpsc::UnboundedReceiver<InstrumentMapUpdate<T::InstrumentKey>>,
+}
+
+impl<T> ControlledTransformer<T>
+where
+    T: UpdateInstrumentMap,
+{
+    /// Construct a new [`ControlledTransformer`] that applies [`InstrumentMapUpdate`]s received
+    /// via the provided [`mpsc::UnboundedReceiver`].
+    pub fn new(
+        inner: T,
+        updates: mpsc::UnboundedReceiver<InstrumentMapUpdate<T::InstrumentKey>>,
+    ) -> Self {
+        Self { inner, updates }
+    }
+}
+
+impl<T> Transformer for ControlledTransformer<T>
+where
+    T: Transformer + UpdateInstrumentMap,
+{
+    type Error = T::Error;
+    type Input = T::Input;
+    type Output = T::Output;
+    type OutputIter = T::OutputIter;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        while let Ok(update) = self.updates.try_recv() {
+            if let Err(error) = self.inner.update_instrument_map(update) {
+                warn!(?error, "failed to apply runtime InstrumentMapUpdate");
+            }
+        }
+
+        self.inner.transform(input)
+    }
+}
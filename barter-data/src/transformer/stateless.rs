@@ -1,4 +1,4 @@
-use super::ExchangeTransformer;
+use super::{ExchangeTransformer, InstrumentMapUpdate, UpdateInstrumentMap};
 use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
@@ -47,6 +47,27 @@ where
     }
 }
 
+impl<Exchange, InstrumentKey, Kind, Input> UpdateInstrumentMap
+    for StatelessTransformer<Exchange, InstrumentKey, Kind, Input>
+{
+    type InstrumentKey = InstrumentKey;
+
+    fn update_instrument_map(
+        &mut self,
+        update: InstrumentMapUpdate<InstrumentKey>,
+    ) -> Result<(), DataError> {
+        match update {
+            InstrumentMapUpdate::Insert(map) => self.instrument_map.0.extend(map.0),
+            InstrumentMapUpdate::Remove(subscription_ids) => {
+                for subscription_id in subscription_ids {
+                    self.instrument_map.0.remove(&subscription_id);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<Exchange, InstrumentKey, Kind, Input> Transformer
     for StatelessTransformer<Exchange, InstrumentKey, Kind, Input>
 where
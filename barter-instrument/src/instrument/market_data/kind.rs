@@ -4,21 +4,18 @@ use std::fmt::{Display, Formatter};
 
 /// Defines the type of [`MarketDataInstrument`](super::MarketDataInstrument) which is being
 /// traded on a given `base_quote` market.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum MarketDataInstrumentKind {
+    #[default]
     Spot,
     Future(FutureContract),
     Perpetual,
     Option(OptionContract),
 }
 
-impl Default for MarketDataInstrumentKind {
-    fn default() -> Self {
-        Self::Spot
-    }
-}
-
 impl Display for MarketDataInstrumentKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Batched asynchronous sink writing [`Metric`]s to an InfluxDB compatible HTTP endpoint.
+pub mod influx;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Serialize)]
 pub struct Metric {
@@ -36,6 +40,56 @@ pub enum Value {
     String(String),
 }
 
+impl Metric {
+    /// Serialise the [`Metric`] as an InfluxDB line protocol point with a millisecond precision
+    /// timestamp, eg/ `http_request_duration,http_method=GET duration=15u 1700000000000`.
+    ///
+    /// Tags are sorted by key, & non-finite float fields are omitted since they are not
+    /// supported by the line protocol. Returns `None` if the [`Metric`] has no valid fields.
+    pub fn to_line_protocol(&self) -> Option<String> {
+        let fields = self
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let value = match &field.value {
+                    Value::Float(value) if !value.is_finite() => return None,
+                    Value::Float(value) => value.to_string(),
+                    Value::Int(value) => format!("{value}i"),
+                    Value::UInt(value) => format!("{value}u"),
+                    Value::Bool(value) => value.to_string(),
+                    Value::String(value) => {
+                        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+                    }
+                };
+                Some(format!("{}={value}", escape_key(field.key)))
+            })
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            return None;
+        }
+
+        let mut tags = self.tags.iter().collect::<Vec<_>>();
+        tags.sort();
+
+        let mut line = self.name.replace(',', "\\,").replace(' ', "\\ ");
+        for tag in tags {
+            let _ = write!(line, ",{}={}", escape_key(tag.key), escape_key(&tag.value));
+        }
+        let _ = write!(line, " {} {}", fields.join(","), self.time);
+
+        Some(line)
+    }
+}
+
+/// Escape the comma, equals & space characters of a line protocol tag key, tag value, or
+/// field key.
+fn escape_key(key: &str) -> String {
+    key.replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
 impl<S> From<(&'static str, S)> for Tag
 where
     S: Into<String>,
@@ -107,3 +161,72 @@ impl From<String> for Value {
         Self::String(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_to_line_protocol() {
+        struct TestCase {
+            input: Metric,
+            expected: Option<&'static str>,
+        }
+
+        let cases = vec![
+            // TC0: Metric with every Value variant & unsorted tags
+            TestCase {
+                input: Metric {
+                    name: "http_request_duration",
+                    time: 1700000000000,
+                    tags: vec![Tag::new("path", "/order"), Tag::new("http_method", "GET")],
+                    fields: vec![
+                        Field::new("duration", 15_u64),
+                        Field::new("offset", -2_i64),
+                        Field::new("ratio", 0.5),
+                        Field::new("success", true),
+                        Field::new("status", "ok".to_string()),
+                    ],
+                },
+                expected: Some(
+                    "http_request_duration,http_method=GET,path=/order \
+                    duration=15u,offset=-2i,ratio=0.5,success=true,status=\"ok\" 1700000000000",
+                ),
+            },
+            // TC1: special characters are escaped & non-finite floats omitted
+            TestCase {
+                input: Metric {
+                    name: "fill event",
+                    time: 1,
+                    tags: vec![Tag::new("instrument", "btc,usdt=spot x")],
+                    fields: vec![
+                        Field::new("nan", f64::NAN),
+                        Field::new("note", "say \"hi\" \\".to_string()),
+                    ],
+                },
+                expected: Some(
+                    "fill\\ event,instrument=btc\\,usdt\\=spot\\ x note=\"say \\\"hi\\\" \\\\\" 1",
+                ),
+            },
+            // TC2: Metric without valid fields
+            TestCase {
+                input: Metric {
+                    name: "empty",
+                    time: 1,
+                    tags: vec![],
+                    fields: vec![Field::new("infinity", f64::INFINITY)],
+                },
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(
+                test.input.to_line_protocol().as_deref(),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
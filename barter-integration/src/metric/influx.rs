@@ -0,0 +1,342 @@
+use crate::{error::SocketError, metric::Metric};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+use url::Url;
+
+/// Configuration of an [`InfluxSink`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct InfluxConfig {
+    /// Influx compatible write endpoint, including any database or bucket query parameters.
+    ///
+    /// eg/ `http://localhost:8086/api/v2/write?org=barter&bucket=metrics`
+    pub write_url: String,
+
+    /// Optional API token sent via the `Authorization: Token <token>` header.
+    pub token: Option<String>,
+
+    /// Number of buffered points that triggers a write.
+    pub batch_size: usize,
+
+    /// Maximum milliseconds a buffered point waits before being written.
+    pub flush_interval_ms: u64,
+
+    /// [`RetryPolicy`] used when a write fails with a retryable error.
+    pub retry: RetryPolicy,
+}
+
+/// Exponential backoff policy used to retry failed [`InfluxSink`] writes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RetryPolicy {
+    /// Maximum number of write attempts of a batch, including the first attempt.
+    pub max_attempts: u32,
+
+    /// Initial backoff milliseconds after the first failed attempt.
+    pub backoff_ms_initial: u64,
+
+    /// Multiplier applied to the backoff after every failed attempt.
+    pub backoff_multiplier: u64,
+
+    /// Maximum backoff milliseconds between attempts.
+    pub backoff_ms_max: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            backoff_ms_initial: 125,
+            backoff_multiplier: 2,
+            backoff_ms_max: 10_000,
+        }
+    }
+}
+
+/// Batched asynchronous sink that serialises [`Metric`]s to the InfluxDB line protocol, and
+/// writes them to an Influx compatible HTTP endpoint.
+///
+/// Points are buffered until either the `batch_size` is reached or the `flush_interval_ms`
+/// elapses. Writes failing due to transport errors, `429 Too Many Requests` or `5xx` responses are
+/// retried using the [`RetryPolicy`], after which the batch is dropped.
+#[derive(Debug)]
+pub struct InfluxSink {
+    config: InfluxConfig,
+    url: Url,
+    http_client: reqwest::Client,
+    batch: Vec<String>,
+}
+
+impl InfluxSink {
+    /// Construct a new [`InfluxSink`] using the provided [`InfluxConfig`]. Fails if the
+    /// `write_url` is not a valid [`Url`].
+    pub fn new(config: InfluxConfig) -> Result<Self, url::ParseError> {
+        // Metric timestamps are milliseconds since the Unix epoch
+        let mut url = Url::parse(&config.write_url)?;
+        url.query_pairs_mut().append_pair("precision", "ms");
+
+        Ok(Self {
+            batch: Vec::with_capacity(config.batch_size),
+            config,
+            url,
+            http_client: reqwest::Client::new(),
+        })
+    }
+
+    /// Write every [`Metric`] received via the provided channel in batches, until every
+    /// transmitter is dropped & the remaining batch is flushed.
+    pub async fn run(mut self, mut metric_rx: mpsc::UnboundedReceiver<Metric>) {
+        let mut flush_interval =
+            tokio::time::interval(Duration::from_millis(self.config.flush_interval_ms.max(1)));
+        flush_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                metric = metric_rx.recv() => match metric {
+                    Some(metric) => {
+                        self.push(&metric);
+                        if self.batch.len() >= self.config.batch_size {
+                            self.flush_logged().await;
+                        }
+                    }
+                    None => {
+                        self.flush_logged().await;
+                        break;
+                    }
+                },
+                _ = flush_interval.tick() => self.flush_logged().await,
+            }
+        }
+    }
+
+    /// Buffer the line protocol point of the provided [`Metric`]. [`Metric`]s without valid
+    /// fields are skipped.
+    pub fn push(&mut self, metric: &Metric) {
+        if let Some(line) = metric.to_line_protocol() {
+            self.batch.push(line);
+        }
+    }
+
+    /// Write every buffered point, retrying retryable failures using the [`RetryPolicy`]. The
+    /// batch is cleared regardless of the outcome.
+    pub async fn flush(&mut self) -> Result<(), SocketError> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+
+        let body = self.batch.join("\n");
+        self.batch.clear();
+
+        let policy = self.config.retry;
+        let max_attempts = policy.max_attempts.max(1);
+        let mut backoff_ms = policy.backoff_ms_initial;
+
+        let mut attempt = 1;
+        loop {
+            match self.write(body.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(error) if attempt < max_attempts && is_retryable(&error) => {
+                    warn!(attempt, backoff_ms, %error, "failed to write Metrics, retrying");
+                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                    backoff_ms =
+                        (backoff_ms * policy.backoff_multiplier).min(policy.backoff_ms_max);
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Flush the buffered points, logging any failure since the batch cannot be recovered.
+    async fn flush_logged(&mut self) {
+        let points = self.batch.len();
+        if let Err(error) = self.flush().await {
+            error!(points, %error, "failed to write Metrics, dropping batch");
+        }
+    }
+
+    /// Execute a single write request of the provided line protocol body.
+    async fn write(&self, body: String) -> Result<(), SocketError> {
+        let mut request = self
+            .http_client
+            .post(self.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "text/plain; charset=utf-8")
+            .body(body);
+
+        if let Some(token) = &self.config.token {
+            request = request.header(reqwest::header::AUTHORIZATION, format!("Token {token}"));
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(SocketError::HttpResponse(
+                status,
+                response.text().await.unwrap_or_default(),
+            ))
+        }
+    }
+}
+
+/// Determines if a failed write should be retried.
+fn is_retryable(error: &SocketError) -> bool {
+    match error {
+        SocketError::Http(_) | SocketError::HttpTimeout(_) => true,
+        SocketError::HttpResponse(status, _) => {
+            status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::Field;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    /// HTTP request received by the local Influx stand-in.
+    #[derive(Debug)]
+    struct Received {
+        head: String,
+        body: String,
+    }
+
+    /// Read a single HTTP/1.1 request from the provided connection.
+    async fn read_request(stream: &mut TcpStream) -> Received {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 1024];
+
+        let head_end = loop {
+            let read = stream.read(&mut chunk).await.unwrap();
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+        let content_length = head
+            .lines()
+            .find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().unwrap())
+            })
+            .unwrap_or(0);
+
+        while buffer.len() < head_end + content_length {
+            let read = stream.read(&mut chunk).await.unwrap();
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+
+        Received {
+            head,
+            body: String::from_utf8_lossy(&buffer[head_end..head_end + content_length]).to_string(),
+        }
+    }
+
+    /// Spawn a local Influx stand-in responding to each request with the next status code.
+    async fn stand_in(statuses: Vec<u16>) -> (String, mpsc::UnboundedReceiver<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (received_tx, received_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                received_tx.send(read_request(&mut stream).await).unwrap();
+                let response = format!(
+                    "HTTP/1.1 {status} Stand-In\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (
+            format!("http://{address}/api/v2/write?org=barter&bucket=metrics"),
+            received_rx,
+        )
+    }
+
+    fn config(write_url: String) -> InfluxConfig {
+        InfluxConfig {
+            write_url,
+            token: Some("secret".to_string()),
+            batch_size: 2,
+            flush_interval_ms: 60_000,
+            retry: RetryPolicy {
+                max_attempts: 3,
+                backoff_ms_initial: 1,
+                backoff_multiplier: 2,
+                backoff_ms_max: 10,
+            },
+        }
+    }
+
+    fn metric(time: u64) -> Metric {
+        Metric {
+            name: "balance",
+            time,
+            tags: vec![],
+            fields: vec![Field::new("total", 100.0)],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_influx_sink_writes_batches_with_retry() {
+        // First write attempt fails with a retryable error, remaining writes succeed
+        let (write_url, mut received_rx) = stand_in(vec![503, 204, 204]).await;
+        let sink = InfluxSink::new(config(write_url)).unwrap();
+
+        let (metric_tx, metric_rx) = mpsc::unbounded_channel();
+        for time in 1..=3 {
+            metric_tx.send(metric(time)).unwrap();
+        }
+        drop(metric_tx);
+        sink.run(metric_rx).await;
+
+        let mut requests = Vec::new();
+        while let Ok(request) = received_rx.try_recv() {
+            requests.push(request);
+        }
+
+        assert_eq!(requests.len(), 3);
+        for request in requests.iter() {
+            assert!(request
+                .head
+                .starts_with("POST /api/v2/write?org=barter&bucket=metrics&precision=ms HTTP/1.1"));
+            assert!(request
+                .head
+                .to_ascii_lowercase()
+                .contains("authorization: token secret"));
+        }
+
+        // Full batch is retried, and the remaining point is flushed when the channel closes
+        assert_eq!(requests[0].body, "balance total=100 1\nbalance total=100 2");
+        assert_eq!(requests[1].body, requests[0].body);
+        assert_eq!(requests[2].body, "balance total=100 3");
+    }
+
+    #[tokio::test]
+    async fn test_influx_sink_flush_does_not_retry_client_errors() {
+        let (write_url, mut received_rx) = stand_in(vec![400]).await;
+        let mut sink = InfluxSink::new(config(write_url)).unwrap();
+
+        sink.push(&metric(1));
+        assert!(matches!(
+            sink.flush().await,
+            Err(SocketError::HttpResponse(status, _)) if status == reqwest::StatusCode::BAD_REQUEST
+        ));
+
+        assert!(received_rx.recv().await.is_some());
+        assert!(received_rx.try_recv().is_err());
+        assert!(sink.flush().await.is_ok(), "batch should be dropped");
+    }
+}
@@ -0,0 +1,93 @@
+use barter::{
+    backtest::{rank, Backtest, Config as BacktestConfig, MarketData, Metric},
+    execution::Fees,
+    statistic::{
+        metric::ratio::Ratio,
+        summary::{equity::Config as EquityConfig, trading::Config as StatisticConfig},
+    },
+    strategy::example::{Config as StrategyConfig, RSIStrategy},
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::candle::Candle,
+};
+use barter_instrument::{
+    exchange::ExchangeId,
+    instrument::market_data::{kind::MarketDataInstrumentKind, MarketDataInstrument},
+};
+use chrono::Duration;
+use std::fs;
+
+const DATA_HISTORIC_CANDLES_1H: &str = "barter/examples/data/candles_1h.json";
+
+fn main() {
+    // Shared read-only historical MarketData for each Market being backtested
+    let data = vec![MarketData::new(
+        (
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", MarketDataInstrumentKind::Spot),
+        ),
+        load_json_market_event_candles(),
+    )];
+
+    // Backtest harness constructing an RSIStrategy from each parameter set
+    let backtest = Backtest::new(
+        BacktestConfig {
+            starting_cash: 10_000.0,
+            default_order_value: 100.0,
+            fees: Fees {
+                exchange: 0.1,
+                slippage: 0.05,
+                network: 0.0,
+            },
+            statistic_config: StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            },
+            equity_config: EquityConfig {
+                interval: Duration::hours(1),
+                risk_free_return: 0.0,
+            },
+            parallelism: None,
+        },
+        data,
+        |params: &StrategyConfig, clock| RSIStrategy::new(*params).with_clock(clock),
+    );
+
+    // Run every parameter set in the grid in parallel
+    let grid = (2..=30).map(|rsi_period| StrategyConfig { rsi_period });
+    let mut results = backtest.run(grid).expect("failed to run backtest");
+
+    // Rank results by Sharpe Ratio, breaking ties with total PnL return
+    rank(&mut results, &[Metric::SharpeRatio, Metric::TotalReturn]);
+
+    for result in results.iter().take(5) {
+        println!(
+            "rsi_period: {:>2} | sharpe: {:>8.4} | daily equity sharpe: {:>8.4} | total return: {:>8.4} | trades: {}",
+            result.params.rsi_period,
+            Metric::SharpeRatio.score(&result.summary),
+            result.equity.tear_sheet.sharpe_ratio.daily(),
+            Metric::TotalReturn.score(&result.summary),
+            result.exited_positions.len()
+        );
+    }
+}
+
+fn load_json_market_event_candles() -> Vec<MarketEvent<MarketDataInstrument, DataKind>> {
+    let candles = fs::read_to_string(DATA_HISTORIC_CANDLES_1H).expect("failed to read file");
+
+    let candles =
+        serde_json::from_str::<Vec<Candle>>(&candles).expect("failed to parse candles String");
+
+    candles
+        .into_iter()
+        .map(|candle| MarketEvent {
+            time_exchange: candle.close_time,
+            time_received: candle.close_time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot)),
+            kind: DataKind::Candle(candle),
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use barter::{
+    clock::HistoricalClock,
     data::historical,
     engine::{trader::Trader, Engine},
     event::{Event, EventTx},
@@ -10,6 +11,7 @@ use barter::{
         allocator::DefaultAllocator, portfolio::MetaPortfolio,
         repository::in_memory::InMemoryRepository, risk::DefaultRisk,
     },
+    report::Report,
     statistic::summary::{
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
@@ -25,7 +27,6 @@ use barter_instrument::{
     instrument::market_data::{kind::MarketDataInstrumentKind, MarketDataInstrument},
     market::Market,
 };
-use chrono::Utc;
 use parking_lot::Mutex;
 use std::{collections::HashMap, fs, sync::Arc};
 use tokio::sync::mpsc;
@@ -51,6 +52,18 @@ async fn main() {
         ("btc", "usdt", MarketDataInstrumentKind::Spot),
     );
 
+    // Load historical MarketEvent Candles
+    let candles = load_json_market_event_candles();
+
+    // Create the Trader's HistoricalClock driven by the MarketEvent::time_exchange of each Candle,
+    // so all generated Events are deterministically timestamped in simulated time. Each Trader
+    // requires it's own HistoricalClock
+    let start = candles
+        .first()
+        .map(|candle| candle.time_exchange)
+        .expect("no historical candles loaded");
+    let clock = HistoricalClock::shared(start);
+
     // Build global shared-state MetaPortfolio (1-to-1 relationship with an Engine)
     let portfolio = Arc::new(Mutex::new(
         MetaPortfolio::builder()
@@ -67,6 +80,7 @@ async fn main() {
                 trading_days_per_year: 365,
                 risk_free_return: 0.0,
             })
+            .clock(Arc::clone(&clock))
             .build_and_init()
             .expect("failed to build & initialise MetaPortfolio"),
     ));
@@ -84,10 +98,10 @@ async fn main() {
             .command_rx(trader_command_rx)
             .event_tx(event_tx.clone())
             .portfolio(Arc::clone(&portfolio))
-            .data(historical::MarketFeed::new(
-                load_json_market_event_candles().into_iter(),
-            ))
-            .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+            .data(historical::MarketFeed::new(candles.into_iter()))
+            .strategy(
+                RSIStrategy::new(StrategyConfig { rsi_period: 14 }).with_clock(Arc::clone(&clock)),
+            )
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
                     exchange: 0.1,
@@ -95,18 +109,19 @@ async fn main() {
                     network: 0.0,
                 },
             }))
+            .clock(clock)
             .build()
             .expect("failed to build trader"),
     );
 
     // Build Engine (1-to-many relationship with Traders)
     // Create HashMap<Market, trader_command_tx> so Engine can route Commands to Traders
-    let trader_command_txs = HashMap::from([(market, trader_command_tx)]);
+    let trader_command_txs = HashMap::from([(market.clone(), trader_command_tx)]);
 
     let engine = Engine::builder()
         .engine_id(engine_id)
         .command_rx(command_rx)
-        .portfolio(portfolio)
+        .portfolio(Arc::clone(&portfolio))
         .traders(traders)
         .trader_command_txs(trader_command_txs)
         .statistics_summary(TradingSummary::init(StatisticConfig {
@@ -120,6 +135,26 @@ async fn main() {
     // Run Engine trading & listen to Events it produces
     tokio::spawn(listen_to_engine_events(event_rx));
     engine.run().await;
+
+    // Archive the trading session as a JSON Report & an offline HTML tear sheet
+    let report = Report::from_portfolio(
+        StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        },
+        &mut portfolio.lock(),
+        &[market],
+    )
+    .expect("failed to generate report");
+
+    let report_dir = std::env::temp_dir();
+    report
+        .write_json(report_dir.join("barter_report.json"))
+        .expect("failed to write JSON report");
+    report
+        .write_html(report_dir.join("barter_report.html"))
+        .expect("failed to write HTML tear sheet");
 }
 
 fn load_json_market_event_candles() -> Vec<MarketEvent<MarketDataInstrument, DataKind>> {
@@ -132,7 +167,7 @@ fn load_json_market_event_candles() -> Vec<MarketEvent<MarketDataInstrument, Dat
         .into_iter()
         .map(|candle| MarketEvent {
             time_exchange: candle.close_time,
-            time_received: Utc::now(),
+            time_received: candle.close_time,
             exchange: ExchangeId::BinanceSpot,
             instrument: MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot)),
             kind: DataKind::Candle(candle),
@@ -159,8 +194,9 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
-            Event::OrderUpdate => {
+            Event::OrderUpdate(order_update) => {
                 // OrderUpdate Event occurred in Engine
+                println!("{order_update:?}");
             }
             Event::Fill(fill_event) => {
                 // Fill Event occurred in Engine
@@ -182,6 +218,14 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::Valuation(valuation) => {
+                // BalanceLedger Valuation Event occurred in Engine
+                println!("{valuation:?}");
+            }
+            Event::Error(trader_failure) => {
+                // Trader failed to handle an Event
+                println!("{trader_failure:?}");
+            }
         }
     }
 }
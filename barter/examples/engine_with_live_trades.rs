@@ -124,7 +124,7 @@ async fn main() {
     // Run Engine trading & listen to Events it produces
     tokio::spawn(listen_to_engine_events(event_rx));
 
-    let _ = tokio::time::timeout(ENGINE_RUN_TIMEOUT, engine.run()).await;
+    let _ = tokio::time::timeout(ENGINE_RUN_TIMEOUT, engine.run_async()).await;
 }
 
 async fn stream_market_event_trades(
@@ -180,8 +180,9 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
-            Event::OrderUpdate => {
+            Event::OrderUpdate(order_update) => {
                 // OrderUpdate Event occurred in Engine
+                println!("{order_update:?}");
             }
             Event::Fill(fill_event) => {
                 // Fill Event occurred in Engine
@@ -203,6 +204,14 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::Valuation(valuation) => {
+                // BalanceLedger Valuation Event occurred in Engine
+                println!("{valuation:?}");
+            }
+            Event::Error(trader_failure) => {
+                // Trader failed to handle an Event
+                println!("{trader_failure:?}");
+            }
         }
     }
 }
@@ -0,0 +1,25 @@
+use crate::{
+    engine::error::EngineError,
+    portfolio::{error::PortfolioError, repository::error::RepositoryError},
+};
+use barter_instrument::market::Market;
+use thiserror::Error;
+
+/// All errors generated in the barter::backtest module.
+#[derive(Error, Debug)]
+pub enum BacktestError {
+    #[error("No historical MarketEvents provided for market: {0:?}")]
+    NoMarketData(Market),
+
+    #[error("Invalid walk-forward configuration: {0}")]
+    InvalidWalkForward(&'static str),
+
+    #[error("Failed to construct backtest Trader: {0}")]
+    Engine(#[from] EngineError),
+
+    #[error("Portfolio error: {0}")]
+    Portfolio(#[from] PortfolioError),
+
+    #[error("Failed to interact with repository")]
+    RepositoryInteraction(#[from] RepositoryError),
+}
@@ -0,0 +1,504 @@
+use self::error::BacktestError;
+use crate::{
+    clock::{HistoricalClock, SharedClock},
+    data::historical,
+    engine::trader::Trader,
+    event::{Event, MessageTransmitter},
+    execution::{
+        simulated::{Config as ExecutionConfig, SimulatedExecution},
+        Fees,
+    },
+    portfolio::{
+        allocator::DefaultAllocator,
+        portfolio::MetaPortfolio,
+        position::Position,
+        repository::{in_memory::InMemoryRepository, PositionHandler, StatisticHandler},
+        risk::DefaultRisk,
+    },
+    statistic::{
+        metric::ratio::Ratio,
+        summary::{
+            benchmark::BenchmarkSummary,
+            equity::{Config as EquityConfig, EquitySummary},
+            trading::{Config as StatisticConfig, TradingSummary},
+        },
+    },
+    strategy::SignalGenerator,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::{
+    instrument::market_data::MarketDataInstrument,
+    market::{Market, MarketId},
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    num::NonZeroUsize,
+    sync::{atomic, atomic::AtomicUsize, Arc},
+    thread,
+};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Barter backtest module specific errors.
+pub mod error;
+
+/// Walk-forward analysis that optimises strategy parameters over rolling in-sample windows, and
+/// evaluates them over the subsequent out-of-sample windows.
+pub mod walk_forward;
+
+/// Historical [`MarketEvent`]s for a single [`Market`], shared read-only between every backtest
+/// run.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarketData {
+    pub market: Market,
+    pub events: Vec<MarketEvent<MarketDataInstrument, DataKind>>,
+}
+
+impl MarketData {
+    /// Constructs a new [`MarketData`] using the provided [`Market`] & historical
+    /// [`MarketEvent`]s, which are expected to be in chronological order.
+    pub fn new<M>(market: M, events: Vec<MarketEvent<MarketDataInstrument, DataKind>>) -> Self
+    where
+        M: Into<Market>,
+    {
+        Self {
+            market: market.into(),
+            events,
+        }
+    }
+}
+
+/// Configuration shared by every run of a [`Backtest`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Cash balance each isolated [`MetaPortfolio`] starts with.
+    pub starting_cash: f64,
+    /// Default [`OrderEvent`](crate::portfolio::OrderEvent) value used by the
+    /// [`DefaultAllocator`].
+    pub default_order_value: f64,
+    /// Simulated fee percentages used by the [`SimulatedExecution`].
+    pub fees: Fees,
+    /// Configuration used to initialise the [`TradingSummary`] of every run.
+    pub statistic_config: StatisticConfig,
+    /// Configuration of the mark-to-market [`EquitySummary`] sampled during every run.
+    pub equity_config: EquityConfig,
+    /// Maximum number of runs executed in parallel. Defaults to the available parallelism.
+    pub parallelism: Option<usize>,
+}
+
+/// Outcome of backtesting one set of strategy parameters against one [`Market`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BacktestResult<Params> {
+    pub params: Params,
+    pub market: Market,
+    pub summary: TradingSummary,
+    /// Mark-to-market equity curve of the run sampled on a fixed schedule.
+    pub equity: EquitySummary,
+    /// Statistics of the run relative to buy-and-hold of the backtested [`Market`].
+    pub benchmark: BenchmarkSummary,
+    pub exited_positions: Vec<Position>,
+}
+
+/// [`TradingSummary`] metric used to rank [`BacktestResult`]s. Every metric is oriented so that
+/// higher values are better (eg/ [`Metric::MaxDrawdown`] is a negative fraction).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum Metric {
+    SharpeRatio,
+    SortinoRatio,
+    CalmarRatio,
+    TotalReturn,
+    MeanReturn,
+    MaxDrawdown,
+}
+
+impl Metric {
+    /// Returns the value of this [`Metric`] for the provided [`TradingSummary`].
+    pub fn score(&self, summary: &TradingSummary) -> f64 {
+        match self {
+            Metric::SharpeRatio => summary.tear_sheet.sharpe_ratio.ratio(),
+            Metric::SortinoRatio => summary.tear_sheet.sortino_ratio.ratio(),
+            Metric::CalmarRatio => summary.tear_sheet.calmar_ratio.ratio(),
+            Metric::TotalReturn => summary.pnl_returns.total.sum,
+            Metric::MeanReturn => summary.pnl_returns.total.mean,
+            Metric::MaxDrawdown => summary.drawdown.max_drawdown.drawdown.drawdown,
+        }
+    }
+
+    /// Compares two [`TradingSummary`]s by this [`Metric`], ordering the better first. Undefined
+    /// (NaN) values are ordered last.
+    pub fn compare(&self, a: &TradingSummary, b: &TradingSummary) -> Ordering {
+        let score = |summary| match self.score(summary) {
+            score if score.is_nan() => f64::NEG_INFINITY,
+            score => score,
+        };
+
+        score(b).total_cmp(&score(a))
+    }
+}
+
+/// Ranks [`BacktestResult`]s best first by the provided [`Metric`]s, with each subsequent
+/// [`Metric`] used to break ties.
+pub fn rank<Params>(results: &mut [BacktestResult<Params>], metrics: &[Metric]) {
+    results.sort_by(|a, b| {
+        metrics
+            .iter()
+            .map(|metric| metric.compare(&a.summary, &b.summary))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+/// Backtest harness that runs every combination of a strategy parameter grid against shared
+/// read-only [`MarketData`].
+///
+/// Each parameter set is backtested against each [`Market`] by a dedicated [`Trader`] with an
+/// isolated [`MetaPortfolio`], [`SimulatedExecution`] & [`HistoricalClock`], so runs are
+/// deterministic and executed in parallel.
+#[derive(Debug)]
+pub struct Backtest<Factory> {
+    config: Config,
+    data: Vec<MarketData>,
+    factory: Factory,
+}
+
+impl<Factory> Backtest<Factory> {
+    /// Constructs a new [`Backtest`] using the provided [`Config`], [`MarketData`] and strategy
+    /// factory. The factory constructs a strategy from a parameter set & the run's
+    /// [`SharedClock`].
+    pub fn new(config: Config, data: Vec<MarketData>, factory: Factory) -> Self {
+        Self {
+            config,
+            data,
+            factory,
+        }
+    }
+
+    /// Backtest every parameter set in the grid against every [`Market`], returning a
+    /// [`BacktestResult`] for each combination in grid order.
+    pub fn run<Params, Strategy>(
+        &self,
+        grid: impl IntoIterator<Item = Params>,
+    ) -> Result<Vec<BacktestResult<Params>>, BacktestError>
+    where
+        Factory: Fn(&Params, SharedClock) -> Strategy + Sync,
+        Params: Clone + Send + Sync,
+        Strategy: SignalGenerator + Send,
+    {
+        let grid = grid.into_iter().collect::<Vec<_>>();
+        let jobs = grid
+            .iter()
+            .flat_map(|params| self.data.iter().map(move |data| (params, data)))
+            .collect::<Vec<_>>();
+
+        let workers = self
+            .config
+            .parallelism
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+            .clamp(1, jobs.len().max(1));
+
+        // Workers pull the next job until none remain, tagging each result with it's job index
+        let next_job = AtomicUsize::new(0);
+        let mut results = thread::scope(|scope| {
+            let workers = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        loop {
+                            let index = next_job.fetch_add(1, atomic::Ordering::Relaxed);
+                            let Some((params, data)) = jobs.get(index) else {
+                                break results;
+                            };
+                            results.push((index, self.run_one(*params, data)));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("backtest worker panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        // Restore grid order
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Backtest a single parameter set against a single [`Market`].
+    fn run_one<Params, Strategy>(
+        &self,
+        params: &Params,
+        data: &MarketData,
+    ) -> Result<BacktestResult<Params>, BacktestError>
+    where
+        Factory: Fn(&Params, SharedClock) -> Strategy,
+        Params: Clone,
+        Strategy: SignalGenerator + Send,
+    {
+        let start = data
+            .events
+            .first()
+            .map(|event| event.time_exchange)
+            .ok_or_else(|| BacktestError::NoMarketData(data.market.clone()))?;
+
+        let engine_id = Uuid::new_v4();
+        let clock = HistoricalClock::shared(start);
+
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![data.market.clone()])
+                .starting_cash(self.config.starting_cash)
+                .repository(InMemoryRepository::<TradingSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: self.config.default_order_value,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(self.config.statistic_config)
+                .equity_config(self.config.equity_config)
+                .benchmark(data.market.clone())
+                .clock(Arc::clone(&clock))
+                .build_and_init()?,
+        ));
+
+        // Command transmitter must outlive the Trader, else it synthesises a Command::Terminate
+        let (_command_tx, command_rx) = mpsc::channel(1);
+
+        let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+            .engine_id(engine_id)
+            .market(data.market.clone())
+            .command_rx(command_rx)
+            .event_tx(DiscardEvents)
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(data.events.iter().cloned()))
+            .strategy((self.factory)(params, Arc::clone(&clock)))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: self.config.fees,
+            }))
+            .clock(clock)
+            .build()?;
+
+        trader.run();
+
+        let mut portfolio = portfolio.lock();
+        Ok(BacktestResult {
+            params: params.clone(),
+            market: data.market.clone(),
+            summary: portfolio.get_statistics(&MarketId::from(&data.market))?,
+            equity: portfolio.equity().clone(),
+            benchmark: portfolio.benchmark_summary().unwrap_or_default(),
+            exited_positions: portfolio.get_exited_positions(engine_id)?,
+        })
+    }
+}
+
+/// [`MessageTransmitter`] that discards every [`Event`] generated during a backtest run.
+#[derive(Copy, Clone, Debug)]
+struct DiscardEvents;
+
+impl MessageTransmitter<Event> for DiscardEvents {
+    fn send(&mut self, _: Event) {}
+
+    fn send_many(&mut self, _: Vec<Event>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        statistic::{metric::EquityPoint, summary::Initialiser},
+        strategy::example::{Config as StrategyConfig, RSIStrategy},
+    };
+    use barter_data::subscription::candle::Candle;
+    use barter_instrument::{
+        exchange::ExchangeId, instrument::market_data::kind::MarketDataInstrumentKind,
+    };
+    use chrono::{Duration, TimeZone, Utc};
+
+    pub(super) fn market_data(market: Market) -> MarketData {
+        let start = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let events = (0..200)
+            .map(|index| {
+                let close = 100.0 + 10.0 * (index as f64 / 8.0).sin();
+                let time = start + Duration::hours(index);
+                MarketEvent {
+                    time_exchange: time,
+                    time_received: time,
+                    exchange: market.exchange,
+                    instrument: market.instrument.clone(),
+                    kind: DataKind::Candle(Candle {
+                        close_time: time,
+                        open: close,
+                        high: close,
+                        low: close,
+                        close,
+                        volume: 1.0,
+                        trade_count: 1,
+                    }),
+                }
+            })
+            .collect();
+
+        MarketData::new(market, events)
+    }
+
+    pub(super) fn config() -> Config {
+        Config {
+            starting_cash: 10_000.0,
+            default_order_value: 100.0,
+            fees: Fees::default(),
+            statistic_config: StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            },
+            equity_config: EquityConfig::default(),
+            parallelism: Some(4),
+        }
+    }
+
+    pub(super) fn equity() -> EquitySummary {
+        EquitySummary::new(config().equity_config, EquityPoint::default()).unwrap()
+    }
+
+    fn summary(sharpe: f64, total: f64) -> TradingSummary {
+        let mut summary = TradingSummary::init(config().statistic_config);
+        summary.tear_sheet.sharpe_ratio.sharpe_ratio_per_trade = sharpe;
+        summary.pnl_returns.total.sum = total;
+        summary
+    }
+
+    #[test]
+    fn test_backtest_run_is_deterministic_and_in_grid_order() {
+        let markets = [
+            Market::new(
+                ExchangeId::BinanceSpot,
+                ("btc", "usdt", MarketDataInstrumentKind::Spot),
+            ),
+            Market::new(
+                ExchangeId::BinanceSpot,
+                ("eth", "usdt", MarketDataInstrumentKind::Spot),
+            ),
+        ];
+
+        let backtest = Backtest::new(
+            config(),
+            markets.iter().cloned().map(market_data).collect(),
+            |params: &StrategyConfig, clock| RSIStrategy::new(*params).with_clock(clock),
+        );
+
+        let grid = [5, 10, 14].map(|rsi_period| StrategyConfig { rsi_period });
+
+        let first = backtest.run(grid).unwrap();
+        let second = backtest.run(grid).unwrap();
+
+        assert_eq!(first.len(), grid.len() * markets.len());
+        for (index, result) in first.iter().enumerate() {
+            assert_eq!(result.params, grid[index / markets.len()]);
+            assert_eq!(result.market, markets[index % markets.len()]);
+            assert!(!result.exited_positions.is_empty());
+
+            // 200 hourly candles sampled by the daily mark-to-market equity curve
+            assert_eq!(result.equity.curve.len(), 9);
+
+            // Benchmark is sampled on the same schedule, pairing every equity curve return
+            assert_eq!(result.benchmark.strategy.count, 8);
+        }
+
+        // Position identifiers are derived from each run's engine_id, so compare statistics
+        let summaries = |results: &[BacktestResult<StrategyConfig>]| {
+            results
+                .iter()
+                .map(|result| result.summary)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(summaries(&first), summaries(&second));
+    }
+
+    #[test]
+    fn test_backtest_run_with_no_market_data() {
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", MarketDataInstrumentKind::Spot),
+        );
+
+        let backtest = Backtest::new(
+            config(),
+            vec![MarketData::new(market, vec![])],
+            |params: &StrategyConfig, _| RSIStrategy::new(*params),
+        );
+
+        assert!(matches!(
+            backtest.run([StrategyConfig { rsi_period: 14 }]),
+            Err(BacktestError::NoMarketData(_))
+        ));
+    }
+
+    #[test]
+    fn test_rank() {
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", MarketDataInstrumentKind::Spot),
+        );
+        let result = |params: usize, sharpe: f64, total: f64| BacktestResult {
+            params,
+            market: market.clone(),
+            summary: summary(sharpe, total),
+            equity: equity(),
+            benchmark: BenchmarkSummary::default(),
+            exited_positions: vec![],
+        };
+
+        struct TestCase {
+            input: Vec<BacktestResult<usize>>,
+            metrics: Vec<Metric>,
+            expected: Vec<usize>,
+        }
+
+        let cases = vec![
+            // TC0: rank by single metric, best first
+            TestCase {
+                input: vec![
+                    result(0, 1.0, 0.0),
+                    result(1, 3.0, 0.0),
+                    result(2, 2.0, 0.0),
+                ],
+                metrics: vec![Metric::SharpeRatio],
+                expected: vec![1, 2, 0],
+            },
+            // TC1: NaN ranked last
+            TestCase {
+                input: vec![
+                    result(0, f64::NAN, 0.0),
+                    result(1, -1.0, 0.0),
+                    result(2, 2.0, 0.0),
+                ],
+                metrics: vec![Metric::SharpeRatio],
+                expected: vec![2, 1, 0],
+            },
+            // TC2: subsequent metric breaks ties
+            TestCase {
+                input: vec![
+                    result(0, 1.0, 5.0),
+                    result(1, 1.0, 9.0),
+                    result(2, 0.0, 20.0),
+                ],
+                metrics: vec![Metric::SharpeRatio, Metric::TotalReturn],
+                expected: vec![1, 0, 2],
+            },
+        ];
+
+        for (index, mut test) in cases.into_iter().enumerate() {
+            rank(&mut test.input, &test.metrics);
+            let actual = test
+                .input
+                .iter()
+                .map(|result| result.params)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
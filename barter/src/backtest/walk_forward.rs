@@ -0,0 +1,466 @@
+use super::{error::BacktestError, Backtest, BacktestResult, MarketData, Metric};
+use crate::{
+    clock::SharedClock,
+    statistic::{
+        de_duration_from_secs,
+        metric::EquityPoint,
+        se_duration_as_secs,
+        summary::{
+            trading::{Config as StatisticConfig, TradingSummary},
+            Initialiser, PositionSummariser,
+        },
+    },
+    strategy::SignalGenerator,
+};
+use barter_instrument::market::Market;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for a walk-forward analysis via [`Backtest::walk_forward`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Duration of each in-sample window used to optimise the strategy parameters.
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub in_sample: Duration,
+    /// Duration of each out-of-sample window used to evaluate the optimised parameters. Windows
+    /// roll forward by this duration, so out-of-sample windows are contiguous.
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub out_of_sample: Duration,
+    /// [`Metric`] the strategy parameters are optimised by in-sample.
+    pub metric: Metric,
+}
+
+/// Half-open `[start, end)` time window of historical [`MarketData`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Window {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Window {
+    /// Returns the [`MarketData`] with [`MarketEvent`](barter_data::event::MarketEvent)s inside
+    /// this [`Window`].
+    fn slice(&self, data: &MarketData) -> MarketData {
+        let start = data
+            .events
+            .partition_point(|event| event.time_exchange < self.start);
+        let end = data
+            .events
+            .partition_point(|event| event.time_exchange < self.end);
+
+        MarketData {
+            market: data.market.clone(),
+            events: data.events[start..end].to_vec(),
+        }
+    }
+}
+
+/// Outcome of a single walk-forward step for one [`Market`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct WalkForwardWindow<Params> {
+    pub in_sample: Window,
+    pub out_of_sample: Window,
+    /// Best in-sample [`BacktestResult`], containing the optimised parameters.
+    pub optimised: BacktestResult<Params>,
+    /// Out-of-sample [`BacktestResult`] of the optimised parameters.
+    pub evaluated: BacktestResult<Params>,
+}
+
+/// Outcome of a walk-forward analysis for one [`Market`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct WalkForwardResult<Params> {
+    pub market: Market,
+    pub windows: Vec<WalkForwardWindow<Params>>,
+    /// Out-of-sample equity curves stitched together, compounding each window from the
+    /// previous window's closing equity.
+    pub equity_curve: Vec<EquityPoint>,
+    /// [`TradingSummary`] of every out-of-sample exited
+    /// [`Position`](crate::portfolio::position::Position) along the stitched equity curve.
+    pub summary: TradingSummary,
+}
+
+impl<Factory> Backtest<Factory> {
+    /// Run a walk-forward analysis over the [`Backtest`] [`MarketData`].
+    ///
+    /// For each rolling window, every parameter set in the grid is backtested in-sample, and the
+    /// best by the configured [`Metric`] is backtested over the subsequent out-of-sample window.
+    /// Returns a [`WalkForwardResult`] per [`Market`] with the out-of-sample equity curves
+    /// stitched together.
+    pub fn walk_forward<Params, Strategy>(
+        &self,
+        grid: impl IntoIterator<Item = Params>,
+        config: Config,
+    ) -> Result<Vec<WalkForwardResult<Params>>, BacktestError>
+    where
+        Factory: Fn(&Params, SharedClock) -> Strategy + Sync,
+        Params: Clone + Send + Sync,
+        Strategy: SignalGenerator + Send,
+    {
+        if config.in_sample <= Duration::zero() || config.out_of_sample <= Duration::zero() {
+            return Err(BacktestError::InvalidWalkForward(
+                "in_sample & out_of_sample durations must be positive",
+            ));
+        }
+
+        let grid = grid.into_iter().collect::<Vec<_>>();
+        let mut windows_per_market = vec![Vec::new(); self.data.len()];
+
+        for (in_sample, out_of_sample) in self.windows(&config) {
+            // Markets with no MarketEvents in either window are skipped for this step
+            let (markets, (in_sample_data, out_of_sample_data)): (Vec<_>, (Vec<_>, Vec<_>)) = self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(index, data)| (index, (in_sample.slice(data), out_of_sample.slice(data))))
+                .filter(|(_, (in_sample, out_of_sample))| {
+                    !in_sample.events.is_empty() && !out_of_sample.events.is_empty()
+                })
+                .unzip();
+
+            if markets.is_empty() {
+                continue;
+            }
+
+            // Optimise parameters in-sample, selecting the best per Market
+            let optimised = Backtest::new(self.config, in_sample_data, &self.factory)
+                .run(grid.iter().cloned())?
+                .into_iter()
+                .fold(Vec::<BacktestResult<Params>>::new(), |mut best, result| {
+                    match best.iter_mut().find(|best| best.market == result.market) {
+                        Some(best) => {
+                            if config
+                                .metric
+                                .compare(&result.summary, &best.summary)
+                                .is_lt()
+                            {
+                                *best = result;
+                            }
+                        }
+                        None => best.push(result),
+                    }
+                    best
+                });
+
+            // Evaluate the optimised parameters out-of-sample
+            for ((index, data), optimised) in
+                markets.into_iter().zip(out_of_sample_data).zip(optimised)
+            {
+                let evaluated = Backtest::new(self.config, vec![data], &self.factory)
+                    .run([optimised.params.clone()])?
+                    .remove(0);
+
+                windows_per_market[index].push(WalkForwardWindow {
+                    in_sample,
+                    out_of_sample,
+                    optimised,
+                    evaluated,
+                });
+            }
+        }
+
+        Ok(self
+            .data
+            .iter()
+            .zip(windows_per_market)
+            .map(|(data, windows)| {
+                let start = windows
+                    .first()
+                    .map(|window| window.out_of_sample.start)
+                    .or_else(|| data.events.first().map(|event| event.time_exchange))
+                    .unwrap_or_default();
+
+                let (equity_curve, summary) = stitch(
+                    self.config.starting_cash,
+                    self.config.statistic_config,
+                    start,
+                    windows.iter().map(|window| &window.evaluated),
+                );
+
+                WalkForwardResult {
+                    market: data.market.clone(),
+                    windows,
+                    equity_curve,
+                    summary,
+                }
+            })
+            .collect())
+    }
+
+    /// Generates the rolling in-sample & out-of-sample [`Window`]s spanning the [`MarketData`].
+    fn windows(&self, config: &Config) -> Vec<(Window, Window)> {
+        let times = self
+            .data
+            .iter()
+            .flat_map(|data| [data.events.first(), data.events.last()])
+            .flatten()
+            .map(|event| event.time_exchange);
+
+        let (Some(first), Some(last)) = (times.clone().min(), times.max()) else {
+            return vec![];
+        };
+
+        generate_windows(first, last, config)
+    }
+}
+
+/// Generates rolling in-sample & out-of-sample [`Window`]s from `first` until the out-of-sample
+/// window no longer starts at or before `last`.
+fn generate_windows(
+    first: DateTime<Utc>,
+    last: DateTime<Utc>,
+    config: &Config,
+) -> Vec<(Window, Window)> {
+    let mut windows = Vec::new();
+    let mut start = first;
+
+    loop {
+        let split = start + config.in_sample;
+        if split > last {
+            break windows;
+        }
+
+        windows.push((
+            Window { start, end: split },
+            Window {
+                start: split,
+                end: split + config.out_of_sample,
+            },
+        ));
+
+        start += config.out_of_sample;
+    }
+}
+
+/// Stitches the out-of-sample [`BacktestResult`]s into a single compounding equity curve, where
+/// each window starts from the previous window's closing equity. Returns the equity curve and the
+/// [`TradingSummary`] of the stitched exited Positions.
+fn stitch<'a, Params: 'a>(
+    starting_cash: f64,
+    statistic_config: StatisticConfig,
+    start: DateTime<Utc>,
+    results: impl IntoIterator<Item = &'a BacktestResult<Params>>,
+) -> (Vec<EquityPoint>, TradingSummary) {
+    let mut equity = starting_cash;
+    let mut equity_curve = vec![EquityPoint {
+        time: start,
+        total: starting_cash,
+    }];
+    let mut summary = TradingSummary::init_at(statistic_config, start);
+
+    for result in results {
+        let scale = equity / starting_cash;
+
+        for position in &result.exited_positions {
+            let mut position = position.clone();
+            if let Some(balance) = position.meta.exit_balance.as_mut() {
+                balance.total *= scale;
+                balance.available *= scale;
+                equity = balance.total;
+                equity_curve.push(EquityPoint::from(*balance));
+            }
+            summary.update(&position);
+        }
+    }
+
+    (equity_curve, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backtest::tests::{config, equity, market_data},
+        portfolio::Balance,
+        statistic::summary::benchmark::BenchmarkSummary,
+        strategy::example::{Config as StrategyConfig, RSIStrategy},
+        test_util,
+    };
+    use barter_instrument::{
+        exchange::ExchangeId, instrument::market_data::kind::MarketDataInstrumentKind,
+    };
+    use chrono::TimeZone;
+
+    fn time(hours: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hours)
+    }
+
+    fn walk_forward_config(in_sample: i64, out_of_sample: i64) -> Config {
+        Config {
+            in_sample: Duration::hours(in_sample),
+            out_of_sample: Duration::hours(out_of_sample),
+            metric: Metric::SharpeRatio,
+        }
+    }
+
+    #[test]
+    fn test_generate_windows() {
+        struct TestCase {
+            last: DateTime<Utc>,
+            config: Config,
+            expected: Vec<(i64, i64, i64)>,
+        }
+
+        let cases = vec![
+            // TC0: data shorter than in-sample window generates no windows
+            TestCase {
+                last: time(5),
+                config: walk_forward_config(10, 5),
+                expected: vec![],
+            },
+            // TC1: out-of-sample windows roll forward contiguously
+            TestCase {
+                last: time(20),
+                config: walk_forward_config(10, 5),
+                expected: vec![(0, 10, 15), (5, 15, 20), (10, 20, 25)],
+            },
+            // TC2: final out-of-sample window may extend beyond the data
+            TestCase {
+                last: time(12),
+                config: walk_forward_config(10, 4),
+                expected: vec![(0, 10, 14)],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = generate_windows(time(0), test.last, &test.config);
+            let expected = test
+                .expected
+                .into_iter()
+                .map(|(start, split, end)| {
+                    (
+                        Window {
+                            start: time(start),
+                            end: time(split),
+                        },
+                        Window {
+                            start: time(split),
+                            end: time(end),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_stitch_compounds_out_of_sample_equity() {
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", MarketDataInstrumentKind::Spot),
+        );
+        let result = |exits: &[(i64, f64)]| BacktestResult {
+            params: (),
+            market: market.clone(),
+            summary: TradingSummary::init(config().statistic_config),
+            equity: equity(),
+            benchmark: BenchmarkSummary::default(),
+            exited_positions: exits
+                .iter()
+                .map(|(hours, total)| {
+                    let mut position = test_util::position();
+                    position.meta.exit_balance = Some(Balance {
+                        time: time(*hours),
+                        total: *total,
+                        available: *total,
+                    });
+                    position
+                })
+                .collect(),
+        };
+
+        let results = [
+            result(&[(1, 11_000.0)]),
+            result(&[]),
+            result(&[(3, 9_000.0), (4, 12_000.0)]),
+        ];
+
+        let (equity_curve, summary) =
+            stitch(10_000.0, config().statistic_config, time(0), results.iter());
+
+        let actual = equity_curve
+            .iter()
+            .map(|point| (point.time, point.total))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            (time(0), 10_000.0),
+            (time(1), 11_000.0),
+            (time(3), 9_900.0),
+            (time(4), 13_200.0),
+        ];
+
+        assert_eq!(actual.len(), expected.len());
+        for ((actual_time, actual), (expected_time, expected)) in actual.into_iter().zip(expected) {
+            assert_eq!(actual_time, expected_time);
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        }
+        assert_eq!(summary.pnl_returns.total.count, 3);
+    }
+
+    #[test]
+    fn test_walk_forward() {
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", MarketDataInstrumentKind::Spot),
+        );
+        let data = market_data(market.clone());
+
+        let backtest = Backtest::new(config(), vec![data], |params: &StrategyConfig, clock| {
+            RSIStrategy::new(*params).with_clock(clock)
+        });
+
+        let results = backtest
+            .walk_forward(
+                [5, 10, 14].map(|rsi_period| StrategyConfig { rsi_period }),
+                walk_forward_config(100, 50),
+            )
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result.market, market);
+        assert_eq!(result.windows.len(), 2);
+
+        for window in &result.windows {
+            assert_eq!(window.in_sample.end, window.out_of_sample.start);
+            assert_eq!(window.optimised.params, window.evaluated.params);
+            assert!(window
+                .evaluated
+                .exited_positions
+                .iter()
+                .all(
+                    |position| position.meta.enter_time >= window.out_of_sample.start
+                        && position.meta.update_time < window.out_of_sample.end
+                ));
+        }
+
+        let exited = result
+            .windows
+            .iter()
+            .map(|window| window.evaluated.exited_positions.len())
+            .sum::<usize>();
+        assert_eq!(result.equity_curve.len(), exited + 1);
+        assert_eq!(result.summary.pnl_returns.total.count, exited as u64);
+    }
+
+    #[test]
+    fn test_walk_forward_invalid_config() {
+        let backtest = Backtest::new(config(), vec![], |params: &StrategyConfig, _| {
+            RSIStrategy::new(*params)
+        });
+
+        assert!(matches!(
+            backtest.walk_forward(
+                [StrategyConfig { rsi_period: 14 }],
+                walk_forward_config(0, 10)
+            ),
+            Err(BacktestError::InvalidWalkForward(_))
+        ));
+    }
+}
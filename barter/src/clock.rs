@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::{fmt::Debug, sync::Arc};
+
+/// Shared handle to a [`Clock`] that is cheaply cloned between the components of a
+/// [`Trader`](crate::engine::trader::Trader) (eg/ Strategy & Execution), so they all agree on the
+/// current time.
+///
+/// [`Trader`](crate::engine::trader::Trader)s run concurrently, so each requires it's own
+/// [`HistoricalClock`] - sharing one would stamp [`Event`](crate::event::Event)s with the time of
+/// whichever [`Trader`](crate::engine::trader::Trader) observed the latest
+/// [`MarketEvent`](barter_data::event::MarketEvent).
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Source of the current time for a trading system.
+///
+/// Live trading uses the [`LiveClock`] wall clock, whereas backtests use a [`HistoricalClock`]
+/// that is driven by the `time_exchange` of each observed [`MarketEvent`](barter_data::event::MarketEvent),
+/// making outputs deterministic.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current time according to this [`Clock`].
+    fn time(&self) -> DateTime<Utc>;
+
+    /// Observe the time of an external event (eg/ a `MarketEvent::time_exchange`). Wall clocks
+    /// ignore observations.
+    fn observe(&self, _time: DateTime<Utc>) {}
+}
+
+/// Construct a [`SharedClock`] using the [`LiveClock`] wall clock.
+pub fn live() -> SharedClock {
+    Arc::new(LiveClock)
+}
+
+/// Wall [`Clock`] that returns [`Utc::now`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct LiveClock;
+
+impl Clock for LiveClock {
+    fn time(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Simulated [`Clock`] whose time only advances when it observes a later event time. Observed
+/// times earlier than the current time are ignored, so the clock is monotonic.
+#[derive(Debug)]
+pub struct HistoricalClock {
+    time: RwLock<DateTime<Utc>>,
+}
+
+impl HistoricalClock {
+    /// Constructs a new [`HistoricalClock`] starting at the provided time.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            time: RwLock::new(start),
+        }
+    }
+
+    /// Construct a [`SharedClock`] using a [`HistoricalClock`] starting at the provided time.
+    pub fn shared(start: DateTime<Utc>) -> SharedClock {
+        Arc::new(Self::new(start))
+    }
+}
+
+impl Clock for HistoricalClock {
+    fn time(&self) -> DateTime<Utc> {
+        *self.time.read()
+    }
+
+    fn observe(&self, time: DateTime<Utc>) {
+        let mut current = self.time.write();
+        if time > *current {
+            *current = time;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_historical_clock_observe() {
+        let start = Utc.timestamp_opt(1_000, 0).unwrap();
+        let clock = HistoricalClock::new(start);
+
+        struct TestCase {
+            observe: DateTime<Utc>,
+            expected: DateTime<Utc>,
+        }
+
+        let cases = vec![
+            // TC0: later time advances the clock
+            TestCase {
+                observe: start + Duration::seconds(10),
+                expected: start + Duration::seconds(10),
+            },
+            // TC1: earlier time is ignored
+            TestCase {
+                observe: start + Duration::seconds(5),
+                expected: start + Duration::seconds(10),
+            },
+            // TC2: equal time is ignored
+            TestCase {
+                observe: start + Duration::seconds(10),
+                expected: start + Duration::seconds(10),
+            },
+            // TC3: later time advances the clock
+            TestCase {
+                observe: start + Duration::seconds(60),
+                expected: start + Duration::seconds(60),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            clock.observe(test.observe);
+            assert_eq!(clock.time(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_live_clock_ignores_observe() {
+        let clock = LiveClock;
+        let past = Utc.timestamp_opt(0, 0).unwrap();
+        clock.observe(past);
+        assert!(clock.time() > past);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::data::{Feed, MarketGenerator};
+use crate::data::{AsyncMarketGenerator, Feed, MarketGenerator};
+use async_trait::async_trait;
 
 /// Historical [`Feed`] of market events.
 #[derive(Debug)]
@@ -20,6 +21,17 @@ where
     }
 }
 
+#[async_trait]
+impl<Iter> AsyncMarketGenerator<Iter::Item> for MarketFeed<Iter>
+where
+    Iter: Iterator + Send,
+    Iter::Item: Send,
+{
+    async fn next(&mut self) -> Feed<Iter::Item> {
+        MarketGenerator::next(self)
+    }
+}
+
 impl<Iter> MarketFeed<Iter>
 where
     Iter: Iterator,
@@ -1,4 +1,5 @@
-use super::{Feed, MarketGenerator};
+use super::{AsyncMarketGenerator, Feed, MarketGenerator};
+use async_trait::async_trait;
 use barter_data::{
     event::{DataKind, MarketEvent},
     streams::{consumer::MarketStreamEvent, reconnect},
@@ -6,7 +7,7 @@ use barter_data::{
 use barter_instrument::instrument::market_data::MarketDataInstrument;
 use futures::{
     executor::{block_on_stream, BlockingStream},
-    Stream,
+    Stream, StreamExt,
 };
 use tokio::sync::mpsc;
 
@@ -18,13 +19,23 @@ pub struct MarketFeed<Event> {
 
 impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
     fn next(&mut self) -> Feed<Event> {
-        loop {
-            match self.market_rx.try_recv() {
-                Ok(event) => break Feed::Next(event),
-                Err(mpsc::error::TryRecvError::Empty) => continue,
-                Err(mpsc::error::TryRecvError::Disconnected) => break Feed::Finished,
-            }
-        }
+        // Park the current thread until the next market Event arrives, rather than busy-waiting
+        self.market_rx
+            .blocking_recv()
+            .map_or(Feed::Finished, Feed::Next)
+    }
+}
+
+#[async_trait]
+impl<Event> AsyncMarketGenerator<Event> for MarketFeed<Event>
+where
+    Event: Send,
+{
+    async fn next(&mut self) -> Feed<Event> {
+        self.market_rx
+            .recv()
+            .await
+            .map_or(Feed::Finished, Feed::Next)
     }
 }
 
@@ -65,6 +76,21 @@ where
     }
 }
 
+#[async_trait]
+impl<St> AsyncMarketGenerator<MarketEvent> for ReconnectingMarketFeed<St>
+where
+    St: Stream<Item = MarketStreamEvent<MarketDataInstrument, DataKind>> + Unpin + Send,
+{
+    async fn next(&mut self) -> Feed<MarketEvent> {
+        // BlockingStream derefs to the underlying Stream, which can be awaited directly
+        match StreamExt::next(&mut *self.market_stream).await {
+            Some(reconnect::Event::Reconnecting(_)) => Feed::Unhealthy,
+            Some(reconnect::Event::Item(item)) => Feed::Next(item),
+            None => Feed::Finished,
+        }
+    }
+}
+
 impl<St> ReconnectingMarketFeed<St>
 where
     St: Stream + Unpin,
@@ -1,4 +1,7 @@
+use async_trait::async_trait;
+use barter_data::event::{DataKind, MarketEvent};
 use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 /// Barter data module specific errors.
@@ -16,6 +19,17 @@ pub trait MarketGenerator<Event> {
     fn next(&mut self) -> Feed<Event>;
 }
 
+/// Asynchronously generates the next `Event`. Acts as the system heartbeat for a
+/// [`Trader`](crate::engine::trader::Trader) running on an async runtime.
+///
+/// Implementations must be cancellation safe, since the next `Event` is awaited concurrently with
+/// remote [`Command`](crate::engine::Command)s & execution updates.
+#[async_trait]
+pub trait AsyncMarketGenerator<Event> {
+    /// Await the next market `Event`.
+    async fn next(&mut self) -> Feed<Event>;
+}
+
 /// Communicates the state of the [`Feed`] as well as the next event.
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub enum Feed<Event> {
@@ -35,6 +49,27 @@ pub struct MarketMeta {
     pub time: DateTime<Utc>,
 }
 
+impl MarketMeta {
+    /// Determine the [`MarketMeta`] of a [`MarketEvent`], returning `None` if the [`DataKind`]
+    /// does not communicate a close price (eg/ `OrderBook` or `Liquidation`).
+    pub fn from_market_event<InstrumentKey>(
+        market: &MarketEvent<InstrumentKey, DataKind>,
+    ) -> Option<Self> {
+        let close = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price().to_f64()?,
+            DataKind::Ticker(ticker) => ticker.last_price,
+            DataKind::OrderBook(_) | DataKind::Liquidation(_) => return None,
+        };
+
+        Some(Self {
+            close,
+            time: market.time_exchange,
+        })
+    }
+}
+
 impl Default for MarketMeta {
     fn default() -> Self {
         Self {
@@ -1,4 +1,7 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::{
+    execution::error::ExecutionError,
+    portfolio::{error::PortfolioError, repository::error::RepositoryError},
+};
 use thiserror::Error;
 
 /// All errors generated in barter-engine.
@@ -9,4 +12,13 @@ pub enum EngineError {
 
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
+
+    #[error("Portfolio error: {0}")]
+    Portfolio(#[from] PortfolioError),
+
+    #[error("Execution error: {0}")]
+    Execution(#[from] ExecutionError),
+
+    #[error("Failed to serialise: {0}")]
+    JsonSerDe(#[from] serde_json::Error),
 }
@@ -1,12 +1,16 @@
 use crate::{
-    data::MarketGenerator,
-    engine::{error::EngineError, trader::Trader},
+    data::{AsyncMarketGenerator, MarketGenerator},
+    engine::{
+        error::EngineError,
+        policy::TraderFailure,
+        trader::{NewTrader, Trader},
+    },
     event::{Event, MessageTransmitter},
     execution::ExecutionClient,
     portfolio::{
         position::Position,
-        repository::{PositionHandler, StatisticHandler},
-        FillUpdater, MarketUpdater, OrderGenerator,
+        repository::{BalanceHandler, PositionHandler, StatisticHandler},
+        Balance, FillUpdater, MarketUpdater, OrderGenerator, OrderUpdater,
     },
     statistic::summary::{PositionSummariser, TableBuilder},
     strategy::SignalGenerator,
@@ -20,14 +24,21 @@ use parking_lot::Mutex;
 use prettytable::Table;
 use serde::Serialize;
 use smol_str::ToSmolStr;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
-use tokio::sync::{mpsc, oneshot};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinSet,
+};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// Configurable [`ErrorPolicy`](policy::ErrorPolicy) determining how a [`Trader`] responds to
+/// failures whilst handling an [`Event`].
+pub mod policy;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has its own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
@@ -49,6 +60,49 @@ pub enum Command {
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance. Involves one [`Trader`].
     ExitPosition(Market),
+
+    /// Fetches the [`Engine`]'s current [`Balance`] and sends it on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchBalance(oneshot::Sender<Result<Balance, EngineError>>),
+
+    /// Fetches the serialised statistics for a [`Market`] and sends them on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchStatistics(
+        Market,
+        oneshot::Sender<Result<serde_json::Value, EngineError>>,
+    ),
+
+    /// Pause Signal generation for every [`Trader`] associated with this [`Engine`]. Paused
+    /// [`Trader`]s continue to update open [`Position`]s. Involves all [`Trader`]s.
+    PauseAll,
+
+    /// Pause Signal generation for a [`Market`]. Uses the [`Market`] provided to route this
+    /// [`Command`] to the relevant [`Trader`] instance. Involves one [`Trader`].
+    Pause(Market),
+
+    /// Resume Signal generation for every [`Trader`] associated with this [`Engine`]. Involves
+    /// all [`Trader`]s.
+    ResumeAll,
+
+    /// Resume Signal generation for a [`Market`]. Uses the [`Market`] provided to route this
+    /// [`Command`] to the relevant [`Trader`] instance. Involves one [`Trader`].
+    Resume(Market),
+
+    /// Update the strategy parameters of a [`Trader`]. Uses the [`Market`] provided to route this
+    /// [`Command`] to the relevant [`Trader`] instance. Involves one [`Trader`].
+    UpdateStrategy(Market, serde_json::Value),
+
+    /// Update the risk parameters used by the Portfolio to evaluate new orders. Involves the
+    /// [`Engine`] only.
+    UpdateRisk(serde_json::Value),
+
+    /// Start running a new [`Trader`] for a [`Market`] not yet traded by this [`Engine`].
+    /// Involves the [`Engine`] only.
+    AddTrader(NewTrader),
+
+    /// Exit any open [`Position`] & terminate the [`Trader`] associated with a [`Market`]. Involves
+    /// one [`Trader`].
+    RemoveTrader(Market),
 }
 
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
@@ -57,8 +111,8 @@ pub struct EngineLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event> + Send,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater + Send,
+    Data: Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -82,9 +136,11 @@ where
 /// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
 /// for each unique [`Market`].
 ///
-/// Each [`Trader`] operates on it's own thread and has it's own Data handler, Strategy &
-/// Execution Handler, as well as shared access to a global Portfolio instance. A graceful remote
-/// shutdown is made possible by sending a [`Command::Terminate`] to the Engine's broadcast::Receiver
+/// Each [`Trader`] operates on it's own thread (see [`Engine::run`]) or tokio task (see
+/// [`Engine::run_async`]) and has it's own Data handler, Strategy & Execution Handler, as well as
+/// shared access to a global Portfolio instance. [`Trader`]s can be added & removed at runtime
+/// via [`Command::AddTrader`] & [`Command::RemoveTrader`]. A graceful remote shutdown is made
+/// possible by sending a [`Command::Terminate`] to the Engine's broadcast::Receiver
 /// termination_rx.
 #[derive(Debug)]
 pub struct Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -92,13 +148,15 @@ where
     EventTx: MessageTransmitter<Event>,
     Statistic: PositionSummariser + Serialize + Send,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
+        + OrderUpdater
         + Send
         + 'static,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send + 'static,
+    Data: Send + 'static,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -126,13 +184,15 @@ where
     EventTx: MessageTransmitter<Event> + Send + 'static,
     Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
+        + OrderUpdater
         + Send
         + 'static,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Data: Send + 'static,
     Strategy: SignalGenerator + Send + 'static,
     Execution: ExecutionClient + Send + 'static,
 {
@@ -157,20 +217,39 @@ where
         EngineBuilder::new()
     }
 
-    /// Run the trading [`Engine`]. Spawns a thread for each [`Trader`] to run on. Asynchronously
-    /// receives [`Command`]s via the `command_rx` and actions them
-    /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
-    /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
-    /// for the trading session.
-    pub async fn run(mut self) {
-        // Run Traders on threads & send notification when they have stopped organically
-        let mut notify_traders_stopped = self.run_traders().await;
-
+    /// Actions [`Command`]s received via the `command_rx`, and logs any [`TraderFailure`]s, until
+    /// either a [`Command::Terminate`] is received or all of the running [`Trader`]s stop
+    /// organically.
+    async fn action_commands(
+        &mut self,
+        mut traders: JoinSet<()>,
+        failure_tx: mpsc::UnboundedSender<TraderFailure>,
+        mut trader_failures: mpsc::UnboundedReceiver<TraderFailure>,
+    ) {
         loop {
             // Action received commands from remote, or wait for all Traders to stop organically
             tokio::select! {
-                _ = notify_traders_stopped.recv() => {
-                    break;
+                stopped = traders.join_next() => match stopped {
+                    Some(Ok(())) => {},
+                    Some(Err(error)) => {
+                        error!(
+                            error = &*format!("{:?}", error),
+                            "Trader has panicked during execution",
+                        )
+                    },
+                    None => break,
+                },
+
+                Some(failure) = trader_failures.recv() => {
+                    error!(
+                        engine_id = %self.engine_id,
+                        market = ?failure.market,
+                        event_type = &*failure.event_type,
+                        error = &*failure.error,
+                        attempts = failure.attempts,
+                        action = ?failure.action,
+                        "Trader failed to handle Event"
+                    );
                 },
 
                 command = self.command_rx.recv() => {
@@ -189,6 +268,39 @@ where
                             Command::ExitAllPositions => {
                                 self.exit_all_positions().await;
                             },
+                            Command::FetchBalance(balance_tx) => {
+                                self.fetch_balance(balance_tx);
+                            },
+                            Command::FetchStatistics(market, statistics_tx) => {
+                                self.fetch_statistics(market, statistics_tx);
+                            },
+                            Command::PauseAll => {
+                                self.send_to_all_traders(Command::Pause).await;
+                            },
+                            Command::ResumeAll => {
+                                self.send_to_all_traders(Command::Resume).await;
+                            },
+                            Command::Pause(market) => {
+                                self.send_to_trader(&market, Command::Pause(market.clone())).await;
+                            },
+                            Command::Resume(market) => {
+                                self.send_to_trader(&market, Command::Resume(market.clone())).await;
+                            },
+                            Command::UpdateStrategy(market, parameters) => {
+                                self.send_to_trader(
+                                    &market,
+                                    Command::UpdateStrategy(market.clone(), parameters)
+                                ).await;
+                            },
+                            Command::UpdateRisk(parameters) => {
+                                self.update_risk(parameters);
+                            },
+                            Command::AddTrader(new_trader) => {
+                                self.add_trader(new_trader, &mut traders, &failure_tx);
+                            },
+                            Command::RemoveTrader(market) => {
+                                self.remove_trader(market).await;
+                            },
                         }
                     } else {
                         // Terminate traders due to dropped receiver
@@ -198,41 +310,21 @@ where
             }
         }
 
-        // Print Trading Session Summary
-        self.generate_session_summary().printstd();
+        // Traders that have not yet stopped continue to run detached from the Engine
+        traders.detach_all();
     }
 
-    /// Runs each [`Trader`] it's own thread. Sends a message on the returned `mpsc::Receiver<bool>`
-    /// if all the [`Trader`]s have stopped organically (eg/ due to a finished [`MarketEvent`] feed).
-    async fn run_traders(&mut self) -> mpsc::Receiver<bool> {
-        // Extract Traders out of the Engine so we can move them into threads
-        let traders = std::mem::take(&mut self.traders);
-
-        // Run each Trader instance on it's own thread
-        let mut thread_handles = Vec::with_capacity(traders.len());
-        for trader in traders.into_iter() {
-            let handle = thread::spawn(move || trader.run());
-            thread_handles.push(handle);
+    /// Extracts the [`Trader`]s out of the [`Engine`] so they can be moved into threads or tasks,
+    /// setting the transmitter used to notify the [`Engine`] of any [`TraderFailure`]s.
+    fn take_traders(
+        &mut self,
+        failure_tx: &mpsc::UnboundedSender<TraderFailure>,
+    ) -> Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>> {
+        let mut traders = std::mem::take(&mut self.traders);
+        for trader in traders.iter_mut() {
+            trader.set_failure_tx(failure_tx.clone());
         }
-
-        // Create channel to notify the Engine when the Traders have stopped organically
-        let (notify_tx, notify_rx) = mpsc::channel(1);
-
-        // Create Task that notifies Engine when the Traders have stopped organically
-        tokio::spawn(async move {
-            for handle in thread_handles {
-                if let Err(err) = handle.join() {
-                    error!(
-                        error = &*format!("{:?}", err),
-                        "Trader thread has panicked during execution",
-                    )
-                }
-            }
-
-            let _ = notify_tx.send(true).await;
-        });
-
-        notify_rx
+        traders
     }
 
     /// Fetches all the [`Engine`]'s open [`Position`]s and sends them on the provided
@@ -318,6 +410,144 @@ where
         }
     }
 
+    /// Fetches the [`Engine`]'s current [`Balance`] and sends it on the provided
+    /// `oneshot::Sender`.
+    fn fetch_balance(&self, balance_tx: oneshot::Sender<Result<Balance, EngineError>>) {
+        let balance = self
+            .portfolio
+            .lock()
+            .get_balance(self.engine_id)
+            .map_err(EngineError::RepositoryInteractionError);
+
+        if balance_tx.send(balance).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchBalance"
+            );
+        }
+    }
+
+    /// Fetches the serialised statistics for a [`Market`] and sends them on the provided
+    /// `oneshot::Sender`.
+    fn fetch_statistics(
+        &self,
+        market: Market,
+        statistics_tx: oneshot::Sender<Result<serde_json::Value, EngineError>>,
+    ) {
+        let statistics = self
+            .portfolio
+            .lock()
+            .get_statistics(&MarketId::from(&market))
+            .map_err(EngineError::RepositoryInteractionError)
+            .and_then(|statistics| serde_json::to_value(statistics).map_err(EngineError::from));
+
+        if statistics_tx.send(statistics).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchStatistics"
+            );
+        }
+    }
+
+    /// Update the risk parameters used by the Portfolio to evaluate new orders.
+    fn update_risk(&self, parameters: serde_json::Value) {
+        let result = self.portfolio.lock().update_risk_parameters(parameters);
+
+        match result {
+            Ok(()) => info!(engine_id = %self.engine_id, "updated Portfolio risk parameters"),
+            Err(error) => warn!(
+                engine_id = %self.engine_id,
+                %error,
+                "cannot action Command::UpdateRisk"
+            ),
+        }
+    }
+
+    /// Start running a [`NewTrader`] for a [`Market`] not yet traded by this [`Engine`].
+    fn add_trader(
+        &mut self,
+        new_trader: NewTrader,
+        traders: &mut JoinSet<()>,
+        failure_tx: &mpsc::UnboundedSender<TraderFailure>,
+    ) {
+        if self.trader_command_txs.contains_key(new_trader.market()) {
+            warn!(
+                market = ?new_trader.market(),
+                why = "Engine already has a Trader associated with provided Market",
+                "cannot action Command::AddTrader"
+            );
+            return;
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            market = ?new_trader.market(),
+            "adding new Trader to Engine"
+        );
+
+        let (market, command_tx) = new_trader.spawn(traders, failure_tx.clone());
+        self.trader_command_txs.insert(market, command_tx);
+    }
+
+    /// Exit any open [`Position`] & terminate the [`Trader`] associated with a [`Market`].
+    async fn remove_trader(&mut self, market: Market) {
+        let Some(command_tx) = self.trader_command_txs.remove(&market) else {
+            warn!(
+                ?market,
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "cannot action Command::RemoveTrader"
+            );
+            return;
+        };
+
+        info!(
+            engine_id = %self.engine_id,
+            ?market,
+            "removing Trader from Engine"
+        );
+
+        for command in [
+            Command::ExitPosition(market.clone()),
+            Command::Terminate("Trader removed from Engine".to_owned()),
+        ] {
+            if command_tx.send(command).await.is_err() {
+                error!(
+                    ?market,
+                    why = "dropped receiver",
+                    "failed to send Command to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Send a [`Command`] to every [`Trader`] associated with this [`Engine`], constructed using
+    /// each [`Trader`]'s [`Market`].
+    async fn send_to_all_traders(&self, command: fn(Market) -> Command) {
+        for market in self.trader_command_txs.keys() {
+            self.send_to_trader(market, command(market.clone())).await;
+        }
+    }
+
+    /// Send a [`Command`] to the [`Trader`] associated with the provided [`Market`].
+    async fn send_to_trader(&self, market: &Market, command: Command) {
+        let Some(command_tx) = self.trader_command_txs.get(market) else {
+            warn!(
+                ?market,
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to route Command to Trader"
+            );
+            return;
+        };
+
+        if command_tx.send(command).await.is_err() {
+            error!(
+                ?market,
+                why = "dropped receiver",
+                "failed to send Command to Trader command_rx"
+            );
+        }
+    }
+
     /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
     /// combination with the average statistics across all [`Market`]s traded.
     fn generate_session_summary(mut self) -> Table {
@@ -360,14 +590,98 @@ where
     }
 }
 
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event> + Send + 'static,
+    Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
+    Portfolio: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + MarketUpdater
+        + OrderGenerator
+        + FillUpdater
+        + OrderUpdater
+        + Send
+        + 'static,
+    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send + 'static,
+    Strategy: SignalGenerator + Send + 'static,
+    Execution: ExecutionClient + Send + 'static,
+{
+    /// Run the trading [`Engine`]. Spawns a thread for each [`Trader`] to run on. Asynchronously
+    /// receives [`Command`]s via the `command_rx` and actions them
+    /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
+    /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
+    /// for the trading session.
+    ///
+    /// Suited to backtests & a small number of [`Trader`]s. See [`Engine::run_async`] to run the
+    /// [`Trader`]s as tokio tasks instead.
+    pub async fn run(mut self) {
+        let (failure_tx, failure_rx) = mpsc::unbounded_channel();
+
+        // Run each Trader instance on it's own blocking thread
+        let mut traders = JoinSet::new();
+        for trader in self.take_traders(&failure_tx) {
+            traders.spawn_blocking(move || trader.run());
+        }
+
+        // Action received commands from remote, or wait for all Traders to stop organically
+        self.action_commands(traders, failure_tx, failure_rx).await;
+
+        // Print Trading Session Summary
+        self.generate_session_summary().printstd();
+    }
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event> + Send + 'static,
+    Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
+    Portfolio: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + MarketUpdater
+        + OrderGenerator
+        + FillUpdater
+        + OrderUpdater
+        + Send
+        + 'static,
+    Data: AsyncMarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send + 'static,
+    Strategy: SignalGenerator + Send + 'static,
+    Execution: ExecutionClient + Send + 'static,
+{
+    /// Run the trading [`Engine`]. Spawns a tokio task for each [`Trader`] to run on, so many
+    /// [`Market`]s can be traded on a small thread pool without busy-waiting. Asynchronously
+    /// receives [`Command`]s via the `command_rx` and actions them
+    /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
+    /// (eg/ due to a finished [`AsyncMarketGenerator`]), the [`Engine`] terminates & prints a
+    /// summary for the trading session.
+    pub async fn run_async(mut self) {
+        let (failure_tx, failure_rx) = mpsc::unbounded_channel();
+
+        // Run each Trader instance as it's own task
+        let mut traders = JoinSet::new();
+        for trader in self.take_traders(&failure_tx) {
+            traders.spawn(trader.run_async());
+        }
+
+        // Action received commands from remote, or wait for all Traders to stop organically
+        self.action_commands(traders, failure_tx, failure_rx).await;
+
+        // Print Trading Session Summary
+        self.generate_session_summary().printstd();
+    }
+}
+
 /// Builder to construct [`Engine`] instances.
 #[derive(Debug, Default)]
 pub struct EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater + Send,
+    Data: Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -385,12 +699,14 @@ where
     EventTx: MessageTransmitter<Event>,
     Statistic: PositionSummariser + Serialize + Send,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
+        + OrderUpdater
         + Send,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Data: Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -0,0 +1,165 @@
+use barter_instrument::market::Market;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Policy determining how a [`Trader`](super::trader::Trader) responds to a failure whilst
+/// handling an [`Event`](crate::event::Event) (eg/ a transient repository or execution error).
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ErrorPolicy {
+    /// Optional retries attempted before the [`ErrorAction`] is taken.
+    pub retry: Option<Retry>,
+    /// [`ErrorAction`] taken once handling the [`Event`](crate::event::Event) has failed, and any
+    /// retries have been exhausted.
+    pub action: ErrorAction,
+    /// Policy used instead when opening an [`OrderEvent`](crate::portfolio::OrderEvent) fails.
+    #[serde(default)]
+    pub open_order: OpenOrderPolicy,
+}
+
+impl Default for ErrorPolicy {
+    fn default() -> Self {
+        Self {
+            retry: Some(Retry::default()),
+            action: ErrorAction::Skip,
+            open_order: OpenOrderPolicy::default(),
+        }
+    }
+}
+
+/// Policy determining how a [`Trader`](super::trader::Trader) responds to a failure whilst
+/// opening an [`OrderEvent`](crate::portfolio::OrderEvent). An [`OrderEvent`] that could not be
+/// opened is always treated as rejected.
+///
+/// By default failures are skipped without retrying, since a failed attempt may still have opened
+/// the order at the venue, and retrying could open a duplicate order.
+///
+/// [`OrderEvent`]: crate::portfolio::OrderEvent
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OpenOrderPolicy {
+    /// Optional retries attempted before the [`ErrorAction`] is taken.
+    pub retry: Option<Retry>,
+    /// [`ErrorAction`] taken once opening the [`OrderEvent`](crate::portfolio::OrderEvent) has
+    /// failed, and any retries have been exhausted.
+    pub action: ErrorAction,
+}
+
+impl Default for OpenOrderPolicy {
+    fn default() -> Self {
+        Self {
+            retry: None,
+            action: ErrorAction::Skip,
+        }
+    }
+}
+
+/// Retry configuration with exponential backoff between attempts.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Retry {
+    /// Maximum number of retries attempted after the initial failure.
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubling for every subsequent retry.
+    pub initial_backoff: Duration,
+    /// Upper bound on the backoff between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+impl Retry {
+    /// Returns the backoff to wait before the input retry attempt (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Action taken by a [`Trader`](super::trader::Trader) once handling an
+/// [`Event`](crate::event::Event) has failed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ErrorAction {
+    /// Skip the failed [`Event`](crate::event::Event) and continue trading.
+    Skip,
+    /// Pause the [`Trader`](super::trader::Trader). A paused Trader continues to update open
+    /// Positions and handle execution updates, but no longer generates Signals.
+    Pause,
+    /// Exit any open Position for the [`Trader`](super::trader::Trader)'s [`Market`], then
+    /// terminate the Trader.
+    FlattenAndTerminate,
+}
+
+/// Failure encountered by a [`Trader`](super::trader::Trader) whilst handling an
+/// [`Event`](crate::event::Event), and the [`ErrorAction`] taken in response. Emitted as an
+/// [`Event::Error`](crate::event::Event::Error) and sent to the associated
+/// [`Engine`](super::Engine).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TraderFailure {
+    pub time: DateTime<Utc>,
+    pub engine_id: Uuid,
+    pub market: Market,
+    /// Type of [`Event`](crate::event::Event) being handled when the failure occurred.
+    pub event_type: String,
+    /// Display representation of the final error encountered.
+    pub error: String,
+    /// Number of attempts made to handle the [`Event`](crate::event::Event).
+    pub attempts: u32,
+    pub action: ErrorAction,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_backoff() {
+        struct TestCase {
+            attempt: u32,
+            expected: Duration,
+        }
+
+        let retry = Retry {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: first retry uses initial backoff
+                attempt: 1,
+                expected: Duration::from_millis(100),
+            },
+            TestCase {
+                // TC1: third retry backoff doubled twice
+                attempt: 3,
+                expected: Duration::from_millis(400),
+            },
+            TestCase {
+                // TC2: backoff capped at max_backoff
+                attempt: 5,
+                expected: Duration::from_millis(1000),
+            },
+            TestCase {
+                // TC3: large attempt does not overflow
+                attempt: 100,
+                expected: Duration::from_millis(1000),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = retry.backoff(test.attempt);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
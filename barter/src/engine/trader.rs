@@ -1,18 +1,23 @@
-use super::{error::EngineError, Command};
+use super::{
+    error::EngineError,
+    policy::{ErrorAction, ErrorPolicy, Retry, TraderFailure},
+    Command,
+};
 use crate::{
-    data::{Feed, MarketGenerator},
+    clock::{self, SharedClock},
+    data::{AsyncMarketGenerator, Feed, MarketGenerator},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
+    execution::{ExecutionClient, ExecutionEvent, OrderStatus, OrderUpdate},
+    portfolio::{FillUpdater, MarketUpdater, OrderGenerator, OrderUpdater},
     strategy::{SignalForceExit, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::{instrument::market_data::MarketDataInstrument, market::Market};
 use parking_lot::Mutex;
 use serde::Serialize;
-use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
-use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
+use tokio::{sync::mpsc, task::JoinSet};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
@@ -21,8 +26,7 @@ pub struct TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>>,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater,
     Strategy: SignalGenerator,
     Execution: ExecutionClient,
 {
@@ -44,6 +48,14 @@ where
     pub strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     pub execution: Execution,
+    /// [`ErrorPolicy`] determining how the [`Trader`] responds to failures whilst handling an
+    /// [`Event`].
+    pub error_policy: ErrorPolicy,
+    /// [`SharedClock`] used to timestamp generated [`Event`]s, and which observes the time of
+    /// every [`MarketEvent`]. Each [`Trader`] requires it's own
+    /// [`HistoricalClock`](crate::clock::HistoricalClock), since [`Trader`]s consume their
+    /// [`MarketEvent`]s concurrently.
+    pub clock: SharedClock,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -52,13 +64,16 @@ where
 /// relationship with an Engine/Portfolio. A graceful remote shutdown is made possible by sending
 /// a [`Command::Terminate`] to the Trader's
 /// mpsc::Receiver command_rx.
+///
+/// Failures whilst handling an [`Event`] are actioned according to the Trader's [`ErrorPolicy`],
+/// rather than stopping the Trader.
 #[derive(Debug)]
 pub struct Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater,
+    Data: Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -83,6 +98,22 @@ where
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
+    /// [`ErrorPolicy`] determining how the [`Trader`] responds to failures whilst handling an
+    /// [`Event`].
+    error_policy: ErrorPolicy,
+    /// [`SharedClock`] used to timestamp generated [`Event`]s, and which observes the time of
+    /// every [`MarketEvent`].
+    clock: SharedClock,
+    /// Optional transmitter for notifying the associated [`Engine`](super::Engine) of any
+    /// [`TraderFailure`]s.
+    failure_tx: Option<mpsc::UnboundedSender<TraderFailure>>,
+    /// Flag communicating if the [`Trader`] has been paused, and should not generate Signals.
+    paused: bool,
+    /// Flag communicating if the [`Trader`] is flattening it's Position before terminating.
+    terminating: bool,
+    /// Flag communicating if the [`Trader`] is running on a dedicated OS thread via
+    /// [`Trader::run`], and should block the thread whilst backing off.
+    blocking: bool,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -91,8 +122,8 @@ impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater,
+    Data: Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -114,6 +145,12 @@ where
             data: lego.data,
             strategy: lego.strategy,
             execution: lego.execution,
+            error_policy: lego.error_policy,
+            clock: lego.clock,
+            failure_tx: None,
+            paused: false,
+            terminating: false,
+            blocking: true,
             _statistic_marker: PhantomData,
         }
     }
@@ -123,114 +160,321 @@ where
         TraderBuilder::new()
     }
 
-    /// Run the trading event-loop for this [`Trader`] instance. Loop will run until [`Trader`]
-    /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
-    /// [`MarketGenerator`] yields [`Feed::Finished`].
-    pub fn run(mut self) {
-        // Run trading loop for this Trader instance
-        'trading: loop {
-            // Check for new remote Commands before continuing to generate another MarketEvent
-            while let Some(command) = self.receive_remote_command() {
-                match command {
-                    Command::Terminate(_) => break 'trading,
-                    Command::ExitPosition(market) => {
-                        self.event_q
-                            .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
-                    }
-                    _ => continue,
-                }
+    /// Actions a remote [`Command`], returning `true` if the [`Trader`] should terminate.
+    fn action_command(&mut self, command: Command) -> bool {
+        match command {
+            Command::Terminate(_) => return true,
+            Command::ExitPosition(market) => {
+                let signal = self.signal_force_exit(market);
+                self.event_q.push_back(Event::SignalForceExit(signal));
             }
-
-            // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
-            match self.data.next() {
-                Feed::Next(market) => {
-                    self.event_tx.send(Event::Market(market.clone()));
-                    self.event_q.push_back(Event::Market(market));
-                }
-                Feed::Unhealthy => {
-                    warn!(
+            Command::Pause(_) => {
+                info!(engine_id = %self.engine_id, market = ?self.market, "Trader paused");
+                self.paused = true;
+            }
+            Command::Resume(_) => {
+                info!(engine_id = %self.engine_id, market = ?self.market, "Trader resumed");
+                self.paused = false;
+            }
+            Command::UpdateStrategy(_, parameters) => {
+                match self.strategy.update_parameters(parameters) {
+                    Ok(()) => info!(
                         engine_id = %self.engine_id,
                         market = ?self.market,
-                        action = "continuing while waiting for healthy Feed",
-                        "MarketFeed unhealthy"
-                    );
-                    continue 'trading;
+                        "updated Trader strategy parameters"
+                    ),
+                    Err(error) => warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        %error,
+                        "cannot action Command::UpdateStrategy"
+                    ),
                 }
-                Feed::Finished => break 'trading,
             }
+            _ => {}
+        }
 
-            // Handle Events in the event_q
-            // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
-                match event {
-                    Event::Market(market) => {
+        false
+    }
+
+    /// Constructs a [`SignalForceExit`] for the provided [`Market`], stamped with the current
+    /// [`SharedClock`] time.
+    fn signal_force_exit(&self, market: Market) -> SignalForceExit {
+        SignalForceExit::new(self.clock.time(), market.exchange, market.instrument)
+    }
+
+    /// Sends the next [`MarketEvent`] to the external sink, and pushes it onto the event_q.
+    fn enqueue_market_event(&mut self, market: MarketEvent<MarketDataInstrument, DataKind>) {
+        self.clock.observe(market.time_exchange);
+        self.event_tx.send(Event::Market(market.clone()));
+        self.event_q.push_back(Event::Market(market));
+    }
+
+    /// Logs that the [`Feed`] is unhealthy.
+    fn log_unhealthy_feed(&self) {
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            action = "continuing while waiting for healthy Feed",
+            "MarketFeed unhealthy"
+        );
+    }
+
+    /// Handles every [`Event`] in the event_q, including any subsequent [`Event`]s generated
+    /// whilst doing so.
+    async fn handle_events(&mut self) {
+        // Handle Events in the event_q
+        // '--> While loop will break when event_q is empty and requires another MarketEvent
+        while let Some(event) = self.event_q.pop_front() {
+            match event {
+                Event::Market(market) => {
+                    // Paused Traders continue to update open Positions, but generate no Signals
+                    if !self.paused {
                         if let Some(signal) = self.strategy.generate_signal(&market) {
                             self.event_tx.send(Event::Signal(signal.clone()));
                             self.event_q.push_back(Event::Signal(signal));
                         }
+                    }
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
-                        }
+                    if let Some(Some(position_update)) = self
+                        .attempt("Market", |trader| {
+                            let mut portfolio = trader.portfolio.lock();
+                            portfolio.update_from_market(&market)
+                        })
+                        .await
+                    {
+                        self.event_tx.send(Event::PositionUpdate(position_update));
                     }
+                }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
-                        }
+                Event::Signal(signal) => {
+                    if let Some(Some(order)) = self
+                        .attempt("Signal", |trader| {
+                            let mut portfolio = trader.portfolio.lock();
+                            portfolio.generate_order(&signal)
+                        })
+                        .await
+                    {
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
                     }
+                }
 
-                    Event::SignalForceExit(signal_force_exit) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_exit_order(signal_force_exit)
-                            .expect("failed to generate forced exit order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
-                        }
+                Event::SignalForceExit(signal_force_exit) => {
+                    if let Some(Some(order)) = self
+                        .attempt("SignalForceExit", |trader| {
+                            let mut portfolio = trader.portfolio.lock();
+                            portfolio.generate_exit_order(signal_force_exit.clone())
+                        })
+                        .await
+                    {
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
                     }
+                }
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                Event::OrderNew(order) => {
+                    // Open the OrderEvent, enqueuing any ExecutionEvents known immediately
+                    // '--> failure to open the OrderEvent is treated as a rejection
+                    let policy = self.error_policy.open_order;
+                    let execution_events = match self
+                        .attempt_with("OrderNew", policy.retry, policy.action, |trader| {
+                            trader.execution.open_order(&order)
+                        })
+                        .await
+                    {
+                        Ok(execution_events) => execution_events,
+                        Err(error) => vec![ExecutionEvent::OrderUpdate(OrderUpdate {
+                            time: self.clock.time(),
+                            cid: order.cid,
+                            exchange: order.exchange,
+                            instrument: order.instrument.clone(),
+                            status: OrderStatus::Rejected(error.to_string()),
+                        })],
+                    };
 
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
-                    }
+                    self.enqueue_execution_events(execution_events);
+                }
 
-                    Event::Fill(fill) => {
-                        let fill_side_effect_events = self
-                            .portfolio
-                            .lock()
-                            .update_from_fill(&fill)
-                            .expect("failed to update Portfolio from fill");
+                Event::OrderUpdate(order_update) => {
+                    if let Some(order_update_side_effect_events) = self
+                        .attempt("OrderUpdate", |trader| {
+                            let mut portfolio = trader.portfolio.lock();
+                            portfolio.update_from_order_update(&order_update)
+                        })
+                        .await
+                    {
+                        self.event_tx.send_many(order_update_side_effect_events);
+                    }
+                }
 
-                        self.event_tx.send_many(fill_side_effect_events);
+                Event::Fill(fill) => {
+                    let (retry, action) = (self.error_policy.retry, self.error_policy.action);
+                    match self
+                        .attempt_with("Fill", retry, action, |trader| {
+                            let mut portfolio = trader.portfolio.lock();
+                            portfolio.update_from_fill(&fill)
+                        })
+                        .await
+                    {
+                        Ok(fill_side_effect_events) => {
+                            self.event_tx.send_many(fill_side_effect_events);
+                        }
+                        Err(error) => {
+                            // Terminate the in-flight OrderEvent of the FillEvent that could not
+                            // be applied, releasing it's reservation so the market is not blocked
+                            // '--> handled before any flattening SignalForceExit
+                            let update = Event::OrderUpdate(OrderUpdate {
+                                time: self.clock.time(),
+                                cid: fill.cid,
+                                exchange: fill.exchange,
+                                instrument: fill.instrument.clone(),
+                                status: OrderStatus::Rejected(format!(
+                                    "failed to apply FillEvent: {error}"
+                                )),
+                            });
+                            self.event_tx.send(update.clone());
+                            self.event_q.push_front(update);
+                        }
                     }
-                    _ => {}
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Attempts the fallible operation required to handle an [`Event`], actioning the
+    /// [`ErrorPolicy`] if it fails. Returns `None` if the operation ultimately failed.
+    async fn attempt<T, E, Operation>(
+        &mut self,
+        event_type: &'static str,
+        operation: Operation,
+    ) -> Option<T>
+    where
+        E: Into<EngineError>,
+        Operation: FnMut(&mut Self) -> Result<T, E>,
+    {
+        let (retry, action) = (self.error_policy.retry, self.error_policy.action);
+        self.attempt_with(event_type, retry, action, operation)
+            .await
+            .ok()
+    }
+
+    /// Attempts the fallible operation required to handle an [`Event`] using the provided
+    /// [`Retry`] & [`ErrorAction`], returning the final error if the operation ultimately failed.
+    async fn attempt_with<T, E, Operation>(
+        &mut self,
+        event_type: &'static str,
+        retry: Option<Retry>,
+        action: ErrorAction,
+        mut operation: Operation,
+    ) -> Result<T, EngineError>
+    where
+        E: Into<EngineError>,
+        Operation: FnMut(&mut Self) -> Result<T, E>,
+    {
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            let error = match operation(self) {
+                Ok(output) => return Ok(output),
+                Err(error) => error.into(),
+            };
+
+            // Retry with backoff if the policy permits
+            if let Some(retry) = retry {
+                if attempts <= retry.max_retries {
+                    let backoff = retry.backoff(attempts);
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        event_type,
+                        %error,
+                        attempts,
+                        ?backoff,
+                        "failed to handle Event, retrying after backoff"
+                    );
+                    self.backoff(backoff).await;
+                    continue;
+                }
+            }
+
+            self.action_failure(event_type, &error, action, attempts);
+            return Err(error);
+        }
+    }
+
+    /// Actions the [`ErrorAction`] for an [`Event`] that could not be handled. The resulting
+    /// [`TraderFailure`] is sent to the external sink & the associated [`Engine`](super::Engine).
+    fn action_failure(
+        &mut self,
+        event_type: &'static str,
+        error: &EngineError,
+        action: ErrorAction,
+        attempts: u32,
+    ) {
+        error!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            event_type,
+            %error,
+            attempts,
+            ?action,
+            "Trader failed to handle Event"
+        );
+
+        let failure = TraderFailure {
+            time: self.clock.time(),
+            engine_id: self.engine_id,
+            market: self.market.clone(),
+            event_type: event_type.to_owned(),
+            error: error.to_string(),
+            attempts,
+            action,
+        };
+
+        self.event_tx.send(Event::Error(failure.clone()));
+        if let Some(failure_tx) = &self.failure_tx {
+            let _ = failure_tx.send(failure);
+        }
+
+        match action {
+            ErrorAction::Skip => {}
+            ErrorAction::Pause => self.paused = true,
+            ErrorAction::FlattenAndTerminate => {
+                if !self.terminating {
+                    self.terminating = true;
+                    let signal = self.signal_force_exit(self.market.clone());
+                    self.event_q.push_back(Event::SignalForceExit(signal));
                 }
             }
+        }
+    }
+
+    /// Waits for the provided backoff before retrying. Blocks the current thread if the [`Trader`]
+    /// is running on a dedicated OS thread, otherwise yields to the tokio runtime.
+    async fn backoff(&mut self, duration: Duration) {
+        if self.blocking {
+            std::thread::sleep(duration)
+        } else {
+            tokio::time::sleep(duration).await
+        }
+    }
+
+    /// Sets the transmitter used to notify the associated [`Engine`](super::Engine) of any
+    /// [`TraderFailure`]s.
+    pub(super) fn set_failure_tx(&mut self, failure_tx: mpsc::UnboundedSender<TraderFailure>) {
+        self.failure_tx = Some(failure_tx);
+    }
 
-            debug!(
-                engine_id = &*self.engine_id.to_string(),
-                market = &*format!("{:?}", self.market),
-                "Trader trading loop stopped"
-            );
+    /// Sends every [`ExecutionEvent`] to the external sink, and pushes them onto the event_q.
+    fn enqueue_execution_events(&mut self, execution_events: Vec<ExecutionEvent>) {
+        for execution_event in execution_events {
+            let event = Event::from(execution_event);
+            self.event_tx.send(event.clone());
+            self.event_q.push_back(event);
         }
     }
 
@@ -262,14 +506,238 @@ where
     }
 }
 
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater,
+    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// Run the trading event-loop for this [`Trader`] instance on the current thread. Loop will
+    /// run until [`Trader`] receives a [`Command::Terminate`] via the mpsc::Receiver command_rx,
+    /// or the [`MarketGenerator`] yields [`Feed::Finished`].
+    ///
+    /// Suited to backtesting with a historical [`MarketGenerator`], or running a small number of
+    /// [`Trader`]s on dedicated OS threads. See [`Trader::run_async`] for an async alternative.
+    pub fn run(mut self) {
+        self.blocking = true;
+
+        // Run trading loop for this Trader instance
+        'trading: loop {
+            // Check for new remote Commands before continuing to generate another MarketEvent
+            while let Some(command) = self.receive_remote_command() {
+                if self.action_command(command) {
+                    // Open any pending exit OrderEvents before terminating
+                    futures::executor::block_on(self.handle_events());
+                    break 'trading;
+                }
+            }
+
+            // Enqueue any ExecutionEvents (eg/ fills, order updates) received asynchronously
+            let execution_events = self.execution.poll_events();
+            self.enqueue_execution_events(execution_events);
+
+            // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
+            match self.data.next() {
+                Feed::Next(market) => self.enqueue_market_event(market),
+                Feed::Unhealthy => {
+                    self.log_unhealthy_feed();
+                    continue 'trading;
+                }
+                Feed::Finished => break 'trading,
+            }
+
+            // Handle Events in the event_q
+            futures::executor::block_on(self.handle_events());
+
+            // Terminate once any flattening exit OrderEvent has been opened
+            if self.terminating {
+                break 'trading;
+            }
+        }
+
+        debug!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            "Trader trading loop stopped"
+        );
+    }
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event> + Send,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater + Send,
+    Data: AsyncMarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// Run the trading event-loop for this [`Trader`] instance as a tokio task. Concurrently
+    /// awaits remote [`Command`]s, the next [`MarketEvent`] & any [`ExecutionEvent`]s without
+    /// busy-waiting, so many [`Trader`]s can share a small thread pool. Loop will run until
+    /// [`Trader`] receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
+    /// [`AsyncMarketGenerator`] yields [`Feed::Finished`].
+    pub async fn run_async(mut self) {
+        self.blocking = false;
+
+        // Run trading loop for this Trader instance
+        'trading: loop {
+            tokio::select! {
+                // Prioritise remote Commands over generating another MarketEvent
+                biased;
+
+                command = self.command_rx.recv() => {
+                    let command = command.unwrap_or_else(|| {
+                        warn!(
+                            action = "synthesising a Command::Terminate",
+                            "remote Command transmitter has been dropped"
+                        );
+                        Command::Terminate("remote command transmitter dropped".to_owned())
+                    });
+
+                    if self.action_command(command) {
+                        // Open any pending exit OrderEvents before terminating
+                        self.handle_events().await;
+                        break 'trading;
+                    }
+                }
+
+                Some(execution_event) = self.execution.next_event() => {
+                    self.enqueue_execution_events(vec![execution_event]);
+                }
+
+                feed = self.data.next() => match feed {
+                    Feed::Next(market) => self.enqueue_market_event(market),
+                    Feed::Unhealthy => {
+                        self.log_unhealthy_feed();
+                        continue 'trading;
+                    }
+                    Feed::Finished => break 'trading,
+                },
+            }
+
+            // Enqueue any ExecutionEvents (eg/ fills, order updates) received asynchronously
+            let execution_events = self.execution.poll_events();
+            self.enqueue_execution_events(execution_events);
+
+            // Handle Events in the event_q
+            self.handle_events().await;
+
+            // Terminate once any flattening exit OrderEvent has been opened
+            if self.terminating {
+                break 'trading;
+            }
+        }
+
+        debug!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            "Trader trading loop stopped"
+        );
+    }
+}
+
+/// Spawns a [`Trader`] into the [`Engine`](super::Engine)'s set of running [`Trader`]s, providing
+/// the transmitter used to notify the [`Engine`](super::Engine) of any [`TraderFailure`]s.
+type SpawnTrader = Box<dyn FnOnce(&mut JoinSet<()>, mpsc::UnboundedSender<TraderFailure>) + Send>;
+
+/// Type-erased [`Trader`] that can be added to a running [`Engine`](super::Engine) via
+/// [`Command::AddTrader`].
+///
+/// The Portfolio must already track the new [`Market`]'s statistics (eg/ via
+/// [`MetaPortfolio::init_statistics`](crate::portfolio::portfolio::MetaPortfolio::init_statistics)).
+pub struct NewTrader {
+    market: Market,
+    command_tx: mpsc::Sender<Command>,
+    spawn: SpawnTrader,
+}
+
+impl Debug for NewTrader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NewTrader")
+            .field("market", &self.market)
+            .field("command_tx", &self.command_tx)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NewTrader {
+    /// Constructs a [`NewTrader`] that runs the provided [`Trader`] as a tokio task via
+    /// [`Trader::run_async`]. The `command_tx` must transmit to the [`Trader`]'s `command_rx`.
+    pub fn new<EventTx, Statistic, Portfolio, Data, Strategy, Execution>(
+        mut trader: Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>,
+        command_tx: mpsc::Sender<Command>,
+    ) -> Self
+    where
+        EventTx: MessageTransmitter<Event> + Send + 'static,
+        Statistic: Serialize + Send + 'static,
+        Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater + Send + 'static,
+        Data: AsyncMarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send + 'static,
+        Strategy: SignalGenerator + Send + 'static,
+        Execution: ExecutionClient + Send + 'static,
+    {
+        Self {
+            market: trader.market.clone(),
+            command_tx,
+            spawn: Box::new(move |traders, failure_tx| {
+                trader.set_failure_tx(failure_tx);
+                traders.spawn(trader.run_async());
+            }),
+        }
+    }
+
+    /// Constructs a [`NewTrader`] that runs the provided [`Trader`] on a blocking thread via
+    /// [`Trader::run`]. The `command_tx` must transmit to the [`Trader`]'s `command_rx`.
+    pub fn blocking<EventTx, Statistic, Portfolio, Data, Strategy, Execution>(
+        mut trader: Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>,
+        command_tx: mpsc::Sender<Command>,
+    ) -> Self
+    where
+        EventTx: MessageTransmitter<Event> + Send + 'static,
+        Statistic: Serialize + Send + 'static,
+        Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater + Send + 'static,
+        Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send + 'static,
+        Strategy: SignalGenerator + Send + 'static,
+        Execution: ExecutionClient + Send + 'static,
+    {
+        Self {
+            market: trader.market.clone(),
+            command_tx,
+            spawn: Box::new(move |traders, failure_tx| {
+                trader.set_failure_tx(failure_tx);
+                traders.spawn_blocking(move || trader.run());
+            }),
+        }
+    }
+
+    /// [`Market`] traded by the [`NewTrader`].
+    pub fn market(&self) -> &Market {
+        &self.market
+    }
+
+    /// Starts running the [`Trader`], returning it's [`Market`] & [`Command`] transmitter.
+    pub(super) fn spawn(
+        self,
+        traders: &mut JoinSet<()>,
+        failure_tx: mpsc::UnboundedSender<TraderFailure>,
+    ) -> (Market, mpsc::Sender<Command>) {
+        (self.spawn)(traders, failure_tx);
+        (self.market, self.command_tx)
+    }
+}
+
 /// Builder to construct [`Trader`] instances.
 #[derive(Debug, Default)]
 pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>>,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater,
     Strategy: SignalGenerator,
     Execution: ExecutionClient,
 {
@@ -281,6 +749,8 @@ where
     data: Option<Data>,
     strategy: Option<Strategy>,
     execution: Option<Execution>,
+    error_policy: Option<ErrorPolicy>,
+    clock: Option<SharedClock>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
@@ -289,8 +759,8 @@ impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<MarketDataInstrument, DataKind>> + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + OrderUpdater,
+    Data: Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
@@ -304,6 +774,8 @@ where
             data: None,
             strategy: None,
             execution: None,
+            error_policy: None,
+            clock: None,
             _statistic_marker: None,
         }
     }
@@ -364,6 +836,20 @@ where
         }
     }
 
+    pub fn error_policy(self, value: ErrorPolicy) -> Self {
+        Self {
+            error_policy: Some(value),
+            ..self
+        }
+    }
+
+    pub fn clock(self, value: SharedClock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
@@ -391,6 +877,12 @@ where
             execution: self
                 .execution
                 .ok_or(EngineError::BuilderIncomplete("execution"))?,
+            error_policy: self.error_policy.unwrap_or_default(),
+            clock: self.clock.unwrap_or_else(clock::live),
+            failure_tx: None,
+            paused: false,
+            terminating: false,
+            blocking: true,
             _statistic_marker: PhantomData,
         })
     }
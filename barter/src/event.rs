@@ -1,6 +1,8 @@
 use crate::{
-    execution::FillEvent,
+    engine::policy::TraderFailure,
+    execution::{FillEvent, OrderUpdate},
     portfolio::{
+        ledger::Valuation,
         position::{Position, PositionExit, PositionUpdate},
         Balance, OrderEvent,
     },
@@ -8,27 +10,31 @@ use crate::{
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::instrument::market_data::MarketDataInstrument;
+use barter_integration::metric::Metric;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use tokio::sync::mpsc;
 use tracing::warn;
 
-/// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], and
-/// [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
+/// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], [`OrderUpdate`]
+/// and [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
 /// the trading sequence. The [`PositionExit`] Event is a representation of work done by the
-/// system, and is useful for analysing performance & reconciliations.
+/// system, and is useful for analysing performance & reconciliations. The [`TraderFailure`] Event
+/// communicates a failure to handle an Event, and the action taken in response.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Event {
     Market(MarketEvent<MarketDataInstrument, DataKind>),
     Signal(Signal),
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
-    OrderUpdate,
+    OrderUpdate(OrderUpdate),
     Fill(FillEvent),
     PositionNew(Position),
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
     Balance(Balance),
+    Valuation(Valuation),
+    Error(TraderFailure),
 }
 
 /// Message transmitter for sending Barter messages to downstream consumers.
@@ -86,3 +92,44 @@ impl EventTx {
         }
     }
 }
+
+/// Transmitter for sending [`Metric`]s to an external sink, such as the rolling statistics of a
+/// [`MetaPortfolio`](crate::portfolio::portfolio::MetaPortfolio). Useful for live monitoring.
+#[derive(Debug, Clone)]
+pub struct MetricTx {
+    /// Flag to communicate if the external [`Metric`] receiver has been dropped.
+    receiver_dropped: bool,
+    /// [`Metric`] channel transmitter to send [`Metric`]s to an external sink.
+    metric_tx: mpsc::UnboundedSender<Metric>,
+}
+
+impl MessageTransmitter<Metric> for MetricTx {
+    fn send(&mut self, message: Metric) {
+        if self.receiver_dropped {
+            return;
+        }
+
+        if self.metric_tx.send(message).is_err() {
+            warn!(
+                action = "setting receiver_dropped = true",
+                why = "metric receiver dropped",
+                "cannot send Metrics"
+            );
+            self.receiver_dropped = true;
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Metric>) {
+        messages.into_iter().for_each(|message| self.send(message))
+    }
+}
+
+impl MetricTx {
+    /// Constructs a new [`MetricTx`] instance using the provided channel transmitter.
+    pub fn new(metric_tx: mpsc::UnboundedSender<Metric>) -> Self {
+        Self {
+            receiver_dropped: false,
+            metric_tx,
+        }
+    }
+}
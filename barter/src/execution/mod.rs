@@ -1,8 +1,11 @@
-use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
+use crate::{data::MarketMeta, event::Event, portfolio::OrderEvent, strategy::Decision};
+use async_trait::async_trait;
 use barter_instrument::{exchange::ExchangeId, instrument::market_data::MarketDataInstrument};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
 use serde::{Deserialize, Serialize};
+use std::ops::{Add, Mul};
+use uuid::Uuid;
 
 /// Barter execution module specific errors.
 pub mod error;
@@ -10,10 +13,105 @@ pub mod error;
 /// Handlers for simulated and live [`OrderEvent`] execution.
 pub mod simulated;
 
-/// Generates a result [`FillEvent`] by executing an [`OrderEvent`].
+/// Executes [`OrderEvent`]s, communicating the result of the work done via [`ExecutionEvent`]s.
+///
+/// Live execution is asynchronous - an order can rest, partially fill, be rejected or be
+/// cancelled some time after it was opened. Any [`ExecutionEvent`]s that are not known at the time
+/// of opening the order are yielded by [`ExecutionClient::poll_events`], or awaited via
+/// [`ExecutionClient::next_event`] when running on an async runtime.
+#[async_trait]
 pub trait ExecutionClient {
-    /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError>;
+    /// Open the input [`OrderEvent`], returning any [`ExecutionEvent`]s that are known
+    /// immediately (eg/ an order acknowledgement, or a synchronous simulated [`FillEvent`]).
+    fn open_order(&mut self, order: &OrderEvent) -> Result<Vec<ExecutionEvent>, ExecutionError>;
+
+    /// Return every [`ExecutionEvent`] received asynchronously since the last poll. Synchronous
+    /// [`ExecutionClient`]s can rely on the default implementation, which never yields.
+    fn poll_events(&mut self) -> Vec<ExecutionEvent> {
+        Vec::new()
+    }
+
+    /// Await the next [`ExecutionEvent`] received asynchronously. Must be cancellation safe.
+    /// Synchronous [`ExecutionClient`]s can rely on the default implementation, which never
+    /// resolves.
+    async fn next_event(&mut self) -> Option<ExecutionEvent>
+    where
+        Self: Send,
+    {
+        std::future::pending().await
+    }
+}
+
+/// Result of work done by an [`ExecutionClient`] for a previously opened [`OrderEvent`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum ExecutionEvent {
+    OrderUpdate(OrderUpdate),
+    Fill(FillEvent),
+}
+
+impl From<ExecutionEvent> for Event {
+    fn from(event: ExecutionEvent) -> Self {
+        match event {
+            ExecutionEvent::OrderUpdate(update) => Event::OrderUpdate(update),
+            ExecutionEvent::Fill(fill) => Event::Fill(fill),
+        }
+    }
+}
+
+/// Unique identifier of an [`OrderEvent`], assigned by the Portfolio that generated it. Every
+/// [`OrderUpdate`] & [`FillEvent`] of the [`OrderEvent`] carries the same [`ClientOrderId`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct ClientOrderId(pub Uuid);
+
+impl ClientOrderId {
+    /// Generate a new random [`ClientOrderId`].
+    pub fn random() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl std::fmt::Display for ClientOrderId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Change in the status of an in-flight [`OrderEvent`], excluding fills which are communicated
+/// via [`FillEvent`]s.
+///
+/// Orders are identified by the [`ClientOrderId`] of the [`OrderEvent`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderUpdate {
+    pub time: DateTime<Utc>,
+    /// [`ClientOrderId`] of the [`OrderEvent`] this update relates to.
+    pub cid: ClientOrderId,
+    pub exchange: ExchangeId,
+    pub instrument: MarketDataInstrument,
+    pub status: OrderStatus,
+}
+
+impl OrderUpdate {
+    pub const EVENT_TYPE: &'static str = "OrderUpdate";
+}
+
+/// Status of an in-flight [`OrderEvent`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum OrderStatus {
+    /// Order has been acknowledged by the exchange and is resting in the order book.
+    Open,
+    /// Order has been cancelled, any unfilled quantity will never be filled.
+    Cancelled,
+    /// Order has been rejected, with the associated reason.
+    Rejected(String),
+}
+
+impl OrderStatus {
+    /// Determines if the [`OrderStatus`] is terminal, meaning no further fills will occur.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, OrderStatus::Cancelled | OrderStatus::Rejected(_))
+    }
 }
 
 /// Fills are journals of work done by an Execution handler. These are sent back to the portfolio,
@@ -21,6 +119,8 @@ pub trait ExecutionClient {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct FillEvent {
     pub time: DateTime<Utc>,
+    /// [`ClientOrderId`] of the [`OrderEvent`] that was (partially) filled.
+    pub cid: ClientOrderId,
     pub exchange: ExchangeId,
     pub instrument: MarketDataInstrument,
     /// Metadata propagated from source MarketEvent
@@ -44,6 +144,17 @@ impl FillEvent {
     }
 }
 
+impl FillEvent {
+    /// Aggregates the input partial [`FillEvent`] into this [`FillEvent`], summing the quantity,
+    /// gross fill value and [`Fees`].
+    pub fn aggregate(&mut self, fill: &FillEvent) {
+        self.time = fill.time;
+        self.quantity += fill.quantity;
+        self.fill_value_gross += fill.fill_value_gross;
+        self.fees = self.fees + fill.fees;
+    }
+}
+
 /// All potential fees incurred by a [`FillEvent`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct Fees {
@@ -62,6 +173,30 @@ impl Fees {
     }
 }
 
+impl Add for Fees {
+    type Output = Fees;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fees {
+            exchange: self.exchange + rhs.exchange,
+            slippage: self.slippage + rhs.slippage,
+            network: self.network + rhs.network,
+        }
+    }
+}
+
+impl Mul<f64> for Fees {
+    type Output = Fees;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Fees {
+            exchange: self.exchange * rhs,
+            slippage: self.slippage * rhs,
+            network: self.network * rhs,
+        }
+    }
+}
+
 /// Communicative type alias for Fee amount as f64.
 pub type FeeAmount = f64;
 
@@ -69,6 +204,7 @@ pub type FeeAmount = f64;
 #[derive(Debug, Default)]
 pub struct FillEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
     pub exchange: Option<ExchangeId>,
     pub instrument: Option<MarketDataInstrument>,
     pub market_meta: Option<MarketMeta>,
@@ -90,6 +226,13 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: ExchangeId) -> Self {
         Self {
             exchange: Some(value),
@@ -142,6 +285,7 @@ impl FillEventBuilder {
     pub fn build(self) -> Result<FillEvent, ExecutionError> {
         Ok(FillEvent {
             time: self.time.ok_or(ExecutionError::BuilderIncomplete("time"))?,
+            cid: self.cid.ok_or(ExecutionError::BuilderIncomplete("cid"))?,
             exchange: self
                 .exchange
                 .ok_or(ExecutionError::BuilderIncomplete("exchange"))?,
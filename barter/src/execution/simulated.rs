@@ -1,8 +1,7 @@
-use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    execution::{error::ExecutionError, ExecutionClient, Fees, FillEvent},
+    execution::{error::ExecutionError, ExecutionClient, ExecutionEvent, Fees, FillEvent},
     portfolio::OrderEvent,
 };
 
@@ -15,18 +14,36 @@ pub struct Config {
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 /// Simulated execution handler that executes [`OrderEvent`]s to generate [`FillEvent`]s via a
-/// simulated broker interaction.
+/// simulated broker interaction. Orders are filled instantly, so each [`FillEvent`] is stamped
+/// with the time of the [`OrderEvent`] (eg/ from the Portfolio's [`Clock`](crate::clock::Clock)).
 pub struct SimulatedExecution {
     fees_pct: Fees,
 }
 
 impl ExecutionClient for SimulatedExecution {
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
-        // Assume (for now) that all orders are filled at the market price
+    fn open_order(&mut self, order: &OrderEvent) -> Result<Vec<ExecutionEvent>, ExecutionError> {
+        // Simulated orders are filled synchronously, so the OrderEvent is never left in-flight
+        self.generate_fill(order)
+            .map(|fill| vec![ExecutionEvent::Fill(fill)])
+    }
+}
+
+impl SimulatedExecution {
+    /// Constructs a new [`SimulatedExecution`] component.
+    pub fn new(cfg: Config) -> Self {
+        Self {
+            fees_pct: cfg.simulated_fees_pct,
+        }
+    }
+
+    /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
+    pub fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+        // Assume (for now) that all orders are filled instantly at the market price
         let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
 
         Ok(FillEvent {
-            time: Utc::now(),
+            time: order.time,
+            cid: order.cid,
             exchange: order.exchange,
             instrument: order.instrument.clone(),
             market_meta: order.market_meta,
@@ -36,15 +53,6 @@ impl ExecutionClient for SimulatedExecution {
             fees: self.calculate_fees(&fill_value_gross),
         })
     }
-}
-
-impl SimulatedExecution {
-    /// Constructs a new [`SimulatedExecution`] component.
-    pub fn new(cfg: Config) -> Self {
-        Self {
-            fees_pct: cfg.simulated_fees_pct,
-        }
-    }
 
     /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
     fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
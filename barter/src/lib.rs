@@ -22,18 +22,19 @@
 //!   provide a live market Candle data feed to the system.
 //! * **Strategy**: The SignalGenerator trait governs potential generation of SignalEvents after analysing incoming
 //!   MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis.
-//! * **Portfolio**: MarketUpdater, OrderGenerator, and FillUpdater govern global state Portfolio implementations. A
-//!   Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy. The Portfolio's state
-//!   updates after receiving MarketEvents and FillEvents.
-//! * **Execution**: The FillGenerator trait governs the generation of FillEvents after receiving OrderEvents from the
-//!   Portfolio. For example, a SimulatedExecution handler implementation is provided for simulating any exchange execution
+//! * **Portfolio**: MarketUpdater, OrderGenerator, FillUpdater and OrderUpdater govern global state Portfolio
+//!   implementations. A Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy, and
+//!   tracks them whilst they are in-flight. The Portfolio's state updates after receiving MarketEvents, FillEvents and
+//!   OrderUpdates.
+//! * **Execution**: The ExecutionClient trait governs the opening of OrderEvents received from the Portfolio, and the
+//!   asynchronous generation of FillEvents and OrderUpdates that result from them. For example, a SimulatedExecution handler implementation is provided for simulating any exchange execution
 //!   behaviour required in dry-trading or backtesting runs.
 //! * **Statistic**: Provides metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown to analyse trading session
 //!   performance. One-pass dispersion algorithms analyse each closed Position and efficiently calculates a trading summary.
 //! * **Trader**: Capable of trading a single market pair using a customisable selection of it's own Data, Strategy &
 //!   Execution instances, as well as shared access to a global Portfolio.
 //! * **Engine**: Multi-threaded trading Engine capable of trading with an arbitrary number of Trader market pairs. Each
-//!   contained Trader instance operates on its own thread.
+//!   contained Trader instance operates on its own thread, or as a tokio task via `Engine::run_async`.
 //!
 //! [`Barter`]: https://github.com/barter-rs/barter-rs
 //! [`Barter-Data`]: https://crates.io/crates/barter-data
@@ -81,16 +82,18 @@
 //! ```
 //! use barter::{
 //!     portfolio::{
-//!         MarketUpdater, OrderGenerator, FillUpdater,
+//!         MarketUpdater, OrderGenerator, FillUpdater, OrderUpdater,
 //!         portfolio::{PortfolioLego, MetaPortfolio},
 //!         repository::in_memory::InMemoryRepository,
 //!         allocator::DefaultAllocator,
 //!         risk::DefaultRisk,
 //!     },
 //!     statistic::summary::{
+//!         equity::Config as EquityConfig,
 //!         pnl::PnLReturnSummary,
 //!         trading::{Config as StatisticConfig, TradingSummary},
 //!     },
+//!     clock,
 //!     event::Event,
 //!     test_util,
 //! };
@@ -112,6 +115,11 @@
 //!         trading_days_per_year: 365,
 //!         risk_free_return: 0.0
 //!     },
+//!     equity_config: EquityConfig::default(),
+//!     benchmark: None,
+//!     rolling: None,
+//!     ledger: None,
+//!     clock: clock::live(),
 //!     _statistic_marker: PhantomData::<TradingSummary>::default()
 //! };
 //!
@@ -129,6 +137,9 @@
 //!     Event::SignalForceExit(signal) => {
 //!         portfolio.generate_exit_order(signal);
 //!     }
+//!     Event::OrderUpdate(order_update) => {
+//!         portfolio.update_from_order_update(&order_update);
+//!     }
 //!     Event::Fill(fill) => {
 //!         portfolio.update_from_fill(&fill);
 //!     }
@@ -159,7 +170,7 @@
 //!
 //! let order_event = test_util::order_event();
 //!
-//! let fill_event = execution.generate_fill(&order_event);
+//! let execution_events = execution.open_order(&order_event);
 //! ```
 //!
 //! ### Statistic
@@ -218,16 +229,16 @@ pub mod data;
 pub mod strategy;
 
 /// Defines useful data structures such as an OrderEvent and Position. The Portfolio must
-/// interact with MarketEvents, SignalEvents, OrderEvents, and FillEvents. The useful traits
-/// MarketUpdater, OrderGenerator, & FillUpdater are provided that define the interactions
+/// interact with MarketEvents, SignalEvents, OrderEvents, OrderUpdates and FillEvents. The useful
+/// traits MarketUpdater, OrderGenerator, FillUpdater & OrderUpdater are provided that define the interactions
 /// with these events. Contains a MetaPortfolio implementation that persists state in a
 /// generic Repository. This also contains example implementations of an OrderAllocator &
 /// OrderEvaluator, which help the Portfolio make decisions on whether to generate OrderEvents and
 /// of what size.
 pub mod portfolio;
 
-/// Defines a FillEvent, and provides a useful trait FillGenerator for handling the generation
-/// of them. Contains an example SimulatedExecution implementation that simulates live broker
+/// Defines a FillEvent & OrderUpdate, and provides a useful trait ExecutionClient for handling the
+/// asynchronous generation of them. Contains an example SimulatedExecution implementation that simulates live broker
 /// execution.
 pub mod execution;
 
@@ -238,7 +249,10 @@ pub mod event;
 
 /// Defines various iterative statistical methods that can be used to calculate trading performance
 /// metrics in one-pass. A trading performance summary implementation has been provided containing
-/// several key metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown.
+/// several key metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown. Monte Carlo
+/// resampling of exited Positions produces confidence intervals for these metrics, and a
+/// buy-and-hold benchmark produces relative metrics such as alpha, beta & information ratio.
+/// Rolling-window statistics of recent trades can be published as metrics for live monitoring.
 pub mod statistic;
 
 /// Multi-threaded trading Engine capable of trading with an arbitrary number market pairs. Contains
@@ -246,10 +260,37 @@ pub mod statistic;
 /// Execution components, as well as shared access to a global Portfolio.
 pub mod engine;
 
+/// Session report generator that serialises the configuration, per-market TradingSummary,
+/// equity curve, drawdown periods & exited Positions to JSON, and renders them to an offline HTML
+/// tear sheet with inline SVG charts.
+pub mod report;
+
+/// Backtest harness that runs a strategy parameter grid in parallel over shared historical
+/// market data, with an isolated Portfolio per run. Results can be ranked by TradingSummary
+/// metrics, and parameters walk-forward analysed over rolling in-sample & out-of-sample windows.
+pub mod backtest;
+
+/// Defines a Clock abstraction used to timestamp Signals, Orders, Fills & statistics. Contains a
+/// LiveClock wall clock for live-trading, and a HistoricalClock driven by the time of observed
+/// MarketEvents for deterministic backtests.
+pub mod clock;
+
+/// Collects [`Metric`](barter_integration::metric::Metric)s generated by RestClient timings, market
+/// data streams, Trader events & Portfolio statistics, encoding them in the Prometheus text format.
+/// The collected metrics can be served over HTTP via the `server` feature, and Trader events can be
+/// converted into points for an InfluxDB compatible time series database.
+pub mod metric;
+
+/// Optional REST & websocket server exposing an [`Engine`](engine::Engine)'s
+/// [`Command`](engine::Command)s, as well as it's [`Event`](event::Event) feed as JSON. Enabled
+/// via the `server` feature.
+#[cfg(feature = "server")]
+pub mod server;
+
 pub mod test_util {
     use crate::{
         data::MarketMeta,
-        execution::{Fees, FillEvent},
+        execution::{ClientOrderId, Fees, FillEvent},
         portfolio::{position::Position, OrderEvent, OrderType},
         strategy::{Decision, Signal},
     };
@@ -317,6 +358,7 @@ pub mod test_util {
     pub fn order_event() -> OrderEvent {
         OrderEvent {
             time: Utc::now(),
+            cid: ClientOrderId::default(),
             exchange: ExchangeId::BinanceSpot,
             instrument: MarketDataInstrument::from(("eth", "usdt", MarketDataInstrumentKind::Spot)),
             market_meta: MarketMeta::default(),
@@ -330,6 +372,7 @@ pub mod test_util {
     pub fn fill_event() -> FillEvent {
         FillEvent {
             time: Utc::now(),
+            cid: ClientOrderId::default(),
             exchange: ExchangeId::BinanceSpot,
             instrument: MarketDataInstrument::from(("eth", "usdt", MarketDataInstrumentKind::Spot)),
             market_meta: Default::default(),
@@ -361,6 +404,7 @@ pub mod test_util {
             current_value_gross: 100.0,
             unrealised_profit_loss: 0.0,
             realised_profit_loss: 0.0,
+            excursion: Default::default(),
         }
     }
 }
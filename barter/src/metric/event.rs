@@ -0,0 +1,781 @@
+use crate::{
+    clock::SharedClock,
+    event::{Event, MessageTransmitter},
+    portfolio::Balance,
+    strategy::Decision,
+};
+use barter_instrument::{
+    exchange::ExchangeId, instrument::market_data::MarketDataInstrument, market::Market,
+};
+use barter_integration::metric::{Field, Metric, Tag};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use smol_str::SmolStr;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+/// Generates [`Metric`]s from the [`Event`]s produced by the
+/// [`Trader`](crate::engine::trader::Trader)s of an Engine, including:
+/// - `trader`: total [`Event`]s of each kind, tagged by engine_id & market.
+/// - `order_latency`: seconds between an [`OrderEvent`](crate::portfolio::OrderEvent) & the
+///   next [`FillEvent`](crate::execution::FillEvent) of the same market.
+/// - `portfolio`: mark-to-market equity, available cash, exposure & open position count of the
+///   Engine, tagged by engine_id.
+///
+/// Every Trader of an Engine shares the same Portfolio, so a single [`EventMetrics`] must be
+/// shared by each Trader's [`MeteredEventTx`] for the `portfolio` [`Metric`] to include the
+/// Positions of every market.
+///
+/// See [`to_metric`] for converting each [`Event`] into a point [`Metric`] instead.
+#[derive(Clone, Debug)]
+pub struct EventMetrics {
+    /// Identifier of the Engine the metered [`Event`]s are produced by.
+    engine_id: Uuid,
+    /// [`Clock`](crate::clock::Clock) used to timestamp the `trader` [`Metric`].
+    clock: SharedClock,
+    /// Total [`Event`]s of each kind, keyed by market.
+    counts: HashMap<(Market, &'static str), u64>,
+    /// Time of the latest [`OrderEvent`](crate::portfolio::OrderEvent) awaiting a fill, keyed by
+    /// market.
+    orders: HashMap<(ExchangeId, MarketDataInstrument), DateTime<Utc>>,
+    /// Latest Portfolio [`Balance`].
+    balance: Option<Balance>,
+    /// Current value gross & unrealised profit & loss of every open Position.
+    positions: HashMap<SmolStr, (f64, f64)>,
+}
+
+impl EventMetrics {
+    pub fn new(engine_id: Uuid, clock: SharedClock) -> Self {
+        Self {
+            engine_id,
+            clock,
+            counts: HashMap::new(),
+            orders: HashMap::new(),
+            balance: None,
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Updates the [`EventMetrics`] with the next [`Event`] produced by the
+    /// [`Trader`](crate::engine::trader::Trader) of the provided [`Market`], returning the
+    /// updated [`Metric`]s.
+    pub fn update(&mut self, market: &Market, event: &Event) -> Vec<Metric> {
+        let kind = kind(event);
+        let count = self.counts.entry((market.clone(), kind)).or_default();
+        *count += 1;
+
+        let mut metrics = vec![Metric {
+            name: "trader",
+            time: timestamp(self.clock.time()),
+            tags: vec![
+                Tag::new("engine_id", self.engine_id.to_string()),
+                Tag::new("exchange", market.exchange.as_str()),
+                Tag::new("instrument", market.instrument.to_string()),
+                Tag::new("kind", kind),
+            ],
+            fields: vec![Field::new("events_total", *count)],
+        }];
+
+        match event {
+            Event::OrderNew(order) => {
+                self.orders
+                    .insert((order.exchange, order.instrument.clone()), order.time);
+            }
+            Event::Fill(fill) => {
+                let key = (fill.exchange, fill.instrument.clone());
+                if let Some(order_time) = self.orders.remove(&key) {
+                    metrics.push(order_latency(self.engine_id, &key, order_time, fill.time));
+                }
+            }
+            Event::Balance(balance) => {
+                self.balance = Some(*balance);
+                metrics.extend(self.portfolio(balance.time));
+            }
+            Event::PositionNew(position) => {
+                self.positions.insert(
+                    position.position_id.clone(),
+                    (
+                        position.current_value_gross,
+                        position.unrealised_profit_loss,
+                    ),
+                );
+            }
+            Event::PositionUpdate(update) => {
+                self.positions.insert(
+                    update.position_id.clone(),
+                    (update.current_value_gross, update.unrealised_profit_loss),
+                );
+                metrics.extend(self.portfolio(update.update_time));
+            }
+            Event::PositionExit(exit) => {
+                self.positions.remove(&exit.position_id);
+            }
+            _ => {}
+        }
+
+        metrics
+    }
+
+    /// Mark-to-market equity of the Portfolio, being the [`Balance`] total & the unrealised
+    /// profit & loss of every open Position.
+    pub fn equity(&self) -> Option<f64> {
+        self.balance.map(|balance| {
+            balance.total
+                + self
+                    .positions
+                    .values()
+                    .map(|(_, unrealised)| unrealised)
+                    .sum::<f64>()
+        })
+    }
+
+    /// Gross value of every open Position as a fraction of the mark-to-market equity.
+    pub fn exposure(&self) -> Option<f64> {
+        self.equity().map(|equity| {
+            let value_gross = self.positions.values().map(|(value, _)| value).sum::<f64>();
+            match equity == 0.0 {
+                true => 0.0,
+                false => value_gross / equity,
+            }
+        })
+    }
+
+    /// Generates the `portfolio` [`Metric`], if a [`Balance`] has been observed.
+    fn portfolio(&self, time: DateTime<Utc>) -> Option<Metric> {
+        let (balance, equity, exposure) = (self.balance?, self.equity()?, self.exposure()?);
+
+        Some(Metric {
+            name: "portfolio",
+            time: timestamp(time),
+            tags: vec![Tag::new("engine_id", self.engine_id.to_string())],
+            fields: vec![
+                Field::new("equity", equity),
+                Field::new("available_cash", balance.available),
+                Field::new("exposure", exposure),
+                Field::new("open_positions", self.positions.len() as u64),
+            ],
+        })
+    }
+}
+
+/// Generates the `order_latency` [`Metric`] of a market.
+fn order_latency(
+    engine_id: Uuid,
+    (exchange, instrument): &(ExchangeId, MarketDataInstrument),
+    order_time: DateTime<Utc>,
+    fill_time: DateTime<Utc>,
+) -> Metric {
+    let latency = fill_time.signed_duration_since(order_time);
+
+    Metric {
+        name: "order_latency",
+        time: timestamp(fill_time),
+        tags: vec![
+            Tag::new("engine_id", engine_id.to_string()),
+            Tag::new("exchange", exchange.as_str()),
+            Tag::new("instrument", instrument.to_string()),
+        ],
+        fields: vec![Field::new(
+            "seconds",
+            latency.num_milliseconds() as f64 / 1000.0,
+        )],
+    }
+}
+
+/// Name of the [`Event`] kind used to tag the `trader` [`Metric`].
+fn kind(event: &Event) -> &'static str {
+    match event {
+        Event::Market(_) => "market",
+        Event::Signal(_) => "signal",
+        Event::SignalForceExit(_) => "signal_force_exit",
+        Event::OrderNew(_) => "order_new",
+        Event::OrderUpdate(_) => "order_update",
+        Event::Fill(_) => "fill",
+        Event::PositionNew(_) => "position_new",
+        Event::PositionUpdate(_) => "position_update",
+        Event::PositionExit(_) => "position_exit",
+        Event::Balance(_) => "balance",
+        Event::Valuation(_) => "valuation",
+        Event::Error(_) => "error",
+    }
+}
+
+/// [`MessageTransmitter`] wrapper that generates [`EventMetrics`] [`Metric`]s for every
+/// [`Event`] before forwarding it to the inner transmitter. Used in place of a
+/// [`Trader`](crate::engine::trader::Trader)'s `event_tx` to meter it's [`Event`]s.
+///
+/// The [`EventMetrics`] should be shared by the [`MeteredEventTx`] of every Trader in an Engine.
+#[derive(Debug)]
+pub struct MeteredEventTx<EventTx, MetricTx> {
+    event_tx: EventTx,
+    metric_tx: MetricTx,
+    metrics: Arc<Mutex<EventMetrics>>,
+    market: Market,
+}
+
+impl<EventTx, MetricTx> MeteredEventTx<EventTx, MetricTx> {
+    /// Constructs a new [`MeteredEventTx`] for the Trader of the provided [`Market`], forwarding
+    /// [`Event`]s to the provided `event_tx`, and sending the [`Metric`]s generated by the shared
+    /// [`EventMetrics`] to the provided `metric_tx`.
+    pub fn new(
+        event_tx: EventTx,
+        metric_tx: MetricTx,
+        metrics: Arc<Mutex<EventMetrics>>,
+        market: Market,
+    ) -> Self {
+        Self {
+            event_tx,
+            metric_tx,
+            metrics,
+            market,
+        }
+    }
+}
+
+impl<EventTx, MetricTx> MessageTransmitter<Event> for MeteredEventTx<EventTx, MetricTx>
+where
+    EventTx: MessageTransmitter<Event>,
+    MetricTx: MessageTransmitter<Metric>,
+{
+    fn send(&mut self, message: Event) {
+        let metrics = self.metrics.lock().update(&self.market, &message);
+        self.metric_tx.send_many(metrics);
+        self.event_tx.send(message);
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        let metrics = {
+            let mut event_metrics = self.metrics.lock();
+            messages
+                .iter()
+                .flat_map(|message| event_metrics.update(&self.market, message))
+                .collect()
+        };
+        self.metric_tx.send_many(metrics);
+        self.event_tx.send_many(messages);
+    }
+}
+
+/// Converts an [`Event`] into a single point [`Metric`] describing it, suitable for a time
+/// series database (eg/ InfluxDB via [`InfluxSink`](barter_integration::metric::influx::InfluxSink)).
+///
+/// Fills, Position entries, updates & exits, Balances and Valuations are converted, returning
+/// `None` for every other [`Event`].
+///
+/// Every point is tagged with the provided engine_id, and Position points with their
+/// position_id & market, so points sharing a timestamp are never replaced by one another. The
+/// provided [`Market`] is that of the [`Trader`](crate::engine::trader::Trader) producing the
+/// [`Event`], since Position updates & exits only carry a position_id.
+pub fn to_metric(engine_id: Uuid, market: &Market, event: &Event) -> Option<Metric> {
+    let engine_id = Tag::new("engine_id", engine_id.to_string());
+
+    let metric = match event {
+        Event::Fill(fill) => Metric {
+            name: "fill",
+            time: timestamp(fill.time),
+            tags: vec![
+                engine_id,
+                Tag::new("exchange", fill.exchange.as_str()),
+                Tag::new("instrument", fill.instrument.to_string()),
+                Tag::new("decision", decision(&fill.decision)),
+            ],
+            fields: vec![
+                Field::new("quantity", fill.quantity),
+                Field::new("fill_value_gross", fill.fill_value_gross),
+                Field::new("fees", fill.fees.calculate_total_fees()),
+            ],
+        },
+        Event::PositionNew(position) => Metric {
+            name: "position_new",
+            time: timestamp(position.meta.enter_time),
+            tags: vec![
+                engine_id,
+                Tag::new("position_id", position.position_id.to_string()),
+                Tag::new("exchange", position.exchange.as_str()),
+                Tag::new("instrument", position.instrument.to_string()),
+                Tag::new("side", position.side.to_string()),
+            ],
+            fields: vec![
+                Field::new("quantity", position.quantity),
+                Field::new("enter_avg_price_gross", position.enter_avg_price_gross),
+                Field::new("enter_value_gross", position.enter_value_gross),
+                Field::new("enter_fees", position.enter_fees_total),
+            ],
+        },
+        Event::PositionUpdate(update) => Metric {
+            name: "position_update",
+            time: timestamp(update.update_time),
+            tags: vec![
+                engine_id,
+                Tag::new("position_id", update.position_id.to_string()),
+                Tag::new("exchange", market.exchange.as_str()),
+                Tag::new("instrument", market.instrument.to_string()),
+            ],
+            fields: vec![
+                Field::new("current_price", update.current_price),
+                Field::new("current_value_gross", update.current_value_gross),
+                Field::new("unrealised_profit_loss", update.unrealised_profit_loss),
+            ],
+        },
+        Event::PositionExit(exit) => Metric {
+            name: "position_exit",
+            time: timestamp(exit.exit_time),
+            tags: vec![
+                engine_id,
+                Tag::new("position_id", exit.position_id.to_string()),
+                Tag::new("exchange", market.exchange.as_str()),
+                Tag::new("instrument", market.instrument.to_string()),
+            ],
+            fields: vec![
+                Field::new("exit_avg_price_gross", exit.exit_avg_price_gross),
+                Field::new("exit_value_gross", exit.exit_value_gross),
+                Field::new("exit_fees", exit.exit_fees_total),
+                Field::new("realised_profit_loss", exit.realised_profit_loss),
+            ],
+        },
+        Event::Balance(balance) => Metric {
+            name: "balance",
+            time: timestamp(balance.time),
+            tags: vec![engine_id],
+            fields: vec![
+                Field::new("total", balance.total),
+                Field::new("available", balance.available),
+            ],
+        },
+        Event::Valuation(valuation) => Metric {
+            name: "valuation",
+            time: timestamp(valuation.time),
+            tags: vec![
+                engine_id,
+                Tag::new("reporting", valuation.reporting.to_string()),
+            ],
+            fields: vec![
+                Field::new("total", valuation.total),
+                Field::new("available", valuation.available),
+                Field::new("unpriced", valuation.unpriced.len() as u64),
+            ],
+        },
+        _ => return None,
+    };
+
+    Some(metric)
+}
+
+/// Milliseconds since the Unix epoch of the provided time, clamped to zero.
+fn timestamp(time: DateTime<Utc>) -> u64 {
+    time.timestamp_millis().max(0) as u64
+}
+
+/// Name of the [`Decision`] used to tag the `fill` [`Metric`].
+fn decision(decision: &Decision) -> &'static str {
+    match decision {
+        Decision::Long => "long",
+        Decision::CloseLong => "close_long",
+        Decision::Short => "short",
+        Decision::CloseShort => "close_short",
+    }
+}
+
+/// [`MessageTransmitter`] adapter that converts [`Event`]s into point [`Metric`]s using
+/// [`to_metric`], sending them via the inner [`Metric`] transmitter. [`Event`]s without a point
+/// representation are dropped.
+///
+/// eg/ Used as a [`Trader`](crate::engine::trader::Trader)'s `event_tx` with a
+/// [`MetricTx`](crate::event::MetricTx) feeding an
+/// [`InfluxSink`](barter_integration::metric::influx::InfluxSink).
+#[derive(Debug)]
+pub struct EventMetricTx<MetricTx> {
+    metric_tx: MetricTx,
+    engine_id: Uuid,
+    market: Market,
+}
+
+impl<MetricTx> EventMetricTx<MetricTx> {
+    /// Constructs a new [`EventMetricTx`] for the Trader of the provided engine_id & [`Market`],
+    /// sending converted [`Event`]s to the provided `metric_tx`.
+    pub fn new(metric_tx: MetricTx, engine_id: Uuid, market: Market) -> Self {
+        Self {
+            metric_tx,
+            engine_id,
+            market,
+        }
+    }
+}
+
+impl<MetricTx> MessageTransmitter<Event> for EventMetricTx<MetricTx>
+where
+    MetricTx: MessageTransmitter<Metric>,
+{
+    fn send(&mut self, message: Event) {
+        if let Some(metric) = to_metric(self.engine_id, &self.market, &message) {
+            self.metric_tx.send(metric)
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        self.metric_tx.send_many(
+            messages
+                .iter()
+                .filter_map(|message| to_metric(self.engine_id, &self.market, message))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clock::{self, HistoricalClock},
+        metric::MetricRegistry,
+        portfolio::{ledger::Valuation, position::PositionUpdate},
+        test_util,
+    };
+    use barter_instrument::{
+        asset::name::AssetNameInternal, instrument::market_data::kind::MarketDataInstrumentKind,
+    };
+    use chrono::Duration;
+
+    #[derive(Debug, Default)]
+    struct CollectEvents(Vec<Event>);
+
+    impl MessageTransmitter<Event> for CollectEvents {
+        fn send(&mut self, message: Event) {
+            self.0.push(message)
+        }
+
+        fn send_many(&mut self, messages: Vec<Event>) {
+            self.0.extend(messages)
+        }
+    }
+
+    #[test]
+    fn test_event_metrics_update() {
+        struct TestCase {
+            input: Event,
+            expected_metrics: Vec<&'static str>,
+            expected_equity: Option<f64>,
+            expected_exposure: Option<f64>,
+        }
+
+        let now = Utc::now();
+
+        let mut order = test_util::order_event();
+        order.time = now;
+        let mut fill = test_util::fill_event();
+        fill.time = now + Duration::milliseconds(250);
+        let mut position = test_util::position();
+        position.current_value_gross = 200.0;
+        position.unrealised_profit_loss = 10.0;
+
+        let market = Market::new(order.exchange, order.instrument.clone());
+        let mut metrics = EventMetrics::new(Uuid::new_v4(), clock::live());
+
+        let cases = vec![
+            // TC0: OrderNew is counted
+            TestCase {
+                input: Event::OrderNew(order),
+                expected_metrics: vec!["trader"],
+                expected_equity: None,
+                expected_exposure: None,
+            },
+            // TC1: Fill of the order generates the order latency
+            TestCase {
+                input: Event::Fill(fill),
+                expected_metrics: vec!["trader", "order_latency"],
+                expected_equity: None,
+                expected_exposure: None,
+            },
+            // TC2: PositionNew is tracked for the exposure
+            TestCase {
+                input: Event::PositionNew(position.clone()),
+                expected_metrics: vec!["trader"],
+                expected_equity: None,
+                expected_exposure: None,
+            },
+            // TC3: Balance generates the portfolio equity & exposure
+            TestCase {
+                input: Event::Balance(Balance {
+                    time: now,
+                    total: 990.0,
+                    available: 790.0,
+                }),
+                expected_metrics: vec!["trader", "portfolio"],
+                expected_equity: Some(1000.0),
+                expected_exposure: Some(0.2),
+            },
+            // TC4: PositionUpdate marks the portfolio equity to market
+            TestCase {
+                input: Event::PositionUpdate(PositionUpdate {
+                    position_id: position.position_id.clone(),
+                    update_time: now,
+                    current_price: 1.0,
+                    current_value_gross: 300.0,
+                    unrealised_profit_loss: 110.0,
+                }),
+                expected_metrics: vec!["trader", "portfolio"],
+                expected_equity: Some(1100.0),
+                expected_exposure: Some(300.0 / 1100.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = metrics.update(&market, &test.input);
+            assert_eq!(
+                actual.iter().map(|metric| metric.name).collect::<Vec<_>>(),
+                test.expected_metrics,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(metrics.equity(), test.expected_equity, "TC{} failed", index);
+            assert_eq!(
+                metrics.exposure(),
+                test.expected_exposure,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_metered_event_tx_forwards_events() {
+        let registry = MetricRegistry::new();
+        let order = test_util::order_event();
+        let metrics = Arc::new(Mutex::new(EventMetrics::new(Uuid::nil(), clock::live())));
+        let mut event_tx = MeteredEventTx::new(
+            CollectEvents::default(),
+            registry.clone(),
+            metrics,
+            Market::new(order.exchange, order.instrument.clone()),
+        );
+
+        event_tx.send(Event::OrderNew(order.clone()));
+        event_tx.send_many(vec![
+            Event::Fill(test_util::fill_event()),
+            Event::OrderNew(order),
+        ]);
+
+        assert_eq!(event_tx.event_tx.0.len(), 3);
+
+        let encoded = registry.encode();
+        let labels = format!(
+            "engine_id=\"{}\",exchange=\"binance_spot\",instrument=\"(eth_usdt, spot)\"",
+            Uuid::nil()
+        );
+        assert!(encoded.contains(&format!(
+            "barter_trader_events_total{{{labels},kind=\"order_new\"}} 2\n"
+        )));
+        assert!(encoded.contains(&format!(
+            "barter_trader_events_total{{{labels},kind=\"fill\"}} 1\n"
+        )));
+        assert!(encoded.contains("# TYPE barter_order_latency_seconds gauge\n"));
+    }
+
+    #[test]
+    fn test_metered_event_tx_shared_between_traders() {
+        let registry = MetricRegistry::new();
+        let time = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+        let metrics = Arc::new(Mutex::new(EventMetrics::new(
+            Uuid::nil(),
+            HistoricalClock::shared(time),
+        )));
+
+        let eth = test_util::position();
+        let mut btc = test_util::position();
+        btc.position_id = SmolStr::new("btc_position");
+        btc.instrument =
+            MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot));
+        btc.unrealised_profit_loss = 50.0;
+
+        let mut eth_tx = MeteredEventTx::new(
+            CollectEvents::default(),
+            registry.clone(),
+            Arc::clone(&metrics),
+            Market::new(eth.exchange, eth.instrument.clone()),
+        );
+        let mut btc_tx = MeteredEventTx::new(
+            CollectEvents::default(),
+            registry.clone(),
+            Arc::clone(&metrics),
+            Market::new(btc.exchange, btc.instrument.clone()),
+        );
+
+        eth_tx.send(Event::PositionNew(eth.clone()));
+        btc_tx.send(Event::PositionNew(btc.clone()));
+        btc_tx.send(Event::PositionNew(btc.clone()));
+        eth_tx.send(Event::Balance(Balance {
+            time,
+            total: 1000.0,
+            available: 800.0,
+        }));
+
+        // Equity includes the Positions of both Traders
+        assert_eq!(
+            metrics.lock().equity(),
+            Some(1000.0 + eth.unrealised_profit_loss + btc.unrealised_profit_loss)
+        );
+
+        // Each Trader's event count is a distinct series
+        let encoded = registry.encode();
+        for expected in [
+            "exchange=\"binance_spot\",instrument=\"(eth_usdt, spot)\",kind=\"position_new\"} 1\n",
+            "exchange=\"binance_spot\",instrument=\"(btc_usdt, spot)\",kind=\"position_new\"} 2\n",
+        ] {
+            assert!(
+                encoded.contains(expected),
+                "missing {expected:?} in {encoded}"
+            );
+        }
+
+        // Trader counts are stamped by the Clock
+        let trader = metrics.lock().update(
+            &Market::new(eth.exchange, eth.instrument.clone()),
+            &Event::OrderNew(test_util::order_event()),
+        );
+        assert_eq!(trader[0].time, 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_to_metric() {
+        struct TestCase {
+            input: Event,
+            expected: Option<(&'static str, Vec<&'static str>)>,
+        }
+
+        let now = Utc::now();
+        let position = test_util::position();
+        let market = Market::new(position.exchange, position.instrument.clone());
+
+        let cases = vec![
+            // TC0: Market event is not converted
+            TestCase {
+                input: Event::Market(test_util::market_event_trade(barter_integration::Side::Buy)),
+                expected: None,
+            },
+            // TC1: Fill is converted
+            TestCase {
+                input: Event::Fill(test_util::fill_event()),
+                expected: Some(("fill", vec!["quantity", "fill_value_gross", "fees"])),
+            },
+            // TC2: PositionNew is converted
+            TestCase {
+                input: Event::PositionNew(test_util::position()),
+                expected: Some((
+                    "position_new",
+                    vec![
+                        "quantity",
+                        "enter_avg_price_gross",
+                        "enter_value_gross",
+                        "enter_fees",
+                    ],
+                )),
+            },
+            // TC3: Balance is converted
+            TestCase {
+                input: Event::Balance(Balance {
+                    time: now,
+                    total: 1000.0,
+                    available: 800.0,
+                }),
+                expected: Some(("balance", vec!["total", "available"])),
+            },
+            // TC4: Valuation is converted
+            TestCase {
+                input: Event::Valuation(Valuation {
+                    time: now,
+                    reporting: AssetNameInternal::from("usdt"),
+                    total: 1000.0,
+                    available: 800.0,
+                    unpriced: vec![],
+                }),
+                expected: Some(("valuation", vec!["total", "available", "unpriced"])),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = to_metric(Uuid::nil(), &market, &test.input).map(|metric| {
+                (
+                    metric.name,
+                    metric
+                        .fields
+                        .iter()
+                        .map(|field| field.key)
+                        .collect::<Vec<_>>(),
+                )
+            });
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_event_metric_tx_line_protocol() {
+        let mut fill = test_util::fill_event();
+        fill.time = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+        fill.quantity = 1.0;
+        fill.fill_value_gross = 100.0;
+        fill.fees = crate::execution::Fees {
+            exchange: 0.5,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let registry = MetricRegistry::new();
+        let market = Market::new(fill.exchange, fill.instrument.clone());
+        let mut event_tx = EventMetricTx::new(registry.clone(), Uuid::nil(), market.clone());
+        event_tx.send_many(vec![
+            Event::OrderNew(test_util::order_event()),
+            Event::Fill(fill.clone()),
+        ]);
+
+        assert!(registry
+            .encode()
+            .contains("barter_fill_fill_value_gross{decision=\"long\",engine_id=\"00000000-0000-0000-0000-000000000000\",exchange=\"binance_spot\""));
+        assert_eq!(
+            to_metric(Uuid::nil(), &market, &Event::Fill(fill))
+                .unwrap()
+                .to_line_protocol()
+                .unwrap(),
+            "fill,decision=long,engine_id=00000000-0000-0000-0000-000000000000,\
+             exchange=binance_spot,instrument=(eth_usdt\\,\\ spot) quantity=1,fill_value_gross=100,fees=0.5 1700000000000"
+        );
+    }
+
+    #[test]
+    fn test_to_metric_positions_at_same_time_are_distinct_series() {
+        let time = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("eth", "usdt", MarketDataInstrumentKind::Spot),
+        );
+        let update = |position_id: &str| {
+            Event::PositionUpdate(PositionUpdate {
+                position_id: SmolStr::new(position_id),
+                update_time: time,
+                current_price: 1.0,
+                current_value_gross: 100.0,
+                unrealised_profit_loss: 0.0,
+            })
+        };
+
+        let series = [update("position_a"), update("position_b")]
+            .iter()
+            .map(|event| {
+                let line = to_metric(Uuid::nil(), &market, event)
+                    .unwrap()
+                    .to_line_protocol()
+                    .unwrap();
+                // Series key is the measurement & tag set, before the first unescaped space
+                let (series, _) = line.split_once(" current_price").unwrap();
+                series.to_owned()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            series[0].contains("position_id=position_a"),
+            "{}",
+            series[0]
+        );
+        assert!(series[0].contains("exchange=binance_spot"), "{}", series[0]);
+        assert_ne!(series[0], series[1]);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::event::MessageTransmitter;
+use barter_integration::metric::{Metric, Value};
+use parking_lot::Mutex;
+use std::{collections::BTreeMap, fmt::Write, sync::Arc};
+use tokio::sync::mpsc;
+
+/// [`Metric`] adapters for trader [`Event`](crate::event::Event)s, including event counts,
+/// order latency, portfolio equity / exposure & point conversion of fills, positions & balances.
+pub mod event;
+
+/// [`Metric`] adapters for market data streams, including message & reconnection counts.
+pub mod stream;
+
+/// Namespace prefixed to every exported metric family name.
+const NAMESPACE: &str = "barter";
+
+/// Type of an exported metric family.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MetricKind {
+    /// Monotonically increasing total, identified by a field key ending in `_total`.
+    Counter,
+    /// Latest observed value.
+    Gauge,
+}
+
+impl MetricKind {
+    /// Determine the [`MetricKind`] of a [`Metric`] field key. Following the Prometheus naming
+    /// conventions, cumulative totals are suffixed with `_total`.
+    pub fn from_field(key: &str) -> Self {
+        match key.ends_with("_total") {
+            true => MetricKind::Counter,
+            false => MetricKind::Gauge,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+        }
+    }
+}
+
+/// Series of a metric family, keyed by their sorted label pairs.
+#[derive(Clone, Debug)]
+struct Family {
+    kind: MetricKind,
+    series: BTreeMap<Vec<(String, String)>, f64>,
+}
+
+/// Registry collecting the latest value of every [`Metric`] field, encoding them in the
+/// Prometheus text exposition format.
+///
+/// Every numeric [`Metric`] field is exported as a metric family named
+/// `barter_<metric.name>_<field.key>`, labelled with the [`Metric`] tags. If the [`Metric`] name
+/// already ends with the field key (eg/ `http_request_duration` & `duration`), the field key is
+/// not repeated. String fields cannot be represented by Prometheus & are ignored.
+///
+/// Cloning a [`MetricRegistry`] shares the collected metrics, so one clone can consume
+/// [`Metric`]s whilst another is served by an exporter.
+#[derive(Clone, Debug, Default)]
+pub struct MetricRegistry {
+    families: Arc<Mutex<BTreeMap<String, Family>>>,
+}
+
+impl MessageTransmitter<Metric> for MetricRegistry {
+    fn send(&mut self, message: Metric) {
+        self.record(&message)
+    }
+
+    fn send_many(&mut self, messages: Vec<Metric>) {
+        messages.iter().for_each(|message| self.record(message))
+    }
+}
+
+impl MetricRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the latest value of every numeric field of the provided [`Metric`].
+    pub fn record(&self, metric: &Metric) {
+        let mut labels = metric
+            .tags
+            .iter()
+            .map(|tag| (sanitise(tag.key), tag.value.clone()))
+            .collect::<Vec<_>>();
+        labels.sort();
+
+        let mut families = self.families.lock();
+        for field in metric.fields.iter() {
+            let Some(value) = numeric(&field.value) else {
+                continue;
+            };
+
+            families
+                .entry(family_name(metric.name, field.key))
+                .or_insert_with(|| Family {
+                    kind: MetricKind::from_field(field.key),
+                    series: BTreeMap::new(),
+                })
+                .series
+                .insert(labels.clone(), value);
+        }
+    }
+
+    /// Record every [`Metric`] received via the provided channel, until every transmitter is
+    /// dropped.
+    pub async fn consume(self, mut metric_rx: mpsc::UnboundedReceiver<Metric>) {
+        while let Some(metric) = metric_rx.recv().await {
+            self.record(&metric);
+        }
+    }
+
+    /// Encode every collected metric family in the Prometheus text exposition format.
+    pub fn encode(&self) -> String {
+        let families = self.families.lock();
+
+        let mut output = String::new();
+        for (name, family) in families.iter() {
+            let _ = writeln!(output, "# TYPE {name} {}", family.kind.as_str());
+
+            for (labels, value) in family.series.iter() {
+                output.push_str(name);
+
+                if !labels.is_empty() {
+                    let labels = labels
+                        .iter()
+                        .map(|(key, value)| format!("{key}=\"{}\"", escape_label(value)))
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    let _ = write!(output, "{{{labels}}}");
+                }
+
+                let _ = writeln!(output, " {}", format_value(*value));
+            }
+        }
+
+        output
+    }
+}
+
+/// Determine the metric family name of a [`Metric`] field.
+fn family_name(name: &str, field: &str) -> String {
+    match name.ends_with(field) {
+        true => sanitise(&format!("{NAMESPACE}_{name}")),
+        false => sanitise(&format!("{NAMESPACE}_{name}_{field}")),
+    }
+}
+
+/// Replace every character that is not valid in a Prometheus metric or label name.
+fn sanitise(name: &str) -> String {
+    name.chars()
+        .map(
+            |character| match character.is_ascii_alphanumeric() || character == '_' {
+                true => character,
+                false => '_',
+            },
+        )
+        .collect()
+}
+
+/// Escape the backslash, double-quote & line feed characters of a label value.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Convert a numeric [`Value`] to the `f64` sample value. String values are not numeric.
+fn numeric(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(value) => Some(*value),
+        Value::Int(value) => Some(*value as f64),
+        Value::UInt(value) => Some(*value as f64),
+        Value::Bool(value) => Some(if *value { 1.0 } else { 0.0 }),
+        Value::String(_) => None,
+    }
+}
+
+/// Format a sample value, using the Prometheus representation of non-finite values.
+fn format_value(value: f64) -> String {
+    match value {
+        value if value.is_nan() => "NaN".to_string(),
+        value if value == f64::INFINITY => "+Inf".to_string(),
+        value if value == f64::NEG_INFINITY => "-Inf".to_string(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::metric::{Field, Tag};
+
+    fn metric(name: &'static str, tags: Vec<Tag>, fields: Vec<Field>) -> Metric {
+        Metric {
+            name,
+            time: 0,
+            tags,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_metric_registry_encode() {
+        struct TestCase {
+            input: Vec<Metric>,
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            // TC0: no metrics
+            TestCase {
+                input: vec![],
+                expected: "",
+            },
+            // TC1: RestClient latency Metric does not repeat the field key
+            TestCase {
+                input: vec![metric(
+                    "http_request_duration",
+                    vec![Tag::new("path", "/order"), Tag::new("http_method", "POST")],
+                    vec![Field::new("duration", 15_u64)],
+                )],
+                expected: "# TYPE barter_http_request_duration gauge\n\
+                    barter_http_request_duration{http_method=\"POST\",path=\"/order\"} 15\n",
+            },
+            // TC2: latest value of a series is exported, totals are counters & strings ignored
+            TestCase {
+                input: vec![
+                    metric(
+                        "market_stream",
+                        vec![Tag::new("exchange", "binance_spot")],
+                        vec![Field::new("messages_total", 1_u64)],
+                    ),
+                    metric(
+                        "market_stream",
+                        vec![Tag::new("exchange", "binance_spot")],
+                        vec![
+                            Field::new("messages_total", 2_u64),
+                            Field::new("status", "healthy".to_string()),
+                        ],
+                    ),
+                ],
+                expected: "# TYPE barter_market_stream_messages_total counter\n\
+                    barter_market_stream_messages_total{exchange=\"binance_spot\"} 2\n",
+            },
+            // TC3: label values are escaped & non-finite values use the Prometheus format
+            TestCase {
+                input: vec![metric(
+                    "portfolio",
+                    vec![Tag::new("engine", "a\"b\\c")],
+                    vec![Field::new("exposure", f64::NAN), Field::new("equity", 1.5)],
+                )],
+                expected: "# TYPE barter_portfolio_equity gauge\n\
+                    barter_portfolio_equity{engine=\"a\\\"b\\\\c\"} 1.5\n\
+                    # TYPE barter_portfolio_exposure gauge\n\
+                    barter_portfolio_exposure{engine=\"a\\\"b\\\\c\"} NaN\n",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut registry = MetricRegistry::new();
+            registry.send_many(test.input);
+            assert_eq!(registry.encode(), test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::event::MessageTransmitter;
+use barter_data::streams::reconnect;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::metric::{Field, Metric, Tag};
+use chrono::Utc;
+use futures::{Stream, StreamExt};
+use std::collections::HashMap;
+
+/// Message & reconnection counts of a market data stream, per [`ExchangeId`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct StreamCounts {
+    pub messages: u64,
+    pub reconnects: u64,
+}
+
+/// Counts the messages & reconnections of market data streams, generating a `market_stream`
+/// [`Metric`] for every
+/// [`MarketStreamEvent`](barter_data::streams::consumer::MarketStreamEvent).
+///
+/// Message rates are derived from the `messages_total` counter by the metrics backend
+/// (eg/ `rate(barter_market_stream_messages_total[1m])`).
+#[derive(Clone, Debug, Default)]
+pub struct StreamMetrics {
+    counts: HashMap<ExchangeId, StreamCounts>,
+}
+
+impl StreamMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the [`StreamCounts`] of the [`ExchangeId`] the stream event originated from,
+    /// returning the updated `market_stream` [`Metric`].
+    pub fn update<T>(&mut self, event: &reconnect::Event<ExchangeId, T>) -> Metric
+    where
+        T: StreamItem,
+    {
+        let exchange = match event {
+            reconnect::Event::Reconnecting(exchange) => *exchange,
+            reconnect::Event::Item(item) => item.exchange(),
+        };
+
+        let counts = self.counts.entry(exchange).or_default();
+        match event {
+            reconnect::Event::Reconnecting(_) => counts.reconnects += 1,
+            reconnect::Event::Item(_) => counts.messages += 1,
+        }
+
+        Metric {
+            name: "market_stream",
+            time: Utc::now().timestamp_millis() as u64,
+            tags: vec![Tag::new("exchange", exchange.as_str())],
+            fields: vec![
+                Field::new("messages_total", counts.messages),
+                Field::new("reconnects_total", counts.reconnects),
+            ],
+        }
+    }
+
+    /// Returns the [`StreamCounts`] of the provided [`ExchangeId`].
+    pub fn counts(&self, exchange: &ExchangeId) -> StreamCounts {
+        self.counts.get(exchange).copied().unwrap_or_default()
+    }
+}
+
+/// Item yielded by a market data stream that originates from an [`ExchangeId`].
+pub trait StreamItem {
+    fn exchange(&self) -> ExchangeId;
+}
+
+impl<InstrumentKey, Kind> StreamItem for barter_data::event::MarketEvent<InstrumentKey, Kind> {
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+}
+
+impl<T, E> StreamItem for Result<T, E>
+where
+    T: StreamItem,
+{
+    fn exchange(&self) -> ExchangeId {
+        match self {
+            Ok(item) => item.exchange(),
+            Err(_) => ExchangeId::Other,
+        }
+    }
+}
+
+/// Meters a reconnecting market data stream, sending the `market_stream` [`Metric`] generated
+/// by [`StreamMetrics`] for every event via the provided transmitter. Events are yielded
+/// unchanged, so the metered stream can be used in place of the original (eg/ to construct a
+/// [`ReconnectingMarketFeed`](crate::data::live::ReconnectingMarketFeed)).
+pub fn metered<St, T, Tx>(stream: St, mut metric_tx: Tx) -> impl Stream<Item = St::Item>
+where
+    St: Stream<Item = reconnect::Event<ExchangeId, T>>,
+    T: StreamItem,
+    Tx: MessageTransmitter<Metric>,
+{
+    let mut metrics = StreamMetrics::new();
+    stream.map(move |event| {
+        metric_tx.send(metrics.update(&event));
+        event
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{metric::MetricRegistry, test_util};
+    use barter_integration::{metric::Value, Side};
+
+    #[test]
+    fn test_stream_metrics_update() {
+        struct TestCase {
+            input: reconnect::Event<ExchangeId, barter_data::event::MarketEvent>,
+            expected: StreamCounts,
+        }
+
+        let mut metrics = StreamMetrics::new();
+
+        let cases = vec![
+            // TC0: message received
+            TestCase {
+                input: reconnect::Event::Item(test_util::market_event_trade(Side::Buy)),
+                expected: StreamCounts {
+                    messages: 1,
+                    reconnects: 0,
+                },
+            },
+            // TC1: stream reconnecting
+            TestCase {
+                input: reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+                expected: StreamCounts {
+                    messages: 1,
+                    reconnects: 1,
+                },
+            },
+            // TC2: message received after reconnecting
+            TestCase {
+                input: reconnect::Event::Item(test_util::market_event_trade(Side::Sell)),
+                expected: StreamCounts {
+                    messages: 2,
+                    reconnects: 1,
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let metric = metrics.update(&test.input);
+            assert_eq!(
+                metrics.counts(&ExchangeId::BinanceSpot),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                metric.fields,
+                vec![
+                    Field::new("messages_total", Value::UInt(test.expected.messages)),
+                    Field::new("reconnects_total", Value::UInt(test.expected.reconnects)),
+                ],
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metered_stream_yields_events_unchanged() {
+        let registry = MetricRegistry::new();
+        let events = vec![
+            reconnect::Event::Item(test_util::market_event_trade(Side::Buy)),
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+        ];
+
+        let metered = metered(futures::stream::iter(events.clone()), registry.clone())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(metered, events);
+        assert!(registry
+            .encode()
+            .contains("barter_market_stream_reconnects_total{exchange=\"binance_spot\"} 1\n"));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::{
+    portfolio::{reconcile::Drift, repository::error::RepositoryError},
+    statistic::error::StatisticError,
+};
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -19,6 +22,21 @@ pub enum PortfolioError {
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("Risk manager does not support updating it's parameters at runtime")]
+    RiskUpdateUnsupported,
+
+    #[error("Failed to deserialise risk parameters: {0}")]
+    RiskParameters(#[from] serde_json::Error),
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
+
+    #[error("Statistic error: {0}")]
+    Statistic(#[from] StatisticError),
+
+    #[error("Failed to interact with venue: {0}")]
+    VenueInteraction(#[from] barter_execution::error::ExecutionError),
+
+    #[error("Reconciliation aborted due to drift between repository & venue: {0:?}")]
+    ReconcileAborted(Vec<Drift>),
 }
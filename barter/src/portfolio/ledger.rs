@@ -0,0 +1,469 @@
+use crate::{
+    data::MarketMeta, execution::FillEvent, portfolio::position::Position, strategy::Decision,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::balance::{AssetBalance, Balance, BalanceDelta};
+use barter_instrument::{
+    asset::name::AssetNameInternal,
+    exchange::ExchangeId,
+    instrument::market_data::{kind::MarketDataInstrumentKind, MarketDataInstrument},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Asset held at an exchange.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ExchangeAsset {
+    pub exchange: ExchangeId,
+    pub asset: AssetNameInternal,
+}
+
+impl ExchangeAsset {
+    pub fn new<S>(exchange: ExchangeId, asset: S) -> Self
+    where
+        S: Into<AssetNameInternal>,
+    {
+        Self {
+            exchange,
+            asset: asset.into(),
+        }
+    }
+}
+
+/// [`Balance`] of an asset held at an exchange, as persisted in a Portfolio repository.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ExchangeAssetBalance {
+    pub asset: ExchangeAsset,
+    pub balance: Balance,
+}
+
+impl ExchangeAssetBalance {
+    pub fn new(asset: ExchangeAsset, balance: Balance) -> Self {
+        Self { asset, balance }
+    }
+}
+
+/// Value of every [`BalanceLedger`] [`Balance`] in the reporting asset at a point in time.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Valuation {
+    pub time: DateTime<Utc>,
+    pub reporting: AssetNameInternal,
+    pub total: f64,
+    pub available: f64,
+    /// Held assets without a price in the reporting asset, excluded from the totals.
+    pub unpriced: Vec<ExchangeAsset>,
+}
+
+/// Per-exchange, per-asset [`Balance`] ledger of a Portfolio, mirroring the
+/// [`AssetBalance`]s held at each venue.
+///
+/// [`FillEvent`]s of spot instruments exchange the quote asset for the base asset (& vice versa),
+/// so spot holdings are tracked in the asset actually held. Derivative [`FillEvent`]s only charge
+/// fees to the quote asset, with the gross profit & loss settled when the [`Position`] exits.
+///
+/// Holdings are valued in the reporting asset using the latest spot price observed for each
+/// asset pair, from any exchange.
+///
+/// A [`MetaPortfolio`](crate::portfolio::portfolio::MetaPortfolio) persists every [`Balance`] as
+/// an [`ExchangeAssetBalance`] via it's repository, and emits the [`Valuation`] as an
+/// [`Event`](crate::event::Event) after every [`FillEvent`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct BalanceLedger {
+    reporting: AssetNameInternal,
+    balances: BTreeMap<ExchangeAsset, Balance>,
+    prices: HashMap<(AssetNameInternal, AssetNameInternal), f64>,
+}
+
+impl BalanceLedger {
+    /// Constructs a new empty [`BalanceLedger`], valued in the provided reporting asset.
+    pub fn new<S>(reporting: S) -> Self
+    where
+        S: Into<AssetNameInternal>,
+    {
+        Self {
+            reporting: reporting.into(),
+            balances: BTreeMap::new(),
+            prices: HashMap::new(),
+        }
+    }
+
+    /// Set the [`Balance`] of every provided [`AssetBalance`] held at the exchange, eg/ with the
+    /// starting balances, or balances fetched from the venue.
+    pub fn set_balances<Balances>(&mut self, exchange: ExchangeId, balances: Balances)
+    where
+        Balances: IntoIterator<Item = AssetBalance>,
+    {
+        for AssetBalance { asset, balance } in balances {
+            self.balances
+                .insert(ExchangeAsset { exchange, asset }, balance);
+        }
+    }
+
+    /// Set the [`Balance`] of a single asset held at an exchange, eg/ when restoring the
+    /// [`ExchangeAssetBalance`]s persisted in a repository.
+    pub fn set_balance(&mut self, balance: ExchangeAssetBalance) {
+        self.balances.insert(balance.asset, balance.balance);
+    }
+
+    /// Returns the [`Balance`] of the asset held at the exchange, if any.
+    pub fn balance(&self, exchange: ExchangeId, asset: &AssetNameInternal) -> Option<Balance> {
+        self.balances
+            .get(&ExchangeAsset {
+                exchange,
+                asset: asset.clone(),
+            })
+            .copied()
+    }
+
+    /// Returns every [`Balance`] in the ledger, ordered by exchange & asset.
+    pub fn balances(&self) -> impl Iterator<Item = (&ExchangeAsset, &Balance)> {
+        self.balances.iter()
+    }
+
+    /// Returns the reporting asset [`Valuation`]s are calculated in.
+    pub fn reporting(&self) -> &AssetNameInternal {
+        &self.reporting
+    }
+
+    /// Updates the latest price of a spot instrument from the input [`MarketEvent`], returning
+    /// true if a price was observed.
+    pub fn update_price(&mut self, market: &MarketEvent<MarketDataInstrument, DataKind>) -> bool {
+        if market.instrument.kind != MarketDataInstrumentKind::Spot {
+            return false;
+        }
+
+        match MarketMeta::from_market_event(market) {
+            Some(meta) => {
+                self.prices.insert(
+                    (
+                        market.instrument.base.clone(),
+                        market.instrument.quote.clone(),
+                    ),
+                    meta.close,
+                );
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the latest price of the `base` asset denominated in the `quote` asset, using the
+    /// inverse price of the `quote` asset if only that has been observed.
+    pub fn price(&self, base: &AssetNameInternal, quote: &AssetNameInternal) -> Option<f64> {
+        if base == quote {
+            return Some(1.0);
+        }
+
+        self.prices
+            .get(&(base.clone(), quote.clone()))
+            .copied()
+            .or_else(|| {
+                self.prices
+                    .get(&(quote.clone(), base.clone()))
+                    .filter(|price| **price != 0.0)
+                    .map(|price| 1.0 / price)
+            })
+    }
+
+    /// Applies the input [`FillEvent`] to the [`Balance`]s of the exchange the fill occurred on.
+    ///
+    /// Fees are assumed to be denominated in the quote asset.
+    pub fn apply_fill(&mut self, fill: &FillEvent) {
+        let MarketDataInstrument { base, quote, kind } = &fill.instrument;
+        let fees = fill.fees.calculate_total_fees();
+
+        if *kind != MarketDataInstrumentKind::Spot {
+            self.apply(fill.exchange, quote, BalanceDelta::new(-fees, -fees));
+            return;
+        }
+
+        // Long entries & Short exits buy the base asset with the quote asset, whilst Short entries
+        // & Long exits sell the base asset for the quote asset
+        let quantity = fill.quantity.abs();
+        let (base_delta, quote_delta) = match fill.decision {
+            Decision::Long | Decision::CloseShort => (quantity, -fill.fill_value_gross - fees),
+            Decision::Short | Decision::CloseLong => (-quantity, fill.fill_value_gross - fees),
+        };
+
+        self.apply(
+            fill.exchange,
+            base,
+            BalanceDelta::new(base_delta, base_delta),
+        );
+        self.apply(
+            fill.exchange,
+            quote,
+            BalanceDelta::new(quote_delta, quote_delta),
+        );
+    }
+
+    /// Settles the gross profit & loss of an exited derivative [`Position`] in it's quote asset.
+    /// Spot [`Position`]s are settled by their [`FillEvent`]s, so are ignored.
+    pub fn apply_exit(&mut self, position: &Position) {
+        if position.instrument.kind == MarketDataInstrumentKind::Spot {
+            return;
+        }
+
+        // Fees were charged by each FillEvent, so are excluded from the settled amount
+        let gross =
+            position.realised_profit_loss + position.enter_fees_total + position.exit_fees_total;
+
+        self.apply(
+            position.exchange,
+            &position.instrument.quote,
+            BalanceDelta::new(gross, gross),
+        );
+    }
+
+    /// Values every [`Balance`] in the reporting asset at the provided time, using the latest
+    /// prices.
+    pub fn valuation(&self, time: DateTime<Utc>) -> Valuation {
+        let mut valuation = Valuation {
+            time,
+            reporting: self.reporting.clone(),
+            total: 0.0,
+            available: 0.0,
+            unpriced: Vec::new(),
+        };
+
+        for (key, balance) in self.balances.iter() {
+            match self.price(&key.asset, &self.reporting) {
+                Some(price) => {
+                    valuation.total += balance.total * price;
+                    valuation.available += balance.available * price;
+                }
+                None if balance.total != 0.0 => valuation.unpriced.push(key.clone()),
+                None => {}
+            }
+        }
+
+        valuation
+    }
+
+    /// Apply the [`BalanceDelta`] to the asset held at the exchange, inserting an empty
+    /// [`Balance`] if the asset is not yet held.
+    fn apply(&mut self, exchange: ExchangeId, asset: &AssetNameInternal, delta: BalanceDelta) {
+        self.balances
+            .entry(ExchangeAsset {
+                exchange,
+                asset: asset.clone(),
+            })
+            .or_insert(Balance::new(0.0, 0.0))
+            .apply(delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{execution::Fees, test_util};
+
+    fn ledger() -> BalanceLedger {
+        let mut ledger = BalanceLedger::new("usdt");
+        ledger.set_balances(
+            ExchangeId::BinanceSpot,
+            [AssetBalance::new("usdt", Balance::new(1000.0, 1000.0))],
+        );
+        ledger
+    }
+
+    fn perpetual() -> MarketDataInstrument {
+        MarketDataInstrument::from(("eth", "usdt", MarketDataInstrumentKind::Perpetual))
+    }
+
+    #[test]
+    fn test_apply_fill() {
+        struct TestCase {
+            input: FillEvent,
+            expected_eth: Option<Balance>,
+            expected_usdt: Balance,
+        }
+
+        let fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let cases = vec![
+            // TC0: spot Long entry buys base asset with quote asset & fees
+            TestCase {
+                input: FillEvent {
+                    decision: Decision::Long,
+                    fees,
+                    ..test_util::fill_event()
+                },
+                expected_eth: Some(Balance::new(1.0, 1.0)),
+                expected_usdt: Balance::new(899.0, 899.0),
+            },
+            // TC1: spot Short entry sells base asset for quote asset less fees
+            TestCase {
+                input: FillEvent {
+                    decision: Decision::Short,
+                    quantity: -1.0,
+                    fees,
+                    ..test_util::fill_event()
+                },
+                expected_eth: Some(Balance::new(-1.0, -1.0)),
+                expected_usdt: Balance::new(1099.0, 1099.0),
+            },
+            // TC2: spot CloseLong exit sells base asset for quote asset less fees
+            TestCase {
+                input: FillEvent {
+                    decision: Decision::CloseLong,
+                    quantity: -1.0,
+                    fill_value_gross: 120.0,
+                    fees,
+                    ..test_util::fill_event()
+                },
+                expected_eth: Some(Balance::new(-1.0, -1.0)),
+                expected_usdt: Balance::new(1119.0, 1119.0),
+            },
+            // TC3: derivative Long entry only charges fees to the quote asset
+            TestCase {
+                input: FillEvent {
+                    instrument: perpetual(),
+                    decision: Decision::Long,
+                    fees,
+                    ..test_util::fill_event()
+                },
+                expected_eth: None,
+                expected_usdt: Balance::new(999.0, 999.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut ledger = ledger();
+            ledger.apply_fill(&test.input);
+
+            assert_eq!(
+                ledger.balance(ExchangeId::BinanceSpot, &AssetNameInternal::from("eth")),
+                test.expected_eth,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                ledger.balance(ExchangeId::BinanceSpot, &AssetNameInternal::from("usdt")),
+                Some(test.expected_usdt),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_exit() {
+        struct TestCase {
+            input: Position,
+            expected_usdt: Balance,
+        }
+
+        let cases = vec![
+            // TC0: spot Position is settled by it's FillEvents
+            TestCase {
+                input: Position {
+                    realised_profit_loss: 18.0,
+                    enter_fees_total: 1.0,
+                    exit_fees_total: 1.0,
+                    ..test_util::position()
+                },
+                expected_usdt: Balance::new(1000.0, 1000.0),
+            },
+            // TC1: derivative Position settles gross profit in the quote asset
+            TestCase {
+                input: Position {
+                    instrument: perpetual(),
+                    realised_profit_loss: 18.0,
+                    enter_fees_total: 1.0,
+                    exit_fees_total: 1.0,
+                    ..test_util::position()
+                },
+                expected_usdt: Balance::new(1020.0, 1020.0),
+            },
+            // TC2: derivative Position settles gross loss in the quote asset
+            TestCase {
+                input: Position {
+                    instrument: perpetual(),
+                    realised_profit_loss: -22.0,
+                    enter_fees_total: 1.0,
+                    exit_fees_total: 1.0,
+                    ..test_util::position()
+                },
+                expected_usdt: Balance::new(980.0, 980.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut ledger = ledger();
+            ledger.apply_exit(&test.input);
+
+            assert_eq!(
+                ledger.balance(ExchangeId::BinanceSpot, &AssetNameInternal::from("usdt")),
+                Some(test.expected_usdt),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_price() {
+        let mut ledger = ledger();
+
+        // Non-spot MarketEvents are not used as prices
+        let mut market = test_util::market_event_candle();
+        market.instrument = perpetual();
+        assert!(!ledger.update_price(&market));
+
+        let market = test_util::market_event_candle();
+        assert!(ledger.update_price(&market));
+
+        let btc = AssetNameInternal::from("btc");
+        let usdt = AssetNameInternal::from("usdt");
+        let eth = AssetNameInternal::from("eth");
+
+        assert_eq!(ledger.price(&btc, &usdt), Some(1000.0));
+        assert_eq!(ledger.price(&usdt, &btc), Some(0.001));
+        assert_eq!(ledger.price(&usdt, &usdt), Some(1.0));
+        assert_eq!(ledger.price(&eth, &usdt), None);
+    }
+
+    #[test]
+    fn test_valuation() {
+        let mut ledger = ledger();
+        ledger.set_balances(
+            ExchangeId::BinanceSpot,
+            [
+                AssetBalance::new("btc", Balance::new(2.0, 1.0)),
+                AssetBalance::new("eth", Balance::new(5.0, 5.0)),
+            ],
+        );
+        ledger.set_balances(
+            ExchangeId::Kraken,
+            [AssetBalance::new("btc", Balance::new(1.0, 1.0))],
+        );
+        ledger.update_price(&test_util::market_event_candle());
+
+        // Spot Long entry moves quote asset into the priced base asset, leaving valuation unchanged
+        ledger.apply_fill(&FillEvent {
+            instrument: MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot)),
+            decision: Decision::Long,
+            quantity: 0.5,
+            fill_value_gross: 500.0,
+            ..test_util::fill_event()
+        });
+
+        let time = Utc::now();
+        assert_eq!(
+            ledger.valuation(time),
+            Valuation {
+                time,
+                reporting: AssetNameInternal::from("usdt"),
+                total: 500.0 + 2500.0 + 1000.0,
+                available: 500.0 + 1500.0 + 1000.0,
+                unpriced: vec![ExchangeAsset::new(ExchangeId::BinanceSpot, "eth")],
+            }
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
+    execution::{ClientOrderId, FillEvent, OrderUpdate},
     portfolio::{error::PortfolioError, position::PositionUpdate},
     strategy::{Decision, Signal, SignalForceExit},
 };
@@ -17,6 +17,9 @@ pub mod allocator;
 /// Barter portfolio module specific errors.
 pub mod error;
 
+/// Per-exchange, per-asset balance ledger, valued in a reporting asset using the latest prices.
+pub mod ledger;
+
 /// Core Portfolio logic containing an implementation of [`MarketUpdater`],
 /// [`OrderGenerator`] and [`FillUpdater`]. Utilises the risk and allocator logic to optimise
 /// [`OrderEvent`] generation.
@@ -26,6 +29,9 @@ pub mod portfolio;
 /// well as the logic for entering, updating and exiting them.
 pub mod position;
 
+/// Startup reconciliation of persisted Portfolio state against the state held at the venue.
+pub mod reconcile;
+
 /// Repositories for persisting Portfolio state.
 pub mod repository;
 
@@ -54,6 +60,16 @@ pub trait OrderGenerator {
         &mut self,
         signal: SignalForceExit,
     ) -> Result<Option<OrderEvent>, PortfolioError>;
+
+    /// Update the risk parameters used to evaluate generated [`OrderEvent`]s at runtime
+    /// (eg/ via a remote [`Command::UpdateRisk`](crate::engine::Command::UpdateRisk)). By default,
+    /// runtime updates are unsupported.
+    fn update_risk_parameters(
+        &mut self,
+        _parameters: serde_json::Value,
+    ) -> Result<(), PortfolioError> {
+        Err(PortfolioError::RiskUpdateUnsupported)
+    }
 }
 
 /// Updates the Portfolio from an input [`FillEvent`].
@@ -64,11 +80,25 @@ pub trait FillUpdater {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError>;
 }
 
+/// Updates the Portfolio's in-flight [`OrderEvent`]s from an input [`OrderUpdate`].
+pub trait OrderUpdater {
+    /// Updates the Portfolio state using the input [`OrderUpdate`]. A cancelled or rejected
+    /// in-flight [`OrderEvent`] releases any cash reserved for it, and applies any partial fills
+    /// that were received before it terminated.
+    fn update_from_order_update(
+        &mut self,
+        update: &OrderUpdate,
+    ) -> Result<Vec<Event>, PortfolioError>;
+}
+
 /// Orders are generated by the portfolio and details work to be done by an Execution handler to
 /// open a trade.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct OrderEvent {
     pub time: DateTime<Utc>,
+    /// Unique identifier of the [`OrderEvent`], carried by every associated [`OrderUpdate`] &
+    /// [`FillEvent`].
+    pub cid: ClientOrderId,
     pub exchange: ExchangeId,
     pub instrument: MarketDataInstrument,
     /// Metadata propagated from source MarketEvent
@@ -109,6 +139,7 @@ impl Default for OrderType {
 #[derive(Debug, Default)]
 pub struct OrderEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
     pub exchange: Option<ExchangeId>,
     pub instrument: Option<MarketDataInstrument>,
     pub market_meta: Option<MarketMeta>,
@@ -129,6 +160,13 @@ impl OrderEventBuilder {
         }
     }
 
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: ExchangeId) -> Self {
         Self {
             exchange: Some(value),
@@ -171,9 +209,11 @@ impl OrderEventBuilder {
         }
     }
 
+    /// Builds the [`OrderEvent`], generating a random [`ClientOrderId`] if none was provided.
     pub fn build(self) -> Result<OrderEvent, PortfolioError> {
         Ok(OrderEvent {
             time: self.time.ok_or(PortfolioError::BuilderIncomplete("time"))?,
+            cid: self.cid.unwrap_or_else(ClientOrderId::random),
             exchange: self
                 .exchange
                 .ok_or(PortfolioError::BuilderIncomplete("exchange"))?,
@@ -196,6 +236,71 @@ impl OrderEventBuilder {
     }
 }
 
+/// [`OrderEvent`] that has been sent for execution, but has not yet been completely filled,
+/// cancelled or rejected.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct InFlightOrder {
+    pub order: OrderEvent,
+    /// Cash reserved from the available [`Balance`] that has not yet been released by a fill.
+    pub reserved_cash: f64,
+    /// +ve or -ve quantity filled so far.
+    pub filled_quantity: f64,
+    /// Aggregated exit [`FillEvent`]s received so far. A [`Position`](position::Position) is only
+    /// exited once it's exit [`OrderEvent`] is complete.
+    pub exit_fill: Option<FillEvent>,
+}
+
+impl InFlightOrder {
+    /// Constructs a new [`InFlightOrder`], reserving the estimated cost of the [`OrderEvent`] if
+    /// it's entering a new [`Position`](position::Position).
+    pub fn new(order: OrderEvent) -> Self {
+        let reserved_cash = match order.decision.is_entry() {
+            true => order.quantity.abs() * order.market_meta.close,
+            false => 0.0,
+        };
+
+        Self {
+            order,
+            reserved_cash,
+            filled_quantity: 0.0,
+            exit_fill: None,
+        }
+    }
+
+    /// Returns the +ve or -ve quantity that is yet to be filled.
+    pub fn remaining_quantity(&self) -> f64 {
+        self.order.quantity - self.filled_quantity
+    }
+
+    /// Determines if the [`OrderEvent`] has been completely filled.
+    pub fn is_filled(&self) -> bool {
+        self.remaining_quantity().abs() <= f64::EPSILON * self.order.quantity.abs().max(1.0)
+    }
+
+    /// Applies the input [`FillEvent`] to the [`InFlightOrder`], returning the reserved cash
+    /// that should be released back into the available [`Balance`].
+    pub fn apply_fill(&mut self, fill: &FillEvent) -> f64 {
+        let remaining_quantity = self.remaining_quantity();
+        self.filled_quantity += fill.quantity;
+
+        // Release reserved cash proportionally to the remaining quantity filled
+        let released_cash = match self.is_filled() || remaining_quantity == 0.0 {
+            true => self.reserved_cash,
+            false => self.reserved_cash * (fill.quantity / remaining_quantity).abs().min(1.0),
+        };
+        self.reserved_cash -= released_cash;
+
+        if fill.decision.is_exit() {
+            match &mut self.exit_fill {
+                Some(exit_fill) => exit_fill.aggregate(fill),
+                None => self.exit_fill = Some(fill.clone()),
+            }
+        }
+
+        released_cash
+    }
+}
+
 /// Communicates a String represents a unique identifier for an Engine's Portfolio [`Balance`].
 pub type BalanceId = String;
 
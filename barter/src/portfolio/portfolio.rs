@@ -1,19 +1,33 @@
 use super::{
     allocator::OrderAllocator,
     error::PortfolioError,
+    ledger::{BalanceLedger, ExchangeAssetBalance, Valuation},
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
+        determine_position_id, Position, PositionEnterer, PositionExit, PositionExiter, PositionId,
         PositionUpdate, PositionUpdater,
     },
-    repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
+    repository::{
+        error::RepositoryError, BalanceHandler, OrderHandler, PositionHandler, StatisticHandler,
+        TransactionHandler,
+    },
     risk::OrderEvaluator,
-    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    Balance, FillUpdater, InFlightOrder, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    OrderUpdater,
 };
 use crate::{
+    clock::{self, SharedClock},
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
-    statistic::summary::{Initialiser, PositionSummariser},
+    execution::{ClientOrderId, FillEvent, OrderUpdate},
+    statistic::{
+        metric::EquityPoint,
+        summary::{
+            benchmark::{Benchmark, BenchmarkSummary},
+            equity::{Config as EquityConfig, EquitySummary},
+            rolling::RollingMonitor,
+            Initialiser, PositionSummariser,
+        },
+    },
     strategy::{Decision, Signal, SignalForceExit, SignalStrength},
 };
 use barter_data::event::{DataKind, MarketEvent};
@@ -22,10 +36,10 @@ use barter_instrument::{
     market::{Market, MarketId},
 };
 use barter_integration::Side;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::{collections::HashMap, marker::PhantomData};
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing & initialising a [`MetaPortfolio`] via the init() constructor
@@ -33,7 +47,7 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub struct PortfolioLego<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -44,7 +58,7 @@ where
     /// [`Market`]s being tracked by a [`MetaPortfolio`].
     pub markets: Vec<Market>,
     /// Repository for a [`MetaPortfolio`] to persist it's state in. Implements
-    /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
+    /// [`PositionHandler`], [`BalanceHandler`], [`OrderHandler`] and [`StatisticHandler`]
     pub repository: Repository,
     /// Allocation manager implements [`OrderAllocator`].
     pub allocator: Allocator,
@@ -55,15 +69,35 @@ where
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
+    /// Configuration of the mark-to-market [`EquitySummary`] sampled by a [`MetaPortfolio`].
+    pub equity_config: EquityConfig,
+    /// Optional [`Market`] held as a buy-and-hold [`Benchmark`] the Portfolio equity is
+    /// compared against.
+    pub benchmark: Option<Market>,
+    /// Optional [`RollingMonitor`] publishing rolling-window statistics of exited [`Position`]s.
+    pub rolling: Option<RollingMonitor>,
+    /// Optional per-exchange, per-asset [`BalanceLedger`] updated from every [`FillEvent`], with
+    /// it's starting balances persisted in the Repository on init.
+    pub ledger: Option<BalanceLedger>,
+    /// [`SharedClock`] used to timestamp the initial [`Balance`], equity & Statistics. Later
+    /// state is timestamped with the time of the [`Event`] it is derived from, since the Portfolio
+    /// is shared between [`Trader`](crate::engine::trader::Trader)s that each have their own clock.
+    pub clock: SharedClock,
     pub _statistic_marker: PhantomData<Statistic>,
 }
 
 /// Portfolio with state persisted in a repository. [`MarketUpdater`], [`OrderGenerator`],
-/// [`FillUpdater`] and [`PositionHandler`].
+/// [`FillUpdater`], [`OrderUpdater`] and [`PositionHandler`].
+///
+/// Every generated [`OrderEvent`] is tracked as an [`InFlightOrder`] until it is completely
+/// filled, cancelled or rejected. Whilst in-flight, the estimated cost of an entry order is
+/// reserved from the available [`Balance`], and no further [`OrderEvent`]s are generated for the
+/// same market. [`InFlightOrder`]s are persisted in the repository, and are matched to
+/// [`OrderUpdate`]s & [`FillEvent`]s by their [`ClientOrderId`].
 #[derive(Debug)]
 pub struct MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -71,19 +105,42 @@ where
     /// Identifier for the [`Engine`](crate::engine::Engine) this Portfolio is associated with (1-to-1 relationship).
     engine_id: Uuid,
     /// Repository for the [`MetaPortfolio`] to persist it's state in. Implements
-    /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
+    /// [`PositionHandler`], [`BalanceHandler`], [`OrderHandler`] and [`StatisticHandler`]
     repository: Repository,
     /// Allocation manager implements [`OrderAllocator`].
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// [`InFlightOrder`]s awaiting execution, keyed by their [`ClientOrderId`].
+    in_flight_orders: HashMap<ClientOrderId, InFlightOrder>,
+    /// Latest unrealised profit & loss of every open [`Position`], used to mark equity to market.
+    unrealised_profit_loss: HashMap<PositionId, f64>,
+    /// Latest [`Balance`] persisted in the repository, kept in memory so equity can be marked to
+    /// market on every [`MarketEvent`] without reading the repository.
+    balance: Balance,
+    /// Mark-to-market equity curve sampled on a fixed schedule.
+    equity: EquitySummary,
+    /// Mark-to-market [`BalanceLedger`] [`Valuation`] curve in the reporting asset, sampled on
+    /// the same schedule as the equity curve, if a [`BalanceLedger`] is configured.
+    valuation_equity: Option<EquitySummary>,
+    /// Optional buy-and-hold [`Benchmark`] marked to market alongside the Portfolio equity.
+    benchmark: Option<Benchmark>,
+    /// Optional [`RollingMonitor`] publishing rolling-window statistics of exited [`Position`]s.
+    rolling: Option<RollingMonitor>,
+    /// Exited [`Position`]s awaiting publication to the [`RollingMonitor`] until every
+    /// Repository write of the event that exited them has succeeded.
+    unpublished_exits: Vec<Position>,
+    /// Optional per-exchange, per-asset [`BalanceLedger`] updated from every [`FillEvent`].
+    ledger: Option<BalanceLedger>,
+    /// [`SharedClock`] used to timestamp the initial [`Balance`], equity & Statistics.
+    clock: SharedClock,
     _statistic_marker: PhantomData<Statistic>,
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> MarketUpdater
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -92,6 +149,11 @@ where
         &mut self,
         market: &MarketEvent<MarketDataInstrument, DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        self.update_benchmark(market);
+        if let Some(ledger) = self.ledger.as_mut() {
+            ledger.update_price(market);
+        }
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
@@ -100,12 +162,17 @@ where
         if let Some(mut position) = self.repository.get_open_position(&position_id)? {
             // Derive PositionUpdate event that communicates the open Position's change in state
             if let Some(position_update) = position.update(market) {
+                self.unrealised_profit_loss
+                    .insert(position_id, position.unrealised_profit_loss);
+
                 // Save updated open Position in the repository
                 self.repository.set_open_position(position)?;
+                self.update_equity(market.time_exchange);
                 return Ok(Some(position_update));
             }
         }
 
+        self.update_equity(market.time_exchange);
         Ok(None)
     }
 }
@@ -113,7 +180,7 @@ where
 impl<Repository, Allocator, RiskManager, Statistic> OrderGenerator
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -122,10 +189,16 @@ where
         // Determine the position_id & associated Option<Position> related to input SignalEvent
         let position_id =
             determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
+
+        // Avoid duplicate OrderEvents whilst an OrderEvent for this market is in-flight
+        if self.has_in_flight_order(&position_id) {
+            return Ok(None);
+        }
+
         let position = self.repository.get_open_position(&position_id)?;
 
         // If signal is advising to open a new Position rather than close one, check we have cash
-        if position.is_none() && self.no_cash_to_enter_new_position()? {
+        if position.is_none() && self.no_cash_to_enter_new_position() {
             return Ok(None);
         }
 
@@ -139,7 +212,8 @@ where
 
         // Construct mutable OrderEvent that can be modified by Allocation & Risk management
         let mut order = OrderEvent {
-            time: Utc::now(),
+            time: signal.time,
+            cid: ClientOrderId::random(),
             exchange: signal.exchange,
             instrument: signal.instrument.clone(),
             market_meta: signal.market_meta,
@@ -153,7 +227,10 @@ where
             .allocate_order(&mut order, position, *signal_strength);
 
         // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+        match self.risk_manager.evaluate_order(order) {
+            Some(order) => self.track_in_flight_order(order).map(Some),
+            None => Ok(None),
+        }
     }
 
     fn generate_exit_order(
@@ -164,6 +241,16 @@ where
         let position_id =
             determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
 
+        // Avoid duplicate exit OrderEvents whilst an OrderEvent for this market is in-flight
+        if self.has_in_flight_order(&position_id) {
+            info!(
+                position_id = &*position_id,
+                outcome = "no forced exit OrderEvent generated",
+                "cannot generate forced exit OrderEvent whilst an OrderEvent is in-flight"
+            );
+            return Ok(None);
+        }
+
         // Retrieve Option<Position> associated with the PositionId
         let position = match self.repository.get_open_position(&position_id)? {
             None => {
@@ -177,8 +264,9 @@ where
             Some(position) => position,
         };
 
-        Ok(Some(OrderEvent {
-            time: Utc::now(),
+        let order = OrderEvent {
+            time: signal.time,
+            cid: ClientOrderId::random(),
             exchange: signal.exchange,
             instrument: signal.instrument,
             market_meta: MarketMeta {
@@ -188,54 +276,179 @@ where
             decision: position.determine_exit_decision(),
             quantity: 0.0 - position.quantity,
             order_type: OrderType::Market,
-        }))
+        };
+
+        self.track_in_flight_order(order).map(Some)
+    }
+
+    fn update_risk_parameters(
+        &mut self,
+        parameters: serde_json::Value,
+    ) -> Result<(), PortfolioError> {
+        self.risk_manager.update_parameters(parameters)
     }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler
+        + BalanceHandler
+        + OrderHandler
+        + StatisticHandler<Statistic>
+        + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser + Serialize,
 {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError> {
+        let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
+        let result = self.transact(fill.cid, position_id, |portfolio| {
+            portfolio.apply_fill(fill)
+        });
+
+        if result.is_ok() {
+            self.update_equity(fill.time);
+        }
+
+        result
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic>
+    MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler
+        + BalanceHandler
+        + OrderHandler
+        + StatisticHandler<Statistic>
+        + TransactionHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser + Serialize,
+{
+    /// Applies an event to the Portfolio & it's Repository, persisting every Repository write
+    /// atomically. If any write or the commit fails, the in-memory state of the event's
+    /// [`ClientOrderId`] & market is restored so the event can be retried.
+    ///
+    /// In-memory side effects (eg/ rolling statistics) are only applied once the event is
+    /// committed, so a retried event is not double counted.
+    fn transact<Apply>(
+        &mut self,
+        cid: ClientOrderId,
+        position_id: PositionId,
+        apply: Apply,
+    ) -> Result<Vec<Event>, PortfolioError>
+    where
+        Apply: FnOnce(&mut Self) -> Result<Vec<Event>, PortfolioError>,
+    {
+        // Snapshot the in-memory state of the event market, restored if the event fails
+        let in_flight = self.in_flight_orders.get(&cid).cloned();
+        let unrealised_profit_loss = self.unrealised_profit_loss.get(&position_id).copied();
+        let balance = self.balance;
+        let ledger = self.ledger.clone();
+
+        self.repository.begin()?;
+        let result = apply(self)
+            .and_then(|events| self.repository.commit().map(|_| events).map_err(Into::into));
+
+        if result.is_err() {
+            if let Err(error) = self.repository.rollback() {
+                warn!(%error, position_id = &*position_id, %cid, "failed to rollback event");
+            }
+
+            match in_flight {
+                Some(in_flight) => self.in_flight_orders.insert(cid, in_flight),
+                None => self.in_flight_orders.remove(&cid),
+            };
+            match unrealised_profit_loss {
+                Some(unrealised) => self.unrealised_profit_loss.insert(position_id, unrealised),
+                None => self.unrealised_profit_loss.remove(&position_id),
+            };
+            self.balance = balance;
+            self.ledger = ledger;
+            self.unpublished_exits.clear();
+        } else {
+            self.publish_exits();
+        }
+
+        result
+    }
+
+    /// Applies the [`FillEvent`] to the Portfolio & it's Repository, returning the generated
+    /// [`Event`]s. Called within a Repository transaction by [`FillUpdater::update_from_fill`].
+    fn apply_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError> {
         // Allocate Vector<Event> to contain any update_from_fill generated events
         let mut generated_events: Vec<Event> = Vec::with_capacity(2);
 
-        // Get the Portfolio Balance from Repository & update timestamp
-        let mut balance = self.repository.get_balance(self.engine_id)?;
+        // Get the latest Portfolio Balance & update timestamp
+        let mut balance = self.balance;
         balance.time = fill.time;
 
+        // Apply every FillEvent to the asset Balances, including partial fills
+        if let Some(ledger) = self.ledger.as_mut() {
+            ledger.apply_fill(fill);
+        }
+
         // Determine the position_id that is related to the input FillEvent
         let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
 
+        // Apply FillEvent to the InFlightOrder with the same ClientOrderId, releasing it's
+        // reserved cash
+        // '--> exit FillEvents are aggregated until the exit InFlightOrder is complete
+        let exit_fill = match self.in_flight_orders.get_mut(&fill.cid) {
+            Some(in_flight) => {
+                balance.available += in_flight.apply_fill(fill);
+
+                if in_flight.is_filled() {
+                    self.repository
+                        .remove_in_flight_order(self.engine_id, &fill.cid)?;
+                    self.in_flight_orders
+                        .remove(&fill.cid)
+                        .and_then(|in_flight| in_flight.exit_fill)
+                } else {
+                    let is_exit = fill.decision.is_exit();
+                    let in_flight = in_flight.clone();
+                    self.repository
+                        .set_in_flight_order(self.engine_id, in_flight)?;
+
+                    if is_exit {
+                        // Persist Balances and wait for the exit InFlightOrder to complete
+                        generated_events.push(Event::Balance(balance));
+                        self.persist_balance(balance)?;
+                        generated_events
+                            .extend(self.persist_ledger(fill.time)?.map(Event::Valuation));
+                        return Ok(generated_events);
+                    }
+                    None
+                }
+            }
+            None => None,
+        };
+
         // Determine FillEvent context based on existence or absence of an open Position
         match self.repository.remove_position(&position_id)? {
+            // INCREASE SCENARIO - entry FillEvent for Asset-Exchange with open Position
+            Some(mut position) if fill.decision.is_entry() => {
+                // Increase Position (in place mutation), & add the PositionUpdate event
+                let position_update = position.increase(fill)?;
+                generated_events.push(Event::PositionUpdate(position_update));
+
+                // Update Portfolio Balance.available on Position increase
+                balance.available += -fill.fill_value_gross - fill.fees.calculate_total_fees();
+                self.unrealised_profit_loss
+                    .insert(position_id, position.unrealised_profit_loss);
+
+                // Update current Position in Repository
+                self.repository.set_open_position(position)?;
+            }
+
             // EXIT SCENARIO - FillEvent for Asset-Exchange combination with open Position
-            Some(mut position) => {
-                // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
-                let position_exit = position.exit(balance, fill)?;
+            Some(position) => {
+                let exit_fill = exit_fill.as_ref().unwrap_or(fill);
+                let position_exit = self.exit_position(position, &mut balance, exit_fill)?;
                 generated_events.push(Event::PositionExit(position_exit));
-
-                // Update Portfolio balance on Position exit
-                // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
-
-                // Update statistics for exited Position market
-                let market_id = MarketId::new(fill.exchange, &fill.instrument);
-
-                let mut stats = self.repository.get_statistics(&market_id)?;
-                stats.update(&position);
-
-                // Persist exited Position & Updated Market statistics in Repository
-                self.repository.set_statistics(market_id, stats)?;
-                self.repository
-                    .set_exited_position(self.engine_id, position)?;
+                self.unrealised_profit_loss.remove(&position_id);
             }
 
             // ENTRY SCENARIO - FillEvent for Asset-Exchange with no Position
@@ -246,6 +459,8 @@ where
 
                 // Update Portfolio Balance.available on Position entry
                 balance.available += -position.enter_value_gross - position.enter_fees_total;
+                self.unrealised_profit_loss
+                    .insert(position_id, position.unrealised_profit_loss);
 
                 // Add to current Positions in Repository
                 self.repository.set_open_position(position)?;
@@ -255,8 +470,102 @@ where
         // Add new Balance event to the Vec<Event>
         generated_events.push(Event::Balance(balance));
 
-        // Persist updated Portfolio Balance in Repository
-        self.repository.set_balance(self.engine_id, balance)?;
+        // Persist updated Portfolio Balance & any BalanceLedger asset balances in Repository
+        self.persist_balance(balance)?;
+        generated_events.extend(self.persist_ledger(fill.time)?.map(Event::Valuation));
+
+        Ok(generated_events)
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic> OrderUpdater
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler
+        + BalanceHandler
+        + OrderHandler
+        + StatisticHandler<Statistic>
+        + TransactionHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser + Serialize,
+{
+    fn update_from_order_update(
+        &mut self,
+        update: &OrderUpdate,
+    ) -> Result<Vec<Event>, PortfolioError> {
+        // Resting orders remain in-flight, awaiting fills
+        if !update.status.is_terminal() || !self.in_flight_orders.contains_key(&update.cid) {
+            return Ok(vec![]);
+        }
+
+        let position_id =
+            determine_position_id(self.engine_id, &update.exchange, &update.instrument);
+        let result = self.transact(update.cid, position_id, |portfolio| {
+            portfolio.apply_order_update(update)
+        });
+
+        if result.is_ok() {
+            self.update_equity(update.time);
+        }
+
+        result
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic>
+    MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser + Serialize,
+{
+    /// Applies the terminal [`OrderUpdate`] of an [`InFlightOrder`] to the Portfolio & it's
+    /// Repository, returning the generated [`Event`]s. Called within a Repository transaction by
+    /// [`OrderUpdater::update_from_order_update`].
+    fn apply_order_update(&mut self, update: &OrderUpdate) -> Result<Vec<Event>, PortfolioError> {
+        // Determine the InFlightOrder with the same ClientOrderId as the input OrderUpdate
+        let Some(in_flight) = self.in_flight_orders.remove(&update.cid) else {
+            return Ok(vec![]);
+        };
+        self.repository
+            .remove_in_flight_order(self.engine_id, &update.cid)?;
+        let position_id =
+            determine_position_id(self.engine_id, &update.exchange, &update.instrument);
+
+        info!(
+            position_id = &*position_id,
+            cid = %update.cid,
+            status = ?update.status,
+            filled_quantity = in_flight.filled_quantity,
+            "in-flight OrderEvent terminated before being completely filled"
+        );
+
+        // Allocate Vector<Event> to contain any update_from_order_update generated events
+        let mut generated_events: Vec<Event> = Vec::with_capacity(2);
+
+        // Get the latest Portfolio Balance & release cash reserved for the OrderEvent
+        let mut balance = self.balance;
+        balance.time = update.time;
+        balance.available += in_flight.reserved_cash;
+
+        // Exit the part of the open Position that was filled before the OrderEvent terminated
+        if let Some(exit_fill) = in_flight.exit_fill {
+            if let Some(mut position) = self.repository.remove_position(&position_id)? {
+                let exited = position.split(exit_fill.quantity.abs().copysign(position.quantity));
+                let position_exit = self.exit_position(exited, &mut balance, &exit_fill)?;
+                generated_events.push(Event::PositionExit(position_exit));
+                self.repository.set_open_position(position)?;
+            }
+        }
+
+        // Add new Balance event to the Vec<Event>
+        generated_events.push(Event::Balance(balance));
+
+        // Persist updated Portfolio Balance & any BalanceLedger asset balances in Repository
+        self.persist_balance(balance)?;
+        generated_events.extend(self.persist_ledger(update.time)?.map(Event::Valuation));
 
         Ok(generated_events)
     }
@@ -265,7 +574,7 @@ where
 impl<Repository, Allocator, RiskManager, Statistic> PositionHandler
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -283,10 +592,11 @@ where
 
     fn get_open_positions<'a, Markets: Iterator<Item = &'a Market>>(
         &mut self,
-        _: Uuid,
+        engine_id: Uuid,
         markets: Markets,
     ) -> Result<Vec<Position>, RepositoryError> {
-        self.repository.get_open_positions(self.engine_id, markets)
+        self.validate_engine_id(engine_id)?;
+        self.repository.get_open_positions(engine_id, markets)
     }
 
     fn remove_position(
@@ -296,20 +606,25 @@ where
         self.repository.remove_position(position_id)
     }
 
-    fn set_exited_position(&mut self, _: Uuid, position: Position) -> Result<(), RepositoryError> {
-        self.repository
-            .set_exited_position(self.engine_id, position)
+    fn set_exited_position(
+        &mut self,
+        engine_id: Uuid,
+        position: Position,
+    ) -> Result<(), RepositoryError> {
+        self.validate_engine_id(engine_id)?;
+        self.repository.set_exited_position(engine_id, position)
     }
 
-    fn get_exited_positions(&mut self, _: Uuid) -> Result<Vec<Position>, RepositoryError> {
-        self.repository.get_exited_positions(self.engine_id)
+    fn get_exited_positions(&mut self, engine_id: Uuid) -> Result<Vec<Position>, RepositoryError> {
+        self.validate_engine_id(engine_id)?;
+        self.repository.get_exited_positions(engine_id)
     }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> StatisticHandler<Statistic>
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -327,10 +642,51 @@ where
     }
 }
 
+impl<Repository, Allocator, RiskManager, Statistic> BalanceHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        self.validate_engine_id(engine_id)?;
+        self.persist_balance(balance)
+    }
+
+    fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
+        self.validate_engine_id(engine_id)?;
+        self.repository.get_balance(engine_id)
+    }
+
+    fn set_asset_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: ExchangeAssetBalance,
+    ) -> Result<(), RepositoryError> {
+        self.validate_engine_id(engine_id)?;
+        self.repository
+            .set_asset_balance(engine_id, balance.clone())?;
+        if let Some(ledger) = self.ledger.as_mut() {
+            ledger.set_balance(balance);
+        }
+        Ok(())
+    }
+
+    fn get_asset_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<ExchangeAssetBalance>, RepositoryError> {
+        self.validate_engine_id(engine_id)?;
+        self.repository.get_asset_balances(engine_id)
+    }
+}
+
 impl<Repository, Allocator, RiskManager, Statistic>
     MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -340,23 +696,55 @@ where
     pub fn init(
         lego: PortfolioLego<Repository, Allocator, RiskManager, Statistic>,
     ) -> Result<Self, PortfolioError> {
+        let start = EquityPoint {
+            time: lego.clock.time(),
+            total: lego.starting_cash,
+        };
+
         // Construct MetaPortfolio instance
         let mut portfolio = Self {
             engine_id: lego.engine_id,
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            in_flight_orders: HashMap::new(),
+            unrealised_profit_loss: HashMap::new(),
+            balance: Balance::new(start.time, lego.starting_cash, lego.starting_cash),
+            equity: EquitySummary::new(lego.equity_config, start)?,
+            valuation_equity: lego
+                .ledger
+                .as_ref()
+                .map(|ledger| {
+                    EquitySummary::new(
+                        lego.equity_config,
+                        EquityPoint {
+                            time: start.time,
+                            total: ledger.valuation(start.time).total,
+                        },
+                    )
+                })
+                .transpose()?,
+            benchmark: lego
+                .benchmark
+                .map(|market| Benchmark::new(market, lego.equity_config, start))
+                .transpose()?,
+            rolling: lego.rolling,
+            unpublished_exits: Vec::new(),
+            ledger: lego.ledger,
+            clock: lego.clock,
             _statistic_marker: PhantomData,
         };
 
-        // Persist initial state in the repository
+        // Persist initial state in the repository & restore any persisted InFlightOrders
         portfolio.bootstrap_repository(lego.starting_cash, &lego.markets, lego.statistic_config)?;
+        portfolio.restore_in_flight_orders()?;
 
         Ok(portfolio)
     }
 
     /// Persist initial [`MetaPortfolio`] state in the repository. This includes initialised
-    /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`.
+    /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`, and
+    /// the starting asset balances of any [`BalanceLedger`].
     pub fn bootstrap_repository<Markets, Id>(
         &mut self,
         starting_cash: f64,
@@ -368,19 +756,35 @@ where
         Id: Into<MarketId>,
     {
         // Persist initial Balance (total & available)
-        self.repository.set_balance(
-            self.engine_id,
-            Balance {
-                time: Utc::now(),
-                total: starting_cash,
-                available: starting_cash,
-            },
-        )?;
+        self.persist_balance(Balance {
+            time: self.clock.time(),
+            total: starting_cash,
+            available: starting_cash,
+        })?;
+        self.persist_ledger(self.clock.time())?;
 
         // Persist initial MetaPortfolio Statistics for every Market
+        self.init_statistics(markets, statistic_config)
+    }
+
+    /// Persist initialised Statistics for every market provided. Used to track a new market at
+    /// runtime, for example before adding a new [`Trader`](crate::engine::trader::Trader) via
+    /// [`Command::AddTrader`](crate::engine::Command::AddTrader).
+    pub fn init_statistics<Markets, Id>(
+        &mut self,
+        markets: Markets,
+        statistic_config: Statistic::Config,
+    ) -> Result<(), PortfolioError>
+    where
+        Markets: IntoIterator<Item = Id>,
+        Id: Into<MarketId>,
+    {
         markets.into_iter().try_for_each(|market| {
             self.repository
-                .set_statistics(market.into(), Statistic::init(statistic_config))
+                .set_statistics(
+                    market.into(),
+                    Statistic::init_at(statistic_config, self.clock.time()),
+                )
                 .map_err(PortfolioError::RepositoryInteraction)
         })
     }
@@ -391,18 +795,239 @@ where
     }
 
     /// Determines if the Portfolio has any cash to enter a new [`Position`].
-    fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
+    fn no_cash_to_enter_new_position(&self) -> bool {
+        self.balance.available <= 0.0
+    }
+
+    /// Persists the [`Balance`] in the repository, and keeps it as the latest in-memory
+    /// [`Balance`] once the write succeeds.
+    fn persist_balance(&mut self, balance: Balance) -> Result<(), RepositoryError> {
+        self.repository.set_balance(self.engine_id, balance)?;
+        self.balance = balance;
+        Ok(())
+    }
+
+    /// Persists every [`ExchangeAssetBalance`] of the [`BalanceLedger`] in the repository,
+    /// returning the [`Valuation`] of the ledger at the provided time, if one is configured.
+    fn persist_ledger(
+        &mut self,
+        time: DateTime<Utc>,
+    ) -> Result<Option<Valuation>, RepositoryError> {
+        let Some(ledger) = self.ledger.as_ref() else {
+            return Ok(None);
+        };
+
+        for (asset, balance) in ledger.balances() {
+            self.repository.set_asset_balance(
+                self.engine_id,
+                ExchangeAssetBalance::new(asset.clone(), *balance),
+            )?;
+        }
+
+        Ok(Some(ledger.valuation(time)))
+    }
+
+    /// Tracks the input [`OrderEvent`] as an [`InFlightOrder`] persisted in the repository,
+    /// reserving the estimated cost of an entry [`OrderEvent`] from the available [`Balance`].
+    fn track_in_flight_order(&mut self, order: OrderEvent) -> Result<OrderEvent, PortfolioError> {
+        let in_flight = InFlightOrder::new(order.clone());
+        self.reserve_in_flight_cash(&in_flight)?;
+
         self.repository
-            .get_balance(self.engine_id)
-            .map(|balance| balance.available == 0.0)
-            .map_err(PortfolioError::RepositoryInteraction)
+            .set_in_flight_order(self.engine_id, in_flight.clone())?;
+        self.in_flight_orders.insert(order.cid, in_flight);
+        Ok(order)
+    }
+
+    /// Reserves the cash of the [`InFlightOrder`] from the available [`Balance`].
+    fn reserve_in_flight_cash(&mut self, in_flight: &InFlightOrder) -> Result<(), PortfolioError> {
+        if in_flight.reserved_cash != 0.0 {
+            let mut balance = self.balance;
+            balance.available -= in_flight.reserved_cash;
+            self.persist_balance(balance)?;
+        }
+        Ok(())
+    }
+
+    /// Restores the [`InFlightOrder`]s persisted in the repository (eg/ before a restart),
+    /// reserving their outstanding cash from the available [`Balance`] so later [`OrderUpdate`]s
+    /// & [`FillEvent`]s are applied to them.
+    fn restore_in_flight_orders(&mut self) -> Result<(), PortfolioError> {
+        for in_flight in self.repository.get_in_flight_orders(self.engine_id)? {
+            self.reserve_in_flight_cash(&in_flight)?;
+            self.in_flight_orders.insert(in_flight.order.cid, in_flight);
+        }
+        Ok(())
+    }
+
+    /// Determines if the Portfolio has an [`InFlightOrder`] for the market of the [`PositionId`].
+    fn has_in_flight_order(&self, position_id: &PositionId) -> bool {
+        self.in_flight_orders.values().any(|in_flight| {
+            determine_position_id(
+                self.engine_id,
+                &in_flight.order.exchange,
+                &in_flight.order.instrument,
+            ) == *position_id
+        })
+    }
+
+    /// Returns the [`InFlightOrder`]s awaiting execution.
+    pub fn in_flight_orders(&self) -> impl Iterator<Item = &InFlightOrder> {
+        self.in_flight_orders.values()
+    }
+
+    /// Returns the identifier of the [`Engine`](crate::engine::Engine) this Portfolio is
+    /// associated with.
+    pub fn engine_id(&self) -> Uuid {
+        self.engine_id
+    }
+
+    /// Validates the engine_id provided to a [`PositionHandler`] or [`BalanceHandler`] method is
+    /// the engine_id this Portfolio is associated with.
+    fn validate_engine_id(&self, engine_id: Uuid) -> Result<(), RepositoryError> {
+        match engine_id == self.engine_id {
+            true => Ok(()),
+            false => Err(RepositoryError::EngineIdMismatch {
+                expected: self.engine_id,
+                actual: engine_id,
+            }),
+        }
+    }
+
+    /// Returns the mark-to-market [`EquitySummary`] sampled on a fixed schedule.
+    pub fn equity(&self) -> &EquitySummary {
+        &self.equity
+    }
+
+    /// Returns the mark-to-market [`EquitySummary`] of the [`BalanceLedger`] [`Valuation`] in
+    /// the reporting asset, if a [`BalanceLedger`] is configured.
+    pub fn valuation_equity(&self) -> Option<&EquitySummary> {
+        self.valuation_equity.as_ref()
+    }
+
+    /// Returns the buy-and-hold [`Benchmark`], if one is configured.
+    pub fn benchmark(&self) -> Option<&Benchmark> {
+        self.benchmark.as_ref()
+    }
+
+    /// Returns the [`RollingMonitor`] of rolling-window statistics, if one is configured.
+    pub fn rolling(&self) -> Option<&RollingMonitor> {
+        self.rolling.as_ref()
+    }
+
+    /// Returns the per-exchange, per-asset [`BalanceLedger`], if one is configured.
+    pub fn ledger(&self) -> Option<&BalanceLedger> {
+        self.ledger.as_ref()
+    }
+
+    /// Updates the latest [`Benchmark`] price from the input [`MarketEvent`], if one is
+    /// configured. Used to feed the [`Benchmark`] from a [`MarketEvent`] stream that is not
+    /// otherwise routed through this Portfolio.
+    pub fn update_benchmark(&mut self, market: &MarketEvent<MarketDataInstrument, DataKind>) {
+        if let Some(benchmark) = self.benchmark.as_mut() {
+            benchmark.update_from_market(market);
+        }
+    }
+
+    /// Generates the [`BenchmarkSummary`] of the Portfolio equity relative to the [`Benchmark`]
+    /// equity, if one is configured.
+    pub fn benchmark_summary(&self) -> Option<BenchmarkSummary> {
+        self.benchmark
+            .as_ref()
+            .map(|benchmark| BenchmarkSummary::generate(&self.equity, &benchmark.equity))
+    }
+
+    /// Marks the Portfolio equity to market using the in-memory [`Balance`] total & the
+    /// unrealised profit & loss of every open [`Position`] at the time of the event being
+    /// processed, updating the [`EquitySummary`]. Any [`Benchmark`] & [`BalanceLedger`]
+    /// [`Valuation`] are marked to market at the same time.
+    fn update_equity(&mut self, time: DateTime<Utc>) {
+        self.equity.update(EquityPoint {
+            time,
+            total: self.balance.total + self.unrealised_profit_loss.values().sum::<f64>(),
+        });
+
+        if let (Some(ledger), Some(valuation_equity)) =
+            (self.ledger.as_ref(), self.valuation_equity.as_mut())
+        {
+            valuation_equity.update(EquityPoint {
+                time,
+                total: ledger.valuation(time).total,
+            });
+        }
+
+        if let Some(benchmark) = self.benchmark.as_mut() {
+            benchmark.mark(time);
+        }
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic>
+    MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser + Serialize,
+{
+    /// Exits the input open [`Position`] using the (possibly aggregated) exit [`FillEvent`],
+    /// updating the Portfolio [`Balance`] & market statistics, and persisting the exited
+    /// [`Position`].
+    fn exit_position(
+        &mut self,
+        mut position: Position,
+        balance: &mut Balance,
+        fill: &FillEvent,
+    ) -> Result<PositionExit, PortfolioError> {
+        // Exit Position (in place mutation)
+        let position_exit = position.exit(*balance, fill)?;
+        if let Some(ledger) = self.ledger.as_mut() {
+            ledger.apply_exit(&position);
+        }
+
+        // Update Portfolio balance on Position exit
+        // '--> available balance adds enter_total_fees since included in result PnL calc
+        balance.available +=
+            position.enter_value_gross + position.realised_profit_loss + position.enter_fees_total;
+        balance.total += position.realised_profit_loss;
+
+        // Update statistics for exited Position market
+        // '--> keyed consistently with the MarketId used to initialise the statistics
+        let market_id = MarketId::from(&Market::<MarketDataInstrument>::new(
+            fill.exchange,
+            fill.instrument.clone(),
+        ));
+
+        let mut stats = self.repository.get_statistics(&market_id)?;
+        stats.update(&position);
+
+        // Defer rolling-window statistics, if configured, until the exited Position is persisted
+        if self.rolling.is_some() {
+            self.unpublished_exits.push(position.clone());
+        }
+
+        // Persist exited Position & Updated Market statistics in Repository
+        self.repository.set_statistics(market_id, stats)?;
+        self.repository
+            .set_exited_position(self.engine_id, position)?;
+
+        Ok(position_exit)
+    }
+
+    /// Publishes the rolling-window statistics of every exited [`Position`] that has been
+    /// persisted since the last event.
+    fn publish_exits(&mut self) {
+        let exits = std::mem::take(&mut self.unpublished_exits);
+        if let Some(rolling) = self.rolling.as_mut() {
+            exits.iter().for_each(|position| rolling.update(position));
+        }
     }
 }
 
 #[derive(Debug, Default)]
 pub struct MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -414,13 +1039,18 @@ where
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
     statistic_config: Option<Statistic::Config>,
+    equity_config: Option<EquityConfig>,
+    benchmark: Option<Market>,
+    rolling: Option<RollingMonitor>,
+    ledger: Option<BalanceLedger>,
+    clock: Option<SharedClock>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
 impl<Repository, Allocator, RiskManager, Statistic>
     MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -434,6 +1064,11 @@ where
             allocation_manager: None,
             risk_manager: None,
             statistic_config: None,
+            equity_config: None,
+            benchmark: None,
+            rolling: None,
+            ledger: None,
+            clock: None,
             _statistic_marker: None,
         }
     }
@@ -487,9 +1122,54 @@ where
         }
     }
 
+    pub fn equity_config(self, value: EquityConfig) -> Self {
+        Self {
+            equity_config: Some(value),
+            ..self
+        }
+    }
+
+    pub fn benchmark(self, value: Market) -> Self {
+        Self {
+            benchmark: Some(value),
+            ..self
+        }
+    }
+
+    pub fn rolling(self, value: RollingMonitor) -> Self {
+        Self {
+            rolling: Some(value),
+            ..self
+        }
+    }
+
+    pub fn ledger(self, value: BalanceLedger) -> Self {
+        Self {
+            ledger: Some(value),
+            ..self
+        }
+    }
+
+    pub fn clock(self, value: SharedClock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
     pub fn build_and_init(
         self,
     ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic>, PortfolioError> {
+        let starting_cash = self
+            .starting_cash
+            .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?;
+        let clock = self.clock.unwrap_or_else(clock::live);
+        let equity_config = self.equity_config.unwrap_or_default();
+        let start = EquityPoint {
+            time: clock.time(),
+            total: starting_cash,
+        };
+
         // Construct Portfolio
         let mut portfolio = MetaPortfolio {
             engine_id: self
@@ -504,13 +1184,37 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            in_flight_orders: HashMap::new(),
+            unrealised_profit_loss: HashMap::new(),
+            balance: Balance::new(start.time, starting_cash, starting_cash),
+            equity: EquitySummary::new(equity_config, start)?,
+            valuation_equity: self
+                .ledger
+                .as_ref()
+                .map(|ledger| {
+                    EquitySummary::new(
+                        equity_config,
+                        EquityPoint {
+                            time: start.time,
+                            total: ledger.valuation(start.time).total,
+                        },
+                    )
+                })
+                .transpose()?,
+            benchmark: self
+                .benchmark
+                .map(|market| Benchmark::new(market, equity_config, start))
+                .transpose()?,
+            rolling: self.rolling,
+            unpublished_exits: Vec::new(),
+            ledger: self.ledger,
+            clock,
             _statistic_marker: PhantomData,
         };
 
         // Persist initial state in the Repository
         portfolio.bootstrap_repository(
-            self.starting_cash
-                .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
+            starting_cash,
             &self
                 .markets
                 .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
@@ -518,6 +1222,9 @@ where
                 .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
         )?;
 
+        // Restore any InFlightOrders persisted in the Repository
+        portfolio.restore_in_flight_orders()?;
+
         Ok(portfolio)
     }
 }
@@ -555,20 +1262,31 @@ pub fn parse_signal_decisions<'a>(
 pub mod tests {
     use super::*;
     use crate::{
-        execution::Fees,
+        event::MetricTx,
+        execution::{Fees, OrderStatus},
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
-            repository::error::RepositoryError, risk::DefaultRisk,
+            allocator::DefaultAllocator,
+            ledger::ExchangeAsset,
+            position::PositionBuilder,
+            repository::{error::RepositoryError, in_memory::InMemoryRepository},
+            risk::DefaultRisk,
+        },
+        statistic::summary::{
+            pnl::PnLReturnSummary,
+            rolling::{Config as RollingConfig, Window},
         },
-        statistic::summary::pnl::PnLReturnSummary,
         strategy::SignalForceExit,
-        test_util::{fill_event, market_event_trade, position, signal},
+        test_util::{fill_event, market_event_trade, order_event, position, signal},
     };
+    use barter_execution::model::balance::{AssetBalance, Balance as ExchangeBalance};
     use barter_instrument::{
+        asset::name::AssetNameInternal,
         exchange::ExchangeId,
         instrument::market_data::{kind::MarketDataInstrumentKind, MarketDataInstrument},
     };
+    use chrono::Utc;
     use smol_str::SmolStr;
+    use tokio::sync::mpsc;
 
     #[derive(Default)]
     struct MockRepository<Statistic> {
@@ -590,6 +1308,10 @@ pub mod tests {
         get_statistics: Option<fn(market_id: &MarketId) -> Result<Statistic, RepositoryError>>,
         position: Option<PositionBuilder>,
         balance: Option<Balance>,
+        asset_balances: Vec<ExchangeAssetBalance>,
+        in_flight_orders: HashMap<ClientOrderId, InFlightOrder>,
+        commit: Option<fn() -> Result<(), RepositoryError>>,
+        transactions: Vec<&'static str>,
     }
 
     impl<Statistic> PositionHandler for MockRepository<Statistic> {
@@ -649,6 +1371,23 @@ pub mod tests {
         }
     }
 
+    impl<Statistic> TransactionHandler for MockRepository<Statistic> {
+        fn begin(&mut self) -> Result<(), RepositoryError> {
+            self.transactions.push("begin");
+            Ok(())
+        }
+
+        fn commit(&mut self) -> Result<(), RepositoryError> {
+            self.transactions.push("commit");
+            self.commit.map_or(Ok(()), |commit| commit())
+        }
+
+        fn rollback(&mut self) -> Result<(), RepositoryError> {
+            self.transactions.push("rollback");
+            Ok(())
+        }
+    }
+
     impl<Statistic> BalanceHandler for MockRepository<Statistic> {
         fn set_balance(
             &mut self,
@@ -660,7 +1399,51 @@ pub mod tests {
         }
 
         fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
-            self.get_balance.unwrap()(engine_id)
+            match self.get_balance {
+                Some(get_balance) => get_balance(engine_id),
+                None => Err(RepositoryError::ExpectedDataNotPresentError),
+            }
+        }
+
+        fn set_asset_balance(
+            &mut self,
+            _: Uuid,
+            balance: ExchangeAssetBalance,
+        ) -> Result<(), RepositoryError> {
+            self.asset_balances
+                .retain(|existing| existing.asset != balance.asset);
+            self.asset_balances.push(balance);
+            Ok(())
+        }
+
+        fn get_asset_balances(
+            &mut self,
+            _: Uuid,
+        ) -> Result<Vec<ExchangeAssetBalance>, RepositoryError> {
+            Ok(self.asset_balances.clone())
+        }
+    }
+
+    impl<Statistic> OrderHandler for MockRepository<Statistic> {
+        fn set_in_flight_order(
+            &mut self,
+            _: Uuid,
+            order: InFlightOrder,
+        ) -> Result<(), RepositoryError> {
+            self.in_flight_orders.insert(order.order.cid, order);
+            Ok(())
+        }
+
+        fn remove_in_flight_order(
+            &mut self,
+            _: Uuid,
+            cid: &ClientOrderId,
+        ) -> Result<Option<InFlightOrder>, RepositoryError> {
+            Ok(self.in_flight_orders.remove(cid))
+        }
+
+        fn get_in_flight_orders(&mut self, _: Uuid) -> Result<Vec<InFlightOrder>, RepositoryError> {
+            Ok(self.in_flight_orders.values().cloned().collect())
         }
     }
 
@@ -682,7 +1465,7 @@ pub mod tests {
         mock_repository: Repository,
     ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
     where
-        Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
         Statistic: PositionSummariser + Initialiser,
     {
         let builder = MetaPortfolio::builder()
@@ -701,22 +1484,44 @@ pub mod tests {
         builder: MetaPortfolioBuilder<Repository, DefaultAllocator, DefaultRisk, Statistic>,
     ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
     where
-        Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        Repository: PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<Statistic>,
         Statistic: PositionSummariser + Initialiser,
     {
+        let engine_id = builder
+            .engine_id
+            .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?;
+        let mut repository = builder
+            .repository
+            .ok_or(PortfolioError::BuilderIncomplete("repository"))?;
+
+        // Latest Balance is loaded from the mocked repository, if any
+        let balance = repository.get_balance(engine_id).unwrap_or_default();
+
         Ok(MetaPortfolio {
-            engine_id: builder
-                .engine_id
-                .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
-            repository: builder
-                .repository
-                .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
+            engine_id,
+            repository,
             allocation_manager: builder
                 .allocation_manager
                 .ok_or(PortfolioError::BuilderIncomplete("allocation_manager"))?,
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            in_flight_orders: HashMap::new(),
+            unrealised_profit_loss: HashMap::new(),
+            balance,
+            equity: EquitySummary::new(
+                builder.equity_config.unwrap_or_default(),
+                EquityPoint {
+                    time: Utc::now(),
+                    total: builder.starting_cash.unwrap_or_default(),
+                },
+            )?,
+            valuation_equity: None,
+            benchmark: None,
+            rolling: None,
+            unpublished_exits: Vec::new(),
+            ledger: None,
+            clock: builder.clock.unwrap_or_else(clock::live),
             _statistic_marker: Default::default(),
         })
     }
@@ -746,6 +1551,7 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -778,6 +1584,23 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn update_from_market_marks_equity_using_in_memory_balance() {
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(None)),
+            get_balance: Some(|_| Ok(Balance::new(Utc::now(), 1500.0, 1200.0))),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Repository Balance is not read once the Portfolio is constructed
+        portfolio.repository.get_balance = Some(|_| Err(RepositoryError::ReadError));
+
+        let input_market = market_event_trade(Side::Buy);
+        assert_eq!(portfolio.update_from_market(&input_market).unwrap(), None);
+        assert_eq!(portfolio.equity().current.total, 1500.0);
+    }
+
     #[test]
     fn update_from_market_with_long_position_decreasing_in_value() {
         // Build Portfolio
@@ -795,6 +1618,7 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -839,6 +1663,7 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -884,6 +1709,7 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -971,6 +1797,7 @@ pub mod tests {
                 available: 100.0,
             })
         });
+        mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -980,8 +1807,13 @@ pub mod tests {
             .insert(Decision::Long, SignalStrength(1.0));
 
         let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let reserved_cash = 100.0 - portfolio.repository.balance.unwrap().available;
 
-        assert_eq!(actual.decision, Decision::Long)
+        assert_eq!(actual.decision, Decision::Long);
+        assert_eq!(
+            reserved_cash,
+            actual.quantity.abs() * actual.market_meta.close
+        );
     }
 
     #[test]
@@ -996,6 +1828,7 @@ pub mod tests {
                 available: 100.0,
             })
         });
+        mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1006,8 +1839,13 @@ pub mod tests {
             .insert(Decision::Short, SignalStrength(1.0));
 
         let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let reserved_cash = 100.0 - portfolio.repository.balance.unwrap().available;
 
-        assert_eq!(actual.decision, Decision::Short)
+        assert_eq!(actual.decision, Decision::Short);
+        assert_eq!(
+            reserved_cash,
+            actual.quantity.abs() * actual.market_meta.close
+        );
     }
 
     #[test]
@@ -1184,6 +2022,82 @@ pub mod tests {
         assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
     }
 
+    #[test]
+    fn update_from_fill_persists_ledger_and_emits_valuation() {
+        // Build Portfolio with a BalanceLedger valued in usdt
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let mut ledger = BalanceLedger::new("usdt");
+        ledger.set_balances(
+            ExchangeId::BinanceSpot,
+            [AssetBalance::new(
+                "usdt",
+                ExchangeBalance::new(1000.0, 1000.0),
+            )],
+        );
+        portfolio.valuation_equity = Some(
+            EquitySummary::new(
+                EquityConfig::default(),
+                EquityPoint {
+                    time: Utc::now(),
+                    total: ledger.valuation(Utc::now()).total,
+                },
+            )
+            .unwrap(),
+        );
+        portfolio.ledger = Some(ledger);
+
+        // Input FillEvent buys 1 eth for 100 usdt & 3 usdt of fees
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+
+        let events = portfolio.update_from_fill(&input_fill).unwrap();
+
+        // eth has not been priced in usdt, so is excluded from the Valuation totals
+        let expected_valuation = Valuation {
+            time: input_fill.time,
+            reporting: AssetNameInternal::from("usdt"),
+            total: 897.0,
+            available: 897.0,
+            unpriced: vec![ExchangeAsset::new(ExchangeId::BinanceSpot, "eth")],
+        };
+        assert_eq!(
+            events.last(),
+            Some(&Event::Valuation(expected_valuation.clone()))
+        );
+        assert_eq!(
+            portfolio.repository.asset_balances,
+            vec![
+                ExchangeAssetBalance::new(
+                    ExchangeAsset::new(ExchangeId::BinanceSpot, "eth"),
+                    ExchangeBalance::new(1.0, 1.0)
+                ),
+                ExchangeAssetBalance::new(
+                    ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt"),
+                    ExchangeBalance::new(897.0, 897.0)
+                ),
+            ]
+        );
+        assert_eq!(
+            portfolio.valuation_equity().unwrap().current,
+            EquityPoint {
+                time: input_fill.time,
+                total: expected_valuation.total,
+            }
+        );
+    }
+
     #[test]
     fn update_from_fill_entering_short_position() {
         // Build Portfolio
@@ -1223,6 +2137,154 @@ pub mod tests {
         assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
     }
 
+    #[test]
+    fn update_from_fill_is_transactional() {
+        struct TestCase {
+            set_exited_position: fn(Uuid, Position) -> Result<(), RepositoryError>,
+            commit: Option<fn() -> Result<(), RepositoryError>>,
+            expected_ok: bool,
+            expected_transactions: Vec<&'static str>,
+            expected_usdt: f64,
+        }
+
+        let cases = vec![
+            // TC0: every Repository write succeeds, so the transaction is committed
+            TestCase {
+                set_exited_position: |_, _| Ok(()),
+                commit: None,
+                expected_ok: true,
+                expected_transactions: vec!["begin", "commit"],
+                expected_usdt: 1200.0,
+            },
+            // TC1: Repository write fails midway, so the transaction & BalanceLedger are rolled back
+            TestCase {
+                set_exited_position: |_, _| Err(RepositoryError::WriteError),
+                commit: None,
+                expected_ok: false,
+                expected_transactions: vec!["begin", "rollback"],
+                expected_usdt: 1000.0,
+            },
+            // TC2: Repository commit fails, so the transaction & BalanceLedger are rolled back
+            TestCase {
+                set_exited_position: |_, _| Ok(()),
+                commit: Some(|| Err(RepositoryError::WriteError)),
+                expected_ok: false,
+                expected_transactions: vec!["begin", "commit", "rollback"],
+                expected_usdt: 1000.0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mock_repository = MockRepository::<PnLReturnSummary> {
+                get_balance: Some(|_| {
+                    Ok(Balance {
+                        time: Utc::now(),
+                        total: 200.0,
+                        available: 97.0,
+                    })
+                }),
+                remove_position: Some(|_| {
+                    let mut input_position = position();
+                    input_position.quantity = 1.0;
+                    input_position.enter_value_gross = 100.0;
+                    Ok(Some(input_position))
+                }),
+                get_statistics: Some(|_| Ok(PnLReturnSummary::default())),
+                set_statistics: Some(|_, _| Ok(())),
+                set_exited_position: Some(test.set_exited_position),
+                set_balance: Some(|_, _| Ok(())),
+                commit: test.commit,
+                ..Default::default()
+            };
+            let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+            let (metric_tx, _metric_rx) = mpsc::unbounded_channel();
+            portfolio.rolling = Some(
+                RollingMonitor::new(
+                    portfolio.engine_id,
+                    &[RollingConfig {
+                        window: Window::Trades(10),
+                        risk_free_return: 0.0,
+                    }],
+                    MetricTx::new(metric_tx),
+                )
+                .unwrap(),
+            );
+
+            let mut ledger = BalanceLedger::new("usdt");
+            ledger.set_balances(
+                ExchangeId::BinanceSpot,
+                [
+                    AssetBalance::new("eth", ExchangeBalance::new(1.0, 1.0)),
+                    AssetBalance::new("usdt", ExchangeBalance::new(1000.0, 1000.0)),
+                ],
+            );
+            portfolio.ledger = Some(ledger);
+
+            let mut input_fill = fill_event();
+            input_fill.decision = Decision::CloseLong;
+            input_fill.quantity = -1.0;
+            input_fill.fill_value_gross = 200.0;
+
+            let result = portfolio.update_from_fill(&input_fill);
+
+            assert_eq!(result.is_ok(), test.expected_ok, "TC{} failed", index);
+            assert_eq!(
+                portfolio.repository.transactions, test.expected_transactions,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                portfolio
+                    .ledger()
+                    .and_then(|ledger| ledger
+                        .balance(ExchangeId::BinanceSpot, &AssetNameInternal::from("usdt")))
+                    .map(|balance| balance.total),
+                Some(test.expected_usdt),
+                "TC{} failed",
+                index
+            );
+
+            // Rolling statistics & equity are only updated once the FillEvent is committed
+            let expected_trades = usize::from(test.expected_ok);
+            assert_eq!(
+                portfolio.rolling().unwrap().summaries()[0].returns.len(),
+                expected_trades,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                portfolio.equity.current.time == input_fill.time,
+                test.expected_ok,
+                "TC{} failed",
+                index
+            );
+
+            // Retrying a failed FillEvent once the Repository recovers counts it exactly once
+            if !test.expected_ok {
+                portfolio.repository.set_exited_position = Some(|_, _| Ok(()));
+                portfolio.repository.commit = None;
+
+                assert!(
+                    portfolio.update_from_fill(&input_fill).is_ok(),
+                    "TC{} failed",
+                    index
+                );
+                assert_eq!(
+                    portfolio.rolling().unwrap().summaries()[0].returns.len(),
+                    1,
+                    "TC{} failed",
+                    index
+                );
+                assert_eq!(
+                    portfolio.equity.current.time, input_fill.time,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
     #[test]
     fn update_from_fill_exiting_long_position_in_profit() {
         // Build Portfolio
@@ -1435,6 +2497,367 @@ pub mod tests {
         assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
     }
 
+    #[test]
+    fn generate_no_order_with_in_flight_order_for_market() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(None)),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 100.0,
+                    available: 100.0,
+                })
+            }),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        let first = portfolio.generate_order(&input_signal).unwrap();
+        let duplicate = portfolio.generate_order(&input_signal).unwrap();
+
+        assert!(first.is_some());
+        assert!(duplicate.is_none());
+        assert_eq!(portfolio.in_flight_orders().count(), 1);
+    }
+
+    #[test]
+    fn update_from_fill_partially_filling_in_flight_entry_order() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 200.0,
+                    available: 100.0,
+                })
+            }),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // In-flight entry OrderEvent for 2.0 contracts, reserving 200.0 cash
+        let mut input_order = order_event();
+        input_order.decision = Decision::Long;
+        input_order.quantity = 2.0;
+        portfolio
+            .in_flight_orders
+            .insert(input_order.cid, InFlightOrder::new(input_order));
+
+        // Input FillEvent for half of the OrderEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 100.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill);
+        let in_flight = portfolio.in_flight_orders().next().unwrap().clone();
+        let updated_cash = portfolio.repository.balance.unwrap().available;
+
+        assert!(result.is_ok());
+        assert_eq!(in_flight.filled_quantity, 1.0);
+        assert_eq!(in_flight.reserved_cash, 100.0);
+        // Partially filled InFlightOrder is persisted in the repository
+        assert_eq!(
+            portfolio
+                .repository
+                .in_flight_orders
+                .get(&in_flight.order.cid),
+            Some(&in_flight)
+        );
+        // cash += released reserved cash - enter_value_gross - enter_fees
+        assert_eq!(updated_cash, 100.0 + 100.0 - 100.0 - 3.0);
+    }
+
+    #[test]
+    fn update_from_fill_partially_filling_in_flight_exit_order_keeps_position_open() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 200.0,
+                    available: 97.0,
+                })
+            }),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // In-flight exit OrderEvent for 2.0 contracts
+        let mut input_order = order_event();
+        input_order.decision = Decision::CloseLong;
+        input_order.quantity = -2.0;
+        portfolio
+            .in_flight_orders
+            .insert(input_order.cid, InFlightOrder::new(input_order));
+
+        // Input FillEvent for half of the OrderEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 200.0;
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let in_flight = portfolio.in_flight_orders().next().unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert!(matches!(result[0], Event::Balance(_)));
+        assert_eq!(in_flight.exit_fill.as_ref().unwrap().quantity, -1.0);
+        assert!(portfolio.repository.position.is_none());
+    }
+
+    #[test]
+    fn update_from_order_update_cancelled_releases_reserved_cash() {
+        struct TestCase {
+            cid: ClientOrderId,
+            status: OrderStatus,
+            expected_events: usize,
+            expected_available: f64,
+            expected_in_flight: usize,
+        }
+
+        let order_cid = ClientOrderId::random();
+
+        let cases = vec![
+            TestCase {
+                // TC0: Open OrderUpdate leaves the OrderEvent in-flight
+                cid: order_cid,
+                status: OrderStatus::Open,
+                expected_events: 0,
+                expected_available: 0.0,
+                expected_in_flight: 1,
+            },
+            TestCase {
+                // TC1: Cancelled OrderUpdate releases reserved cash
+                cid: order_cid,
+                status: OrderStatus::Cancelled,
+                expected_events: 1,
+                expected_available: 100.0,
+                expected_in_flight: 0,
+            },
+            TestCase {
+                // TC2: Rejected OrderUpdate releases reserved cash
+                cid: order_cid,
+                status: OrderStatus::Rejected("insufficient margin".to_owned()),
+                expected_events: 1,
+                expected_available: 100.0,
+                expected_in_flight: 0,
+            },
+            TestCase {
+                // TC3: Cancelled OrderUpdate of another OrderEvent on the same market is ignored
+                cid: ClientOrderId::random(),
+                status: OrderStatus::Cancelled,
+                expected_events: 0,
+                expected_available: 0.0,
+                expected_in_flight: 1,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            // Build Portfolio
+            let mock_repository = MockRepository::<PnLReturnSummary> {
+                get_balance: Some(|_| {
+                    Ok(Balance {
+                        time: Utc::now(),
+                        total: 100.0,
+                        available: 0.0,
+                    })
+                }),
+                set_balance: Some(|_, _| Ok(())),
+                ..Default::default()
+            };
+            let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+            // In-flight entry OrderEvent reserving 100.0 cash
+            let mut input_order = order_event();
+            input_order.cid = order_cid;
+            input_order.decision = Decision::Long;
+            let in_flight = InFlightOrder::new(input_order.clone());
+            portfolio
+                .repository
+                .set_in_flight_order(portfolio.engine_id, in_flight.clone())
+                .unwrap();
+            portfolio.in_flight_orders.insert(order_cid, in_flight);
+
+            let input_update = OrderUpdate {
+                time: Utc::now(),
+                cid: test.cid,
+                exchange: input_order.exchange,
+                instrument: input_order.instrument,
+                status: test.status,
+            };
+
+            let actual = portfolio.update_from_order_update(&input_update).unwrap();
+            let actual_available = portfolio
+                .repository
+                .balance
+                .map(|balance| balance.available)
+                .unwrap_or(0.0);
+
+            assert_eq!(actual.len(), test.expected_events, "TC{index} failed");
+            assert_eq!(
+                actual_available, test.expected_available,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                portfolio.in_flight_orders().count(),
+                test.expected_in_flight,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                portfolio.repository.in_flight_orders.len(),
+                test.expected_in_flight,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn build_and_init_restores_persisted_in_flight_orders() {
+        // Repository persisting an in-flight entry OrderEvent reserving 100.0 cash
+        let mut repository = InMemoryRepository::<PnLReturnSummary>::new();
+        let engine_id = Uuid::new_v4();
+        let mut input_order = order_event();
+        input_order.decision = Decision::Long;
+        input_order.market_meta.close = 100.0;
+        repository
+            .set_in_flight_order(engine_id, InFlightOrder::new(input_order.clone()))
+            .unwrap();
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![Market::new(
+                input_order.exchange,
+                input_order.instrument.clone(),
+            )])
+            .starting_cash(1000.0)
+            .repository(repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .build_and_init()
+            .unwrap();
+
+        assert_eq!(
+            portfolio.in_flight_orders().next().unwrap().order,
+            input_order
+        );
+        assert_eq!(portfolio.get_balance(engine_id).unwrap().available, 900.0);
+
+        // FillEvent of the restored OrderEvent completes it
+        let input_fill = FillEvent {
+            cid: input_order.cid,
+            decision: Decision::Long,
+            ..fill_event()
+        };
+        portfolio.update_from_fill(&input_fill).unwrap();
+
+        assert_eq!(portfolio.in_flight_orders().count(), 0);
+        assert!(portfolio
+            .repository
+            .get_in_flight_orders(engine_id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn update_from_order_update_partial_exit_is_transactional() {
+        // Build Portfolio with an open Position of 2.0 contracts & a rolling window
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            remove_position: Some(|_| {
+                let mut input_position = position();
+                input_position.quantity = 2.0;
+                input_position.enter_value_gross = 200.0;
+                Ok(Some(input_position))
+            }),
+            set_open_position: Some(|_| Ok(())),
+            get_statistics: Some(|_| Ok(PnLReturnSummary::default())),
+            set_statistics: Some(|_, _| Ok(())),
+            set_exited_position: Some(|_, _| Err(RepositoryError::WriteError)),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        let (metric_tx, _metric_rx) = mpsc::unbounded_channel();
+        portfolio.rolling = Some(
+            RollingMonitor::new(
+                portfolio.engine_id,
+                &[RollingConfig {
+                    window: Window::Trades(10),
+                    risk_free_return: 0.0,
+                }],
+                MetricTx::new(metric_tx),
+            )
+            .unwrap(),
+        );
+        let balance = portfolio.balance;
+
+        // In-flight exit OrderEvent for 2.0 contracts, half filled before being cancelled
+        let mut input_order = order_event();
+        input_order.decision = Decision::CloseLong;
+        input_order.quantity = -2.0;
+        let mut in_flight = InFlightOrder::new(input_order.clone());
+        in_flight.apply_fill(&FillEvent {
+            cid: input_order.cid,
+            decision: Decision::CloseLong,
+            quantity: -1.0,
+            fill_value_gross: 200.0,
+            ..fill_event()
+        });
+        portfolio
+            .in_flight_orders
+            .insert(input_order.cid, in_flight);
+
+        let input_update = OrderUpdate {
+            time: Utc::now(),
+            cid: input_order.cid,
+            exchange: input_order.exchange,
+            instrument: input_order.instrument,
+            status: OrderStatus::Cancelled,
+        };
+
+        // Repository write fails midway, so the transaction & in-memory state are rolled back
+        assert!(portfolio.update_from_order_update(&input_update).is_err());
+        assert_eq!(portfolio.repository.transactions, vec!["begin", "rollback"]);
+        assert_eq!(portfolio.in_flight_orders().count(), 1);
+        assert_eq!(portfolio.balance, balance);
+        assert!(portfolio.rolling().unwrap().summaries()[0]
+            .returns
+            .is_empty());
+
+        // Retrying once the Repository recovers exits the filled part of the Position once
+        portfolio.repository.set_exited_position = Some(|_, _| Ok(()));
+        let events = portfolio.update_from_order_update(&input_update).unwrap();
+
+        assert!(matches!(events[0], Event::PositionExit(_)));
+        assert_eq!(
+            portfolio.repository.transactions,
+            vec!["begin", "rollback", "begin", "commit"]
+        );
+        assert_eq!(portfolio.in_flight_orders().count(), 0);
+        assert_eq!(portfolio.rolling().unwrap().summaries()[0].returns.len(), 1);
+        assert!(portfolio.repository.position.is_some());
+    }
+
     #[test]
     fn parse_signal_decisions_to_net_close_long() {
         // Some(Position)
@@ -1593,4 +3016,33 @@ pub mod tests {
 
         assert_eq!(actual, None);
     }
+
+    #[test]
+    fn balance_handler_rejects_mismatched_engine_id() {
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| Ok(Balance::default())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        let engine_id = portfolio.engine_id();
+        let other_engine_id = Uuid::new_v4();
+
+        assert!(portfolio.get_balance(engine_id).is_ok());
+        assert!(portfolio.set_balance(engine_id, Balance::default()).is_ok());
+
+        assert!(matches!(
+            portfolio.get_balance(other_engine_id),
+            Err(RepositoryError::EngineIdMismatch { expected, actual })
+                if expected == engine_id && actual == other_engine_id
+        ));
+        assert!(matches!(
+            portfolio.set_balance(other_engine_id, Balance::default()),
+            Err(RepositoryError::EngineIdMismatch { .. })
+        ));
+        assert!(matches!(
+            portfolio.get_exited_positions(other_engine_id),
+            Err(RepositoryError::EngineIdMismatch { .. })
+        ));
+    }
 }
@@ -1,13 +1,14 @@
 use crate::{
+    data::MarketMeta,
     execution::{FeeAmount, Fees, FillEvent},
     portfolio::{error::PortfolioError, Balance},
+    statistic::{de_duration_from_secs, se_duration_as_secs},
     strategy::Decision,
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::{exchange::ExchangeId, instrument::market_data::MarketDataInstrument};
 use barter_integration::Side;
-use chrono::{DateTime, Utc};
-use rust_decimal::prelude::ToPrimitive;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use smol_str::{format_smolstr, SmolStr};
 use std::convert::TryFrom;
@@ -108,6 +109,11 @@ pub struct Position {
 
     /// Realised P&L after the [`Position`] has closed.
     pub realised_profit_loss: f64,
+
+    /// Maximum adverse & favourable excursions, and time spent in profit & loss, recorded over
+    /// the lifetime of the [`Position`].
+    #[serde(default)]
+    pub excursion: PositionExcursion,
 }
 
 impl PositionEnterer for Position {
@@ -147,6 +153,7 @@ impl PositionEnterer for Position {
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
             realised_profit_loss: 0.0,
+            excursion: PositionExcursion::default(),
         })
     }
 }
@@ -157,19 +164,18 @@ impl PositionUpdater for Position {
         market: &MarketEvent<MarketDataInstrument, DataKind>,
     ) -> Option<PositionUpdate> {
         // Determine close from MarketEvent
-        let close = match &market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price().to_f64()?,
-            DataKind::OrderBook(_) | DataKind::Liquidation(_) => return None,
-        };
+        let close = MarketMeta::from_market_event(market)?.close;
 
+        // Accrue time in profit or loss at the previous price
+        self.accrue_excursion_time(market.time_exchange);
         self.meta.update_time = market.time_exchange;
 
         self.current_price = close;
 
         // Market value gross
         self.current_value_gross = close * self.quantity.abs();
+        self.excursion
+            .update(self.calculate_excursion(self.current_value_gross));
 
         // Unreal profit & loss
         self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
@@ -197,6 +203,11 @@ impl PositionExiter for Position {
         self.exit_value_gross = fill.fill_value_gross;
         self.exit_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
+        // Excursion up to & including the exit price
+        self.accrue_excursion_time(fill.time);
+        self.excursion
+            .update(self.calculate_excursion(self.exit_value_gross));
+
         // Result profit & loss
         self.realised_profit_loss = self.calculate_realised_profit_loss();
         self.unrealised_profit_loss = self.realised_profit_loss;
@@ -234,6 +245,72 @@ impl Position {
         }
     }
 
+    /// Increases the size of this open [`Position`] using an additional entry [`FillEvent`] (eg/
+    /// a partial fill of an in-flight entry order), returning a [`PositionUpdate`] that
+    /// communicates the change in state.
+    pub fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::ParseEntrySide);
+        }
+
+        // Enter fees
+        self.enter_fees = self.enter_fees + fill.fees;
+        self.enter_fees_total += fill.fees.calculate_total_fees();
+
+        // Enter quantity, value & price
+        self.quantity += fill.quantity;
+        self.enter_value_gross += fill.fill_value_gross;
+        self.enter_avg_price_gross = self.enter_value_gross / self.quantity.abs();
+
+        // Market value gross & unreal profit & loss
+        self.accrue_excursion_time(fill.time);
+        self.meta.update_time = fill.time;
+        self.current_value_gross = self.current_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    /// Splits the input +ve or -ve quantity off this open [`Position`], returning it as a new
+    /// [`Position`]. Enter values & fees are apportioned between the two [`Position`]s. Used to
+    /// exit the part of a [`Position`] that an exit order filled before it was cancelled.
+    pub fn split(&mut self, quantity: f64) -> Position {
+        let ratio = (quantity / self.quantity).clamp(0.0, 1.0);
+
+        let mut split = self.clone();
+        split.quantity = quantity;
+        split.enter_fees = self.enter_fees * ratio;
+        split.enter_fees_total = self.enter_fees_total * ratio;
+        split.enter_value_gross = self.enter_value_gross * ratio;
+        split.current_value_gross = self.current_value_gross * ratio;
+        split.unrealised_profit_loss = split.calculate_unrealised_profit_loss();
+        split.excursion.scale(ratio);
+
+        self.quantity -= quantity;
+        self.enter_fees = self.enter_fees * (1.0 - ratio);
+        self.enter_fees_total -= split.enter_fees_total;
+        self.enter_value_gross -= split.enter_value_gross;
+        self.current_value_gross -= split.current_value_gross;
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+        self.excursion.scale(1.0 - ratio);
+
+        split
+    }
+
+    /// Resizes this open [`Position`] to the input +ve or -ve quantity, scaling the enter values &
+    /// fees proportionally. Used to adopt the quantity held at the venue when reconciling.
+    pub fn resize(&mut self, quantity: f64) {
+        let ratio = quantity / self.quantity;
+
+        self.quantity = quantity;
+        self.enter_fees = self.enter_fees * ratio;
+        self.enter_fees_total *= ratio;
+        self.enter_value_gross *= ratio;
+        self.current_value_gross *= ratio;
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+        self.excursion.scale(ratio);
+    }
+
     /// Determines the [`Decision`] required to exit this [`Side`] (Buy or Sell) [`Position`].
     pub fn determine_exit_decision(&self) -> Decision {
         match self.side {
@@ -267,6 +344,85 @@ impl Position {
     pub fn calculate_profit_loss_return(&self) -> f64 {
         self.realised_profit_loss / self.enter_value_gross
     }
+
+    /// Calculate the gross excursion (excluding fees) of the [`Position`] if it were valued at
+    /// the input gross value.
+    pub fn calculate_excursion(&self, value_gross: f64) -> f64 {
+        match self.side {
+            Side::Buy => value_gross - self.enter_value_gross,
+            Side::Sell => self.enter_value_gross - value_gross,
+        }
+    }
+
+    /// Duration the [`Position`] has been held, up until it's exit if it has been exited.
+    pub fn holding_duration(&self) -> Duration {
+        self.meta
+            .update_time
+            .signed_duration_since(self.meta.enter_time)
+    }
+
+    /// Accrue the time elapsed since the last [`Position`] update to the time in profit or loss,
+    /// based on the excursion at the current price.
+    fn accrue_excursion_time(&mut self, time: DateTime<Utc>) {
+        let elapsed = time.signed_duration_since(self.meta.update_time);
+        if elapsed <= Duration::zero() {
+            return;
+        }
+
+        let excursion = self.calculate_excursion(self.current_value_gross);
+        if excursion > 0.0 {
+            self.excursion.time_in_profit += elapsed;
+        } else if excursion < 0.0 {
+            self.excursion.time_in_loss += elapsed;
+        }
+    }
+}
+
+/// Maximum adverse excursion (MAE) & maximum favourable excursion (MFE) of a [`Position`], as
+/// well as the time it has spent in profit & loss. Excursions are gross values excluding fees.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionExcursion {
+    /// Most adverse gross excursion observed (<= 0.0).
+    pub max_adverse: f64,
+    /// Most favourable gross excursion observed (>= 0.0).
+    pub max_favourable: f64,
+    /// Duration spent with a positive gross excursion.
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub time_in_profit: Duration,
+    /// Duration spent with a negative gross excursion.
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub time_in_loss: Duration,
+}
+
+impl Default for PositionExcursion {
+    fn default() -> Self {
+        Self {
+            max_adverse: 0.0,
+            max_favourable: 0.0,
+            time_in_profit: Duration::zero(),
+            time_in_loss: Duration::zero(),
+        }
+    }
+}
+
+impl PositionExcursion {
+    /// Updates the maximum adverse & favourable excursions with the latest gross excursion.
+    pub fn update(&mut self, excursion: f64) {
+        self.max_adverse = self.max_adverse.min(excursion);
+        self.max_favourable = self.max_favourable.max(excursion);
+    }
+
+    /// Scales the excursions to a proportion of the [`Position`] quantity.
+    fn scale(&mut self, ratio: f64) {
+        self.max_adverse *= ratio;
+        self.max_favourable *= ratio;
+    }
 }
 
 /// Builder to construct [`Position`] instances.
@@ -290,6 +446,7 @@ pub struct PositionBuilder {
     pub current_value_gross: Option<f64>,
     pub unrealised_profit_loss: Option<f64>,
     pub realised_profit_loss: Option<f64>,
+    pub excursion: Option<PositionExcursion>,
 }
 
 impl PositionBuilder {
@@ -423,6 +580,13 @@ impl PositionBuilder {
         }
     }
 
+    pub fn excursion(self, value: PositionExcursion) -> Self {
+        Self {
+            excursion: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<Position, PortfolioError> {
         Ok(Position {
             position_id: self
@@ -475,6 +639,7 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            excursion: self.excursion.unwrap_or_default(),
         })
     }
 }
@@ -1574,6 +1739,156 @@ mod tests {
         assert_eq!(position.determine_exit_decision(), Decision::CloseShort);
     }
 
+    #[test]
+    fn update_and_exit_position_tracks_excursion() {
+        struct TestCase {
+            hours: i64,
+            price: f64,
+            expected: PositionExcursion,
+        }
+
+        let start = position().meta.update_time;
+        let mut position = position();
+
+        let cases = vec![
+            // TC0: price falls, no time accrued at the entry price
+            TestCase {
+                hours: 1,
+                price: 90.0,
+                expected: PositionExcursion {
+                    max_adverse: -10.0,
+                    max_favourable: 0.0,
+                    time_in_profit: Duration::zero(),
+                    time_in_loss: Duration::zero(),
+                },
+            },
+            // TC1: price rises, previous hour spent in loss
+            TestCase {
+                hours: 2,
+                price: 125.0,
+                expected: PositionExcursion {
+                    max_adverse: -10.0,
+                    max_favourable: 25.0,
+                    time_in_profit: Duration::zero(),
+                    time_in_loss: Duration::hours(1),
+                },
+            },
+            // TC2: price retraces, previous 2 hours spent in profit
+            TestCase {
+                hours: 4,
+                price: 105.0,
+                expected: PositionExcursion {
+                    max_adverse: -10.0,
+                    max_favourable: 25.0,
+                    time_in_profit: Duration::hours(2),
+                    time_in_loss: Duration::hours(1),
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut market = market_event_trade(Side::Buy);
+            market.time_exchange = start + Duration::hours(test.hours);
+            if let DataKind::Trade(trade) = &mut market.kind {
+                trade.price = test.price;
+            }
+
+            position.update(&market);
+            assert_eq!(position.excursion, test.expected, "TC{} failed", index);
+        }
+
+        // Exit below the lowest observed price after another hour in profit
+        let mut exit_fill = fill_event();
+        exit_fill.decision = Decision::CloseLong;
+        exit_fill.quantity = -1.0;
+        exit_fill.fill_value_gross = 80.0;
+        exit_fill.time = start + Duration::hours(5);
+
+        position.exit(Balance::default(), &exit_fill).unwrap();
+        assert_eq!(position.excursion.max_adverse, -20.0);
+        assert_eq!(position.excursion.time_in_profit, Duration::hours(3));
+        assert_eq!(
+            position.holding_duration(),
+            Duration::hours(5) + (start - position.meta.enter_time)
+        );
+    }
+
+    #[test]
+    fn increase_long_position_with_additional_long_entry_fill() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+        position.enter_fees_total = 3.0;
+        position.enter_value_gross = 100.0;
+        position.enter_avg_price_gross = 100.0;
+        position.current_price = 100.0;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 200.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let actual_update = position.increase(&input_fill).unwrap();
+
+        assert_eq!(position.quantity, 2.0);
+        assert_eq!(position.enter_value_gross, 300.0);
+        assert_eq!(position.enter_avg_price_gross, 150.0);
+        assert_eq!(position.enter_fees_total, 4.0);
+        assert_eq!(position.current_value_gross, 200.0);
+        // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
+        assert_eq!(actual_update.unrealised_profit_loss, 200.0 - 300.0 - 8.0);
+
+        // Increasing with an opposite side entry FillEvent is an error
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -1.0;
+        assert!(position.increase(&input_fill).is_err());
+    }
+
+    #[test]
+    fn split_short_position_apportions_enter_values() {
+        let mut position = position();
+        position.side = Side::Sell;
+        position.quantity = -4.0;
+        position.enter_fees_total = 8.0;
+        position.enter_value_gross = 400.0;
+        position.current_price = 100.0;
+        position.current_value_gross = 400.0;
+
+        let split = position.split(-1.0);
+
+        assert_eq!(split.quantity, -1.0);
+        assert_eq!(split.enter_value_gross, 100.0);
+        assert_eq!(split.enter_fees_total, 2.0);
+        assert_eq!(split.current_value_gross, 100.0);
+        assert_eq!(position.quantity, -3.0);
+        assert_eq!(position.enter_value_gross, 300.0);
+        assert_eq!(position.enter_fees_total, 6.0);
+        assert_eq!(position.current_value_gross, 300.0);
+    }
+
+    #[test]
+    fn resize_position_scales_enter_values() {
+        let mut position = position();
+        position.quantity = 2.0;
+        position.enter_fees_total = 4.0;
+        position.enter_value_gross = 200.0;
+        position.current_price = 100.0;
+        position.current_value_gross = 200.0;
+
+        position.resize(3.0);
+
+        assert_eq!(position.quantity, 3.0);
+        assert_eq!(position.enter_value_gross, 300.0);
+        assert_eq!(position.enter_fees_total, 6.0);
+        assert_eq!(position.current_value_gross, 300.0);
+        assert_eq!(position.enter_avg_price_gross, 100.0);
+    }
+
     #[test]
     fn position_update_from_position() {
         let mut input_position = position();
@@ -0,0 +1,652 @@
+use crate::portfolio::{
+    error::PortfolioError,
+    position::{determine_position_id, Position},
+    repository::{BalanceHandler, PositionHandler},
+};
+use barter_execution::{
+    model::{
+        balance::AssetBalance,
+        order::{Open, Order, RequestCancel},
+    },
+    ExecutionClient,
+};
+use barter_instrument::{
+    asset::name::AssetNameInternal, instrument::market_data::kind::MarketDataInstrumentKind,
+    market::Market,
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Action taken when a [`Drift`] is detected between the repository & the venue.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Resolution {
+    /// Log the [`Drift`] and resume trading with the repository state unchanged.
+    Ignore,
+    /// Prevent trading from resuming by returning a [`PortfolioError::ReconcileAborted`].
+    Abort,
+    /// Treat the venue as the source of truth. Repository [`Position`]s & balance are updated to
+    /// match the venue, and orphaned venue orders are cancelled.
+    Resolve,
+}
+
+/// Configurable [`Resolution`] for each kind of [`Drift`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ReconcilePolicy {
+    /// [`Resolution`] for [`Drift::MissingAtVenue`], [`Drift::MissingInRepository`] &
+    /// [`Drift::QuantityMismatch`].
+    ///
+    /// Note that a [`Drift::MissingInRepository`] cannot be resolved since the venue does not
+    /// provide the [`Position`] entry price, so [`Resolution::Resolve`] aborts.
+    pub positions: Resolution,
+    /// [`Resolution`] for [`Drift::CashMismatch`].
+    pub balance: Resolution,
+    /// [`Resolution`] for [`Drift::OrphanedOrder`].
+    pub orders: Resolution,
+}
+
+impl Default for ReconcilePolicy {
+    fn default() -> Self {
+        Self {
+            positions: Resolution::Abort,
+            balance: Resolution::Abort,
+            orders: Resolution::Abort,
+        }
+    }
+}
+
+/// Difference detected between the repository & the venue.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Drift {
+    /// Repository has an open [`Position`] that the venue holds no balance for.
+    MissingAtVenue(Position),
+    /// Venue holds a balance for a base asset that the repository has no open [`Position`] for.
+    MissingInRepository {
+        asset: AssetNameInternal,
+        quantity: f64,
+    },
+    /// Repository open [`Position`] quantity differs from its share of the base asset balance
+    /// held at the venue.
+    QuantityMismatch { position: Position, venue: f64 },
+    /// Repository available cash differs from the cash asset balance held at the venue.
+    CashMismatch { repository: f64, venue: f64 },
+    /// Venue has an open order the Portfolio is not tracking.
+    OrphanedOrder(Order<Open>),
+}
+
+impl Drift {
+    /// Determine the [`Resolution`] for this [`Drift`] using the provided [`ReconcilePolicy`].
+    pub fn resolution(&self, policy: &ReconcilePolicy) -> Resolution {
+        match self {
+            Drift::MissingInRepository { .. } => match policy.positions {
+                Resolution::Resolve => Resolution::Abort,
+                resolution => resolution,
+            },
+            Drift::MissingAtVenue(_) | Drift::QuantityMismatch { .. } => policy.positions,
+            Drift::CashMismatch { .. } => policy.balance,
+            Drift::OrphanedOrder(_) => policy.orders,
+        }
+    }
+}
+
+/// Reconciles the open [`Position`]s & balance persisted in a Portfolio's repository against the
+/// state held at the venue, applying a [`ReconcilePolicy`] to any [`Drift`] before trading
+/// resumes.
+///
+/// Only spot [`Market`]s have their open [`Position`]s reconciled, since the venue base asset
+/// balance is the sum of the spot [`Position`] quantities for every [`Market`] sharing that base
+/// asset.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Reconciler {
+    /// Identifier for the [`Engine`](crate::engine::Engine) whose state is reconciled.
+    pub engine_id: Uuid,
+    /// [`Market`]s traded by the [`Engine`](crate::engine::Engine).
+    pub markets: Vec<Market>,
+    /// Asset the Portfolio's available cash is denominated in (eg/ "usdt").
+    pub cash_asset: AssetNameInternal,
+    /// Absolute difference in quantity or cash below which no [`Drift`] is detected.
+    pub tolerance: f64,
+    pub policy: ReconcilePolicy,
+}
+
+impl Reconciler {
+    /// Fetch the venue state using the provided [`ExecutionClient`], detect any [`Drift`] against
+    /// the Portfolio's repository, and apply the [`ReconcilePolicy`]. Returns every [`Drift`]
+    /// detected, or a [`PortfolioError::ReconcileAborted`] containing the [`Drift`] that
+    /// prevents trading from resuming.
+    pub async fn reconcile<Portfolio, Client>(
+        &self,
+        portfolio: &Mutex<Portfolio>,
+        client: &Client,
+    ) -> Result<Vec<Drift>, PortfolioError>
+    where
+        Portfolio: PositionHandler + BalanceHandler,
+        Client: ExecutionClient + Sync,
+    {
+        let balances = client.fetch_balances().await?;
+        let orders = client.fetch_orders_open().await?;
+
+        let drifts = {
+            let mut portfolio = portfolio.lock();
+            self.detect(&mut *portfolio, &balances, orders)?
+        };
+
+        // Abort before resolving anything if any Drift cannot be tolerated
+        let aborted = drifts
+            .iter()
+            .filter(|drift| drift.resolution(&self.policy) == Resolution::Abort)
+            .cloned()
+            .collect::<Vec<_>>();
+        if !aborted.is_empty() {
+            return Err(PortfolioError::ReconcileAborted(aborted));
+        }
+
+        let (resolved, ignored): (Vec<_>, Vec<_>) = drifts
+            .iter()
+            .partition(|drift| drift.resolution(&self.policy) == Resolution::Resolve);
+
+        for drift in ignored {
+            warn!(engine_id = %self.engine_id, ?drift, "ignoring reconciliation Drift");
+        }
+        for drift in &resolved {
+            info!(engine_id = %self.engine_id, ?drift, "resolving reconciliation Drift");
+        }
+
+        // Cancel orphaned orders first, so the repository is only updated once the venue state
+        // it is resolved against can no longer change
+        let cancel_requests = resolved
+            .iter()
+            .filter_map(|drift| match drift {
+                Drift::OrphanedOrder(order) => Some(Order {
+                    exchange: order.exchange,
+                    instrument: order.instrument.clone(),
+                    cid: order.cid,
+                    side: order.side,
+                    state: RequestCancel::from(order.state.id.clone()),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if !cancel_requests.is_empty() {
+            for cancelled in client.cancel_orders(cancel_requests).await {
+                cancelled?;
+            }
+        }
+
+        let mut portfolio = portfolio.lock();
+        for drift in resolved {
+            self.resolve(&mut *portfolio, drift)?;
+        }
+
+        Ok(drifts)
+    }
+
+    /// Detect every [`Drift`] between the Portfolio's repository & the provided venue state.
+    pub fn detect<Portfolio>(
+        &self,
+        portfolio: &mut Portfolio,
+        balances: &[AssetBalance],
+        orders: Vec<Order<Open>>,
+    ) -> Result<Vec<Drift>, PortfolioError>
+    where
+        Portfolio: PositionHandler + BalanceHandler,
+    {
+        let venue_balance = |asset: &AssetNameInternal| {
+            balances
+                .iter()
+                .find(|balance| &balance.asset == asset)
+                .map_or(0.0, |balance| balance.balance.total)
+        };
+
+        let mut drifts = Vec::new();
+
+        // Spot Positions, aggregated per base asset since Markets may share a base asset
+        let mut base_positions: Vec<(&AssetNameInternal, Vec<Position>)> = Vec::new();
+        for market in &self.markets {
+            if market.instrument.kind != MarketDataInstrumentKind::Spot {
+                continue;
+            }
+
+            let position_id =
+                determine_position_id(self.engine_id, &market.exchange, &market.instrument);
+            let position = portfolio.get_open_position(&position_id)?;
+
+            let base = &market.instrument.base;
+            let positions = match base_positions.iter_mut().find(|(asset, _)| *asset == base) {
+                Some((_, positions)) => positions,
+                None => {
+                    base_positions.push((base, Vec::new()));
+                    &mut base_positions.last_mut().unwrap().1
+                }
+            };
+            positions.extend(position);
+        }
+
+        for (base, positions) in base_positions {
+            let venue = venue_balance(base);
+            let repository = positions
+                .iter()
+                .map(|position| position.quantity.abs())
+                .sum::<f64>();
+
+            if positions.is_empty() {
+                if venue > self.tolerance {
+                    drifts.push(Drift::MissingInRepository {
+                        asset: base.clone(),
+                        quantity: venue,
+                    });
+                }
+            } else if venue <= self.tolerance {
+                drifts.extend(positions.into_iter().map(Drift::MissingAtVenue));
+            } else if (repository - venue).abs() > self.tolerance {
+                // Apportion the venue balance between the Positions by their repository quantity
+                drifts.extend(positions.into_iter().map(|position| {
+                    let venue = venue * position.quantity.abs() / repository;
+                    Drift::QuantityMismatch { position, venue }
+                }));
+            }
+        }
+
+        // Available cash
+        let repository = portfolio.get_balance(self.engine_id)?.available;
+        let venue = venue_balance(&self.cash_asset);
+        if (repository - venue).abs() > self.tolerance {
+            drifts.push(Drift::CashMismatch { repository, venue });
+        }
+
+        // Open orders are not persisted, so any venue open order for a traded Market is orphaned
+        drifts.extend(
+            orders
+                .into_iter()
+                .filter(|order| {
+                    self.markets.iter().any(|market| {
+                        market.exchange == order.exchange && market.instrument == order.instrument
+                    })
+                })
+                .map(Drift::OrphanedOrder),
+        );
+
+        Ok(drifts)
+    }
+
+    /// Update the Portfolio's repository to match the venue for the provided [`Drift`].
+    fn resolve<Portfolio>(
+        &self,
+        portfolio: &mut Portfolio,
+        drift: &Drift,
+    ) -> Result<(), PortfolioError>
+    where
+        Portfolio: PositionHandler + BalanceHandler,
+    {
+        match drift {
+            Drift::MissingAtVenue(position) => {
+                portfolio.remove_position(&position.position_id)?;
+            }
+            Drift::QuantityMismatch { position, venue } => {
+                let mut position = position.clone();
+                position.resize(venue.copysign(position.quantity));
+                portfolio.set_open_position(position)?;
+            }
+            Drift::CashMismatch { venue, .. } => {
+                let mut balance = portfolio.get_balance(self.engine_id)?;
+                balance.total += venue - balance.available;
+                balance.available = *venue;
+                portfolio.set_balance(self.engine_id, balance)?;
+            }
+            Drift::MissingInRepository { .. } | Drift::OrphanedOrder(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{repository::in_memory::InMemoryRepository, Balance},
+        statistic::summary::trading::TradingSummary,
+        test_util::position,
+    };
+    use async_trait::async_trait;
+    use barter_execution::{
+        error::ExecutionError,
+        model::{
+            balance::Balance as VenueBalance,
+            order::{Cancelled, OrderId, RequestOpen},
+            AccountEvent, ClientOrderId,
+        },
+    };
+    use barter_instrument::exchange::ExchangeId;
+    use barter_integration::Side;
+    use chrono::Utc;
+    use tokio::sync::mpsc;
+
+    const ENGINE_ID: Uuid = Uuid::nil();
+
+    fn market() -> Market {
+        Market::new(
+            ExchangeId::BinanceSpot,
+            ("eth", "usdt", MarketDataInstrumentKind::Spot),
+        )
+    }
+
+    fn reconciler(policy: ReconcilePolicy) -> Reconciler {
+        Reconciler {
+            engine_id: ENGINE_ID,
+            markets: vec![market()],
+            cash_asset: AssetNameInternal::from("usdt"),
+            tolerance: 1e-6,
+            policy,
+        }
+    }
+
+    fn repository(
+        position_quantity: Option<f64>,
+        available: f64,
+    ) -> InMemoryRepository<TradingSummary> {
+        let mut repository = InMemoryRepository::new();
+        repository
+            .set_balance(
+                ENGINE_ID,
+                Balance {
+                    time: Utc::now(),
+                    total: available + 100.0,
+                    available,
+                },
+            )
+            .unwrap();
+
+        if let Some(quantity) = position_quantity {
+            let market = market();
+            let mut position = position();
+            position.position_id =
+                determine_position_id(ENGINE_ID, &market.exchange, &market.instrument);
+            position.resize(quantity);
+            repository.set_open_position(position).unwrap();
+        }
+
+        repository
+    }
+
+    fn venue_balances(eth: f64, usdt: f64) -> Vec<AssetBalance> {
+        vec![
+            AssetBalance::new("eth", VenueBalance::new(eth, eth)),
+            AssetBalance::new("usdt", VenueBalance::new(usdt, usdt)),
+        ]
+    }
+
+    fn venue_order() -> Order<Open> {
+        let market = market();
+        Order {
+            exchange: market.exchange,
+            instrument: market.instrument,
+            cid: ClientOrderId(Uuid::nil()),
+            side: Side::Buy,
+            state: Open {
+                id: OrderId::from("order_id"),
+                price: 100.0,
+                quantity: 1.0,
+                filled_quantity: 0.0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_detect_drift() {
+        struct TestCase {
+            position_quantity: Option<f64>,
+            balances: Vec<AssetBalance>,
+            orders: Vec<Order<Open>>,
+            expected: Vec<&'static str>,
+        }
+
+        let kind = |drift: &Drift| match drift {
+            Drift::MissingAtVenue(_) => "MissingAtVenue",
+            Drift::MissingInRepository { .. } => "MissingInRepository",
+            Drift::QuantityMismatch { .. } => "QuantityMismatch",
+            Drift::CashMismatch { .. } => "CashMismatch",
+            Drift::OrphanedOrder(_) => "OrphanedOrder",
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: repository matches venue
+                position_quantity: Some(1.0),
+                balances: venue_balances(1.0, 1000.0),
+                orders: vec![],
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: open Position without venue balance
+                position_quantity: Some(1.0),
+                balances: venue_balances(0.0, 1000.0),
+                orders: vec![],
+                expected: vec!["MissingAtVenue"],
+            },
+            TestCase {
+                // TC2: venue balance without open Position
+                position_quantity: None,
+                balances: venue_balances(2.0, 1000.0),
+                orders: vec![],
+                expected: vec!["MissingInRepository"],
+            },
+            TestCase {
+                // TC3: quantity & cash mismatch
+                position_quantity: Some(1.0),
+                balances: venue_balances(1.5, 900.0),
+                orders: vec![],
+                expected: vec!["QuantityMismatch", "CashMismatch"],
+            },
+            TestCase {
+                // TC4: untracked venue open order
+                position_quantity: None,
+                balances: venue_balances(0.0, 1000.0),
+                orders: vec![venue_order()],
+                expected: vec!["OrphanedOrder"],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut repository = repository(test.position_quantity, 1000.0);
+            let actual = reconciler(ReconcilePolicy::default())
+                .detect(&mut repository, &test.balances, test.orders)
+                .unwrap();
+            let actual = actual.iter().map(kind).collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    /// Venue [`ExecutionClient`] with fixed state that records cancelled orders.
+    struct MockVenue {
+        balances: Vec<AssetBalance>,
+        orders: Vec<Order<Open>>,
+        cancelled: Mutex<Vec<Order<RequestCancel>>>,
+        cancel_fails: bool,
+    }
+
+    #[async_trait]
+    impl ExecutionClient for MockVenue {
+        const CLIENT: ExchangeId = ExchangeId::BinanceSpot;
+        type Config = ();
+
+        async fn init(_: Self::Config, _: mpsc::UnboundedSender<AccountEvent>) -> Self {
+            unimplemented!()
+        }
+
+        async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+            Ok(self.orders.clone())
+        }
+
+        async fn fetch_balances(&self) -> Result<Vec<AssetBalance>, ExecutionError> {
+            Ok(self.balances.clone())
+        }
+
+        async fn open_orders(
+            &self,
+            _: Vec<Order<RequestOpen>>,
+        ) -> Vec<Result<Order<Open>, ExecutionError>> {
+            unimplemented!()
+        }
+
+        async fn cancel_orders(
+            &self,
+            cancel_requests: Vec<Order<RequestCancel>>,
+        ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+            if self.cancel_fails {
+                return cancel_requests
+                    .into_iter()
+                    .map(|order| Err(ExecutionError::OrderNotFound(order.cid)))
+                    .collect();
+            }
+
+            self.cancelled.lock().extend(cancel_requests);
+            Vec::new()
+        }
+
+        async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_detect_drift_aggregates_positions_sharing_base_asset() {
+        let eth_btc = Market::new(
+            ExchangeId::BinanceSpot,
+            ("eth", "btc", MarketDataInstrumentKind::Spot),
+        );
+        let eth_btc_position_id =
+            determine_position_id(ENGINE_ID, &eth_btc.exchange, &eth_btc.instrument);
+
+        let mut reconciler = reconciler(ReconcilePolicy::default());
+        reconciler.markets.push(eth_btc);
+
+        let mut repository = repository(Some(1.0), 1000.0);
+        let mut eth_btc_position = position();
+        eth_btc_position.position_id = eth_btc_position_id.clone();
+        eth_btc_position.resize(0.5);
+        repository.set_open_position(eth_btc_position).unwrap();
+
+        // TC0: venue base asset balance matches the sum of the Position quantities
+        let actual = reconciler
+            .detect(&mut repository, &venue_balances(1.5, 1000.0), vec![])
+            .unwrap();
+        assert!(actual.is_empty(), "TC0 failed: {actual:?}");
+
+        // TC1: venue base asset balance is apportioned between the Positions
+        let actual = reconciler
+            .detect(&mut repository, &venue_balances(3.0, 1000.0), vec![])
+            .unwrap();
+        let actual = actual
+            .iter()
+            .map(|drift| match drift {
+                Drift::QuantityMismatch { position, venue } => {
+                    (position.position_id.clone(), *venue)
+                }
+                drift => panic!("TC1 failed: unexpected {drift:?}"),
+            })
+            .collect::<Vec<_>>();
+        let market = market();
+        let eth_usdt_position_id =
+            determine_position_id(ENGINE_ID, &market.exchange, &market.instrument);
+        assert_eq!(
+            actual,
+            vec![(eth_usdt_position_id, 2.0), (eth_btc_position_id, 1.0)],
+            "TC1 failed"
+        );
+    }
+
+    #[tokio::test]
+    async fn reconcile_resolves_drift_using_venue_state() {
+        let portfolio = Mutex::new(repository(Some(1.0), 1000.0));
+        let venue = MockVenue {
+            balances: venue_balances(1.5, 900.0),
+            orders: vec![venue_order()],
+            cancelled: Mutex::new(Vec::new()),
+            cancel_fails: false,
+        };
+        let reconciler = reconciler(ReconcilePolicy {
+            positions: Resolution::Resolve,
+            balance: Resolution::Resolve,
+            orders: Resolution::Resolve,
+        });
+
+        let drifts = reconciler.reconcile(&portfolio, &venue).await.unwrap();
+        assert_eq!(drifts.len(), 3);
+
+        let market = market();
+        let position_id = determine_position_id(ENGINE_ID, &market.exchange, &market.instrument);
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.quantity, 1.5);
+
+        let balance = portfolio.lock().get_balance(ENGINE_ID).unwrap();
+        assert_eq!(balance.available, 900.0);
+        assert_eq!(balance.total, 1000.0);
+
+        assert_eq!(venue.cancelled.lock().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn reconcile_aborts_without_resolving_drift() {
+        let portfolio = Mutex::new(repository(Some(1.0), 1000.0));
+        let venue = MockVenue {
+            balances: venue_balances(1.5, 900.0),
+            orders: vec![venue_order()],
+            cancelled: Mutex::new(Vec::new()),
+            cancel_fails: false,
+        };
+        let reconciler = reconciler(ReconcilePolicy {
+            positions: Resolution::Resolve,
+            balance: Resolution::Abort,
+            orders: Resolution::Resolve,
+        });
+
+        let actual = reconciler.reconcile(&portfolio, &venue).await;
+        assert!(matches!(
+            actual,
+            Err(PortfolioError::ReconcileAborted(drifts))
+                if matches!(drifts.as_slice(), [Drift::CashMismatch { .. }])
+        ));
+
+        let balance = portfolio.lock().get_balance(ENGINE_ID).unwrap();
+        assert_eq!(balance.available, 1000.0);
+        assert!(venue.cancelled.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reconcile_leaves_repository_unchanged_when_cancels_fail() {
+        let portfolio = Mutex::new(repository(Some(1.0), 1000.0));
+        let venue = MockVenue {
+            balances: venue_balances(1.5, 900.0),
+            orders: vec![venue_order()],
+            cancelled: Mutex::new(Vec::new()),
+            cancel_fails: true,
+        };
+        let reconciler = reconciler(ReconcilePolicy {
+            positions: Resolution::Resolve,
+            balance: Resolution::Resolve,
+            orders: Resolution::Resolve,
+        });
+
+        let actual = reconciler.reconcile(&portfolio, &venue).await;
+        assert!(matches!(actual, Err(PortfolioError::VenueInteraction(_))));
+
+        let market = market();
+        let position_id = determine_position_id(ENGINE_ID, &market.exchange, &market.instrument);
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.quantity, 1.0);
+
+        let balance = portfolio.lock().get_balance(ENGINE_ID).unwrap();
+        assert_eq!(balance.available, 1000.0);
+    }
+}
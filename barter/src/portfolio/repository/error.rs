@@ -1,4 +1,5 @@
 use thiserror::Error;
+use uuid::Uuid;
 
 /// All errors generated in the barter::portfolio::repository module.
 #[derive(Error, Debug)]
@@ -15,6 +16,15 @@ pub enum RepositoryError {
     #[error("Failed to delete data from the repository")]
     DeleteError,
 
+    #[error("Invalid repository transaction: {0}")]
+    TransactionError(&'static str),
+
+    #[error("SQL database error: {0}")]
+    SqlError(String),
+
     #[error("Failed to retrieve expected data due to it not being present")]
     ExpectedDataNotPresentError,
+
+    #[error("engine_id {actual} does not match the Portfolio engine_id {expected}")]
+    EngineIdMismatch { expected: Uuid, actual: Uuid },
 }
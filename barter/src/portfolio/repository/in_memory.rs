@@ -1,35 +1,56 @@
 use crate::{
+    execution::ClientOrderId,
     portfolio::{
+        ledger::{ExchangeAsset, ExchangeAssetBalance},
         position::{determine_position_id, Position, PositionId},
         repository::{
-            determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            determine_asset_balances_id, determine_exited_positions_id,
+            determine_in_flight_orders_id, error::RepositoryError, AssetBalancesId, BalanceHandler,
+            InFlightOrdersId, OrderHandler, PositionHandler, StatisticHandler, TransactionHandler,
         },
-        Balance, BalanceId,
+        Balance, BalanceId, InFlightOrder,
     },
     statistic::summary::PositionSummariser,
 };
+use barter_execution::model::balance::Balance as ExchangeBalance;
 use barter_instrument::market::{Market, MarketId};
 use smol_str::SmolStr;
 use std::collections::HashMap;
 use uuid::Uuid;
 
-/// In-Memory repository for Proof Of Concepts. Implements [`PositionHandler`], [`BalanceHandler`]
-/// & [`StatisticHandler`]. Used by a Proof Of Concept Portfolio implementation to
+/// In-Memory repository for Proof Of Concepts. Implements [`PositionHandler`], [`BalanceHandler`],
+/// [`OrderHandler`] & [`StatisticHandler`]. Used by a Proof Of Concept Portfolio implementation to
 /// save the current equity, available cash, Positions, and market pair statistics.
+/// [`TransactionHandler`] rollbacks restore the state journaled since the transaction began.
 /// **Careful in production - no fault tolerant guarantees!**
 #[derive(Debug, Default)]
 pub struct InMemoryRepository<Statistic: PositionSummariser> {
     open_positions: HashMap<PositionId, Position>,
     closed_positions: HashMap<String, Vec<Position>>,
     current_balances: HashMap<BalanceId, Balance>,
+    asset_balances: HashMap<AssetBalancesId, HashMap<ExchangeAsset, ExchangeBalance>>,
+    in_flight_orders: HashMap<InFlightOrdersId, HashMap<ClientOrderId, InFlightOrder>>,
     statistics: HashMap<MarketId, Statistic>,
+    journal: Option<Vec<Undo<Statistic>>>,
+}
+
+/// Previous state overwritten by a write made within an [`InMemoryRepository`] transaction,
+/// restored in reverse order on rollback.
+#[derive(Debug)]
+enum Undo<Statistic> {
+    OpenPosition(PositionId, Option<Box<Position>>),
+    ExitedPosition(String),
+    Balance(BalanceId, Option<Balance>),
+    AssetBalance(AssetBalancesId, ExchangeAsset, Option<ExchangeBalance>),
+    InFlightOrder(InFlightOrdersId, ClientOrderId, Option<Box<InFlightOrder>>),
+    Statistic(MarketId, Option<Statistic>),
 }
 
 impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Statistic> {
     fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
-        self.open_positions
-            .insert(position.position_id.clone(), position);
+        let position_id = position.position_id.clone();
+        let previous = self.open_positions.insert(position_id.clone(), position);
+        self.record(Undo::OpenPosition(position_id, previous.map(Box::new)));
         Ok(())
     }
 
@@ -62,7 +83,14 @@ impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Stati
         &mut self,
         position_id: &SmolStr,
     ) -> Result<Option<Position>, RepositoryError> {
-        Ok(self.open_positions.remove(position_id))
+        let removed = self.open_positions.remove(position_id);
+        if let Some(position) = removed.as_ref().filter(|_| self.journal.is_some()) {
+            self.record(Undo::OpenPosition(
+                position_id.clone(),
+                Some(Box::new(position.clone())),
+            ));
+        }
+        Ok(removed)
     }
 
     fn set_exited_position(
@@ -75,10 +103,11 @@ impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Stati
         match self.closed_positions.get_mut(&exited_positions_key) {
             None => {
                 self.closed_positions
-                    .insert(exited_positions_key, vec![position]);
+                    .insert(exited_positions_key.clone(), vec![position]);
             }
             Some(closed_positions) => closed_positions.push(position),
         }
+        self.record(Undo::ExitedPosition(exited_positions_key));
         Ok(())
     }
 
@@ -93,8 +122,9 @@ impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Stati
 
 impl<Statistic: PositionSummariser> BalanceHandler for InMemoryRepository<Statistic> {
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
-        self.current_balances
-            .insert(Balance::balance_id(engine_id), balance);
+        let balance_id = Balance::balance_id(engine_id);
+        let previous = self.current_balances.insert(balance_id.clone(), balance);
+        self.record(Undo::Balance(balance_id, previous));
         Ok(())
     }
 
@@ -104,6 +134,94 @@ impl<Statistic: PositionSummariser> BalanceHandler for InMemoryRepository<Statis
             .copied()
             .ok_or(RepositoryError::ExpectedDataNotPresentError)
     }
+
+    fn set_asset_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: ExchangeAssetBalance,
+    ) -> Result<(), RepositoryError> {
+        let asset_balances_id = determine_asset_balances_id(engine_id);
+        let previous = self
+            .asset_balances
+            .entry(asset_balances_id.clone())
+            .or_default()
+            .insert(balance.asset.clone(), balance.balance);
+        self.record(Undo::AssetBalance(
+            asset_balances_id,
+            balance.asset,
+            previous,
+        ));
+        Ok(())
+    }
+
+    fn get_asset_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<ExchangeAssetBalance>, RepositoryError> {
+        Ok(self
+            .asset_balances
+            .get(&determine_asset_balances_id(engine_id))
+            .map(|balances| {
+                balances
+                    .iter()
+                    .map(|(asset, balance)| ExchangeAssetBalance::new(asset.clone(), *balance))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+impl<Statistic: PositionSummariser> OrderHandler for InMemoryRepository<Statistic> {
+    fn set_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        order: InFlightOrder,
+    ) -> Result<(), RepositoryError> {
+        let in_flight_orders_id = determine_in_flight_orders_id(engine_id);
+        let cid = order.order.cid;
+        let previous = self
+            .in_flight_orders
+            .entry(in_flight_orders_id.clone())
+            .or_default()
+            .insert(cid, order);
+        self.record(Undo::InFlightOrder(
+            in_flight_orders_id,
+            cid,
+            previous.map(Box::new),
+        ));
+        Ok(())
+    }
+
+    fn remove_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        cid: &ClientOrderId,
+    ) -> Result<Option<InFlightOrder>, RepositoryError> {
+        let in_flight_orders_id = determine_in_flight_orders_id(engine_id);
+        let removed = self
+            .in_flight_orders
+            .get_mut(&in_flight_orders_id)
+            .and_then(|orders| orders.remove(cid));
+        if let Some(order) = removed.as_ref().filter(|_| self.journal.is_some()) {
+            self.record(Undo::InFlightOrder(
+                in_flight_orders_id,
+                *cid,
+                Some(Box::new(order.clone())),
+            ));
+        }
+        Ok(removed)
+    }
+
+    fn get_in_flight_orders(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<InFlightOrder>, RepositoryError> {
+        Ok(self
+            .in_flight_orders
+            .get(&determine_in_flight_orders_id(engine_id))
+            .map(|orders| orders.values().cloned().collect())
+            .unwrap_or_default())
+    }
 }
 
 impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepository<Statistic> {
@@ -112,7 +230,8 @@ impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepo
         market_id: MarketId,
         statistic: Statistic,
     ) -> Result<(), RepositoryError> {
-        self.statistics.insert(market_id, statistic);
+        let previous = self.statistics.insert(market_id.clone(), statistic);
+        self.record(Undo::Statistic(market_id, previous));
         Ok(())
     }
 
@@ -124,6 +243,75 @@ impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepo
     }
 }
 
+impl<Statistic: PositionSummariser> TransactionHandler for InMemoryRepository<Statistic> {
+    fn begin(&mut self) -> Result<(), RepositoryError> {
+        match self.journal {
+            Some(_) => Err(RepositoryError::TransactionError(
+                "transaction already in progress",
+            )),
+            None => {
+                self.journal = Some(Vec::new());
+                Ok(())
+            }
+        }
+    }
+
+    fn commit(&mut self) -> Result<(), RepositoryError> {
+        self.journal
+            .take()
+            .map(|_| ())
+            .ok_or(RepositoryError::TransactionError(
+                "no transaction in progress",
+            ))
+    }
+
+    fn rollback(&mut self) -> Result<(), RepositoryError> {
+        let journal = self
+            .journal
+            .take()
+            .ok_or(RepositoryError::TransactionError(
+                "no transaction in progress",
+            ))?;
+
+        for undo in journal.into_iter().rev() {
+            match undo {
+                Undo::OpenPosition(position_id, previous) => restore(
+                    &mut self.open_positions,
+                    position_id,
+                    previous.map(|position| *position),
+                ),
+                Undo::ExitedPosition(exited_positions_key) => {
+                    if let Some(closed_positions) =
+                        self.closed_positions.get_mut(&exited_positions_key)
+                    {
+                        closed_positions.pop();
+                    }
+                }
+                Undo::Balance(balance_id, previous) => {
+                    restore(&mut self.current_balances, balance_id, previous)
+                }
+                Undo::AssetBalance(asset_balances_id, asset, previous) => restore(
+                    self.asset_balances.entry(asset_balances_id).or_default(),
+                    asset,
+                    previous,
+                ),
+                Undo::InFlightOrder(in_flight_orders_id, cid, previous) => restore(
+                    self.in_flight_orders
+                        .entry(in_flight_orders_id)
+                        .or_default(),
+                    cid,
+                    previous.map(|order| *order),
+                ),
+                Undo::Statistic(market_id, previous) => {
+                    restore(&mut self.statistics, market_id, previous)
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
     /// Constructs a new [`InMemoryRepository`] component.
     pub fn new() -> Self {
@@ -131,7 +319,108 @@ impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
             open_positions: HashMap::new(),
             closed_positions: HashMap::new(),
             current_balances: HashMap::new(),
+            asset_balances: HashMap::new(),
+            in_flight_orders: HashMap::new(),
             statistics: HashMap::new(),
+            journal: None,
+        }
+    }
+
+    /// Journal the previous state overwritten by a write, if a transaction is in progress.
+    fn record(&mut self, undo: Undo<Statistic>) {
+        if let Some(journal) = self.journal.as_mut() {
+            journal.push(undo);
         }
     }
 }
+
+/// Restore the previous value of a key, removing the key if it was previously absent.
+fn restore<Key, Value>(map: &mut HashMap<Key, Value>, key: Key, previous: Option<Value>)
+where
+    Key: std::hash::Hash + Eq,
+{
+    match previous {
+        Some(previous) => map.insert(key, previous),
+        None => map.remove(&key),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{statistic::summary::pnl::PnLReturnSummary, test_util};
+    use barter_instrument::exchange::ExchangeId;
+    use chrono::Utc;
+
+    #[test]
+    fn test_in_memory_repository_rollback_restores_journaled_state() {
+        let mut repository = InMemoryRepository::<PnLReturnSummary>::new();
+        let engine_id = Uuid::new_v4();
+        let position = test_util::position();
+        let balance = Balance::new(Utc::now(), 100.0, 100.0);
+        let usdt = ExchangeAssetBalance::new(
+            ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt"),
+            ExchangeBalance::new(100.0, 100.0),
+        );
+
+        repository.set_open_position(position.clone()).unwrap();
+        repository.set_balance(engine_id, balance).unwrap();
+        repository
+            .set_asset_balance(engine_id, usdt.clone())
+            .unwrap();
+
+        // Writes within the transaction are visible until rolled back
+        repository.begin().unwrap();
+        repository.remove_position(&position.position_id).unwrap();
+        repository
+            .set_exited_position(engine_id, position.clone())
+            .unwrap();
+        repository
+            .set_balance(engine_id, Balance::new(Utc::now(), 150.0, 150.0))
+            .unwrap();
+        repository
+            .set_in_flight_order(engine_id, InFlightOrder::new(test_util::order_event()))
+            .unwrap();
+        repository
+            .set_asset_balance(
+                engine_id,
+                ExchangeAssetBalance::new(usdt.asset.clone(), ExchangeBalance::new(50.0, 50.0)),
+            )
+            .unwrap();
+        repository
+            .set_asset_balance(
+                engine_id,
+                ExchangeAssetBalance::new(
+                    ExchangeAsset::new(ExchangeId::BinanceSpot, "eth"),
+                    ExchangeBalance::new(1.0, 1.0),
+                ),
+            )
+            .unwrap();
+        assert_eq!(repository.get_balance(engine_id).unwrap().total, 150.0);
+        repository.rollback().unwrap();
+
+        assert_eq!(
+            repository.get_open_position(&position.position_id).unwrap(),
+            Some(position)
+        );
+        assert!(repository
+            .get_exited_positions(engine_id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(repository.get_balance(engine_id).unwrap(), balance);
+        assert!(repository
+            .get_in_flight_orders(engine_id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            repository.get_asset_balances(engine_id).unwrap(),
+            vec![usdt]
+        );
+
+        // Commit requires a transaction in progress
+        assert!(matches!(
+            repository.commit(),
+            Err(RepositoryError::TransactionError(_))
+        ));
+    }
+}
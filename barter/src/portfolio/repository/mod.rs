@@ -1,7 +1,11 @@
-use crate::portfolio::{
-    position::{Position, PositionId},
-    repository::error::RepositoryError,
-    Balance,
+use crate::{
+    execution::ClientOrderId,
+    portfolio::{
+        ledger::ExchangeAssetBalance,
+        position::{Position, PositionId},
+        repository::error::RepositoryError,
+        Balance, InFlightOrder,
+    },
 };
 use barter_instrument::market::{Market, MarketId};
 use uuid::Uuid;
@@ -15,6 +19,10 @@ pub mod in_memory;
 /// Redis repository for state keeping.
 pub mod redis;
 
+/// SQL repository for state keeping & post-trade analysis, backed by SQLite (`sqlite` feature) or
+/// Postgres (`postgres` feature) using the same schema.
+pub mod sql;
+
 /// Handles the reading & writing of a [`Position`] to/from the persistence layer.
 pub trait PositionHandler {
     /// Upsert the open [`Position`] using it's [`PositionId`].
@@ -50,12 +58,50 @@ pub trait PositionHandler {
     fn get_exited_positions(&mut self, engine_id: Uuid) -> Result<Vec<Position>, RepositoryError>;
 }
 
-/// Handles the reading & writing of a Portfolio's current balance to/from the persistence layer.
+/// Handles the reading & writing of a Portfolio's current balance, and the per-exchange,
+/// per-asset balances of it's [`BalanceLedger`](crate::portfolio::ledger::BalanceLedger),
+/// to/from the persistence layer.
 pub trait BalanceHandler {
     /// Upsert the Portfolio [`Balance`] at the engine_id.
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError>;
     /// Get the Portfolio [`Balance`] using the engine_id provided.
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError>;
+
+    /// Upsert the [`ExchangeAssetBalance`] of the engine_id using it's exchange & asset.
+    fn set_asset_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: ExchangeAssetBalance,
+    ) -> Result<(), RepositoryError>;
+    /// Get every [`ExchangeAssetBalance`] associated with the engine_id.
+    fn get_asset_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<ExchangeAssetBalance>, RepositoryError>;
+}
+
+/// Handles the reading & writing of a Portfolio's [`InFlightOrder`]s to/from the persistence
+/// layer, so they survive a restart.
+pub trait OrderHandler {
+    /// Upsert the [`InFlightOrder`] of the engine_id using it's [`ClientOrderId`].
+    fn set_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        order: InFlightOrder,
+    ) -> Result<(), RepositoryError>;
+
+    /// Remove the [`InFlightOrder`] of the engine_id at the [`ClientOrderId`].
+    fn remove_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        cid: &ClientOrderId,
+    ) -> Result<Option<InFlightOrder>, RepositoryError>;
+
+    /// Get every [`InFlightOrder`] associated with the engine_id.
+    fn get_in_flight_orders(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<InFlightOrder>, RepositoryError>;
 }
 
 /// Handles the reading & writing of a Portfolio's statistics for each of it's
@@ -71,6 +117,37 @@ pub trait StatisticHandler<Statistic> {
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError>;
 }
 
+/// Groups the writes of a [`PositionHandler`], [`BalanceHandler`], [`OrderHandler`] &
+/// [`StatisticHandler`] into an all-or-nothing unit of work. Reads made within a transaction observe it's uncommitted writes.
+pub trait TransactionHandler {
+    /// Begin a transaction, buffering or journaling every subsequent write until it is committed
+    /// or rolled back.
+    fn begin(&mut self) -> Result<(), RepositoryError>;
+
+    /// Atomically persist every write made since the transaction began.
+    fn commit(&mut self) -> Result<(), RepositoryError>;
+
+    /// Discard every write made since the transaction began.
+    fn rollback(&mut self) -> Result<(), RepositoryError>;
+}
+
+/// Communicates a String represents a unique identifier for all a Portfolio's [`InFlightOrder`]s.
+pub type InFlightOrdersId = String;
+
+/// Returns the unique identifier for a Portfolio's [`InFlightOrder`]s, given an engine_id.
+pub fn determine_in_flight_orders_id(engine_id: Uuid) -> InFlightOrdersId {
+    format!("orders_in_flight_{}", engine_id)
+}
+
+/// Communicates a String represents a unique identifier for all a Portfolio's
+/// [`ExchangeAssetBalance`]s.
+pub type AssetBalancesId = String;
+
+/// Returns the unique identifier for a Portfolio's [`ExchangeAssetBalance`]s, given an engine_id.
+pub fn determine_asset_balances_id(engine_id: Uuid) -> AssetBalancesId {
+    format!("asset_balances_{}", engine_id)
+}
+
 /// Communicates a String represents a unique identifier for all a Portfolio's exited [`Position`]s.
 /// Used to append new exited [`Position`]s to the entry in the [`PositionHandler`].
 pub type ExitedPositionsId = String;
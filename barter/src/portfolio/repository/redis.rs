@@ -1,20 +1,24 @@
 use crate::{
+    execution::ClientOrderId,
     portfolio::{
         error::PortfolioError,
+        ledger::ExchangeAssetBalance,
         position::{determine_position_id, Position, PositionId},
         repository::{
-            determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            determine_asset_balances_id, determine_exited_positions_id,
+            determine_in_flight_orders_id, error::RepositoryError, BalanceHandler, OrderHandler,
+            PositionHandler, StatisticHandler, TransactionHandler,
         },
-        Balance,
+        Balance, InFlightOrder,
     },
     statistic::summary::PositionSummariser,
 };
 use barter_instrument::market::{Market, MarketId};
-use redis::{Commands, Connection, ErrorKind};
+use redis::{Commands, Connection, ErrorKind, Pipeline};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use smol_str::SmolStr;
 use std::{
+    collections::HashMap,
     fmt::{Debug, Formatter},
     marker::PhantomData,
 };
@@ -27,16 +31,30 @@ pub struct Config {
 }
 
 /// Redis persisted repository that implements [`PositionHandler`], [`BalanceHandler`],
-/// & [`PositionSummariser`]. Used by a Portfolio implementation to persist the Portfolio state,
-/// including total equity, available cash & Positions.
+/// [`OrderHandler`] & [`PositionSummariser`]. Used by a Portfolio implementation to persist the
+/// Portfolio state, including total equity, available cash, Positions & in-flight orders.
+///
+/// [`TransactionHandler`] writes are buffered client side & executed atomically via MULTI/EXEC on
+/// commit.
 pub struct RedisRepository<Statistic>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
 {
     conn: Connection,
+    transaction: Option<Transaction>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
+/// Writes buffered by an in progress [`RedisRepository`] transaction, executed atomically via
+/// MULTI/EXEC on commit.
+struct Transaction {
+    pipeline: Pipeline,
+    /// Pending value of every key written, `None` if the key is deleted.
+    writes: HashMap<String, Option<String>>,
+    /// Pending values pushed to every list, in push order.
+    pushes: HashMap<String, Vec<String>>,
+}
+
 impl<Statistic> PositionHandler for RedisRepository<Statistic>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
@@ -44,19 +62,14 @@ where
     fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
         let position_string = serde_json::to_string(&position)?;
 
-        self.conn
-            .set(position.position_id.as_str(), position_string)
-            .map_err(|_| RepositoryError::WriteError)
+        self.set(position.position_id.to_string(), position_string)
     }
 
     fn get_open_position(
         &mut self,
         position_id: &PositionId,
     ) -> Result<Option<Position>, RepositoryError> {
-        let position_value: String = self
-            .conn
-            .get(position_id.as_str())
-            .map_err(|_| RepositoryError::ReadError)?;
+        let position_value = self.get(position_id.as_str())?;
 
         Ok(Some(serde_json::from_str::<Position>(&position_value)?))
     }
@@ -84,9 +97,7 @@ where
     ) -> Result<Option<Position>, RepositoryError> {
         let position = self.get_open_position(position_id)?;
 
-        self.conn
-            .del(position_id.as_str())
-            .map_err(|_| RepositoryError::DeleteError)?;
+        self.del(position_id.to_string())?;
 
         Ok(position)
     }
@@ -96,21 +107,34 @@ where
         engine_id: Uuid,
         position: Position,
     ) -> Result<(), RepositoryError> {
-        self.conn
-            .lpush(
-                determine_exited_positions_id(engine_id),
-                serde_json::to_string(&position)?,
-            )
-            .map_err(|_| RepositoryError::WriteError)
+        self.lpush(
+            determine_exited_positions_id(engine_id),
+            serde_json::to_string(&position)?,
+        )
     }
 
     fn get_exited_positions(&mut self, engine_id: Uuid) -> Result<Vec<Position>, RepositoryError> {
-        self.conn
-            .get(determine_exited_positions_id(engine_id))
+        let exited_positions_id = determine_exited_positions_id(engine_id);
+
+        let persisted = self
+            .conn
+            .get(&exited_positions_id)
             .or_else(|err| match err.kind() {
                 ErrorKind::TypeError => Ok(Vec::<String>::new()),
                 _ => Err(RepositoryError::ReadError),
-            })?
+            })?;
+
+        // Pending pushes precede the persisted list, since they are pushed to the head
+        let pending = self
+            .transaction
+            .as_ref()
+            .and_then(|transaction| transaction.pushes.get(&exited_positions_id))
+            .into_iter()
+            .flat_map(|pushes| pushes.iter().rev().cloned());
+
+        pending
+            .chain(persisted)
+            .collect::<Vec<String>>()
             .iter()
             .map(|position| serde_json::from_str::<Position>(position))
             .collect::<Result<Vec<Position>, serde_json::Error>>()
@@ -125,19 +149,101 @@ where
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
         let balance_string = serde_json::to_string(&balance)?;
 
-        self.conn
-            .set(Balance::balance_id(engine_id), balance_string)
-            .map_err(|_| RepositoryError::WriteError)
+        self.set(Balance::balance_id(engine_id), balance_string)
     }
 
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
-        let balance_value: String = self
-            .conn
-            .get(Balance::balance_id(engine_id))
-            .map_err(|_| RepositoryError::ReadError)?;
+        let balance_value = self.get(&Balance::balance_id(engine_id))?;
 
         Ok(serde_json::from_str::<Balance>(&balance_value)?)
     }
+
+    fn set_asset_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: ExchangeAssetBalance,
+    ) -> Result<(), RepositoryError> {
+        let mut balances = self.get_asset_balances(engine_id)?;
+        match balances
+            .iter_mut()
+            .find(|existing| existing.asset == balance.asset)
+        {
+            Some(existing) => *existing = balance,
+            None => balances.push(balance),
+        }
+
+        self.set(
+            determine_asset_balances_id(engine_id),
+            serde_json::to_string(&balances)?,
+        )
+    }
+
+    fn get_asset_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<ExchangeAssetBalance>, RepositoryError> {
+        match self.get_optional(&determine_asset_balances_id(engine_id))? {
+            Some(balances) => Ok(serde_json::from_str(&balances)?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+impl<Statistic> OrderHandler for RedisRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        order: InFlightOrder,
+    ) -> Result<(), RepositoryError> {
+        let mut orders = self.get_in_flight_orders(engine_id)?;
+        match orders
+            .iter_mut()
+            .find(|in_flight| in_flight.order.cid == order.order.cid)
+        {
+            Some(in_flight) => *in_flight = order,
+            None => orders.push(order),
+        }
+
+        self.set(
+            determine_in_flight_orders_id(engine_id),
+            serde_json::to_string(&orders)?,
+        )
+    }
+
+    fn remove_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        cid: &ClientOrderId,
+    ) -> Result<Option<InFlightOrder>, RepositoryError> {
+        let mut orders = self.get_in_flight_orders(engine_id)?;
+        let Some(index) = orders
+            .iter()
+            .position(|in_flight| in_flight.order.cid == *cid)
+        else {
+            return Ok(None);
+        };
+        let removed = orders.remove(index);
+
+        self.set(
+            determine_in_flight_orders_id(engine_id),
+            serde_json::to_string(&orders)?,
+        )?;
+
+        Ok(Some(removed))
+    }
+
+    fn get_in_flight_orders(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<InFlightOrder>, RepositoryError> {
+        match self.get_optional(&determine_in_flight_orders_id(engine_id))? {
+            Some(orders) => Ok(serde_json::from_str(&orders)?),
+            None => Ok(Vec::new()),
+        }
+    }
 }
 
 impl<Statistic> StatisticHandler<Statistic> for RedisRepository<Statistic>
@@ -149,21 +255,63 @@ where
         market_id: MarketId,
         statistic: Statistic,
     ) -> Result<(), RepositoryError> {
-        self.conn
-            .set(market_id.0.as_str(), serde_json::to_string(&statistic)?)
-            .map_err(|_| RepositoryError::WriteError)
+        self.set(market_id.0.to_string(), serde_json::to_string(&statistic)?)
     }
 
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
-        let statistics: String = self
-            .conn
-            .get(market_id.0.as_str())
-            .map_err(|_| RepositoryError::ReadError)?;
+        let statistics = self.get(market_id.0.as_str())?;
 
         serde_json::from_str(&statistics).map_err(RepositoryError::JsonSerDeError)
     }
 }
 
+impl<Statistic> TransactionHandler for RedisRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn begin(&mut self) -> Result<(), RepositoryError> {
+        if self.transaction.is_some() {
+            return Err(RepositoryError::TransactionError(
+                "transaction already in progress",
+            ));
+        }
+
+        let mut pipeline = redis::pipe();
+        pipeline.atomic();
+
+        self.transaction = Some(Transaction {
+            pipeline,
+            writes: HashMap::new(),
+            pushes: HashMap::new(),
+        });
+
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), RepositoryError> {
+        let transaction = self
+            .transaction
+            .take()
+            .ok_or(RepositoryError::TransactionError(
+                "no transaction in progress",
+            ))?;
+
+        transaction
+            .pipeline
+            .query::<()>(&mut self.conn)
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn rollback(&mut self) -> Result<(), RepositoryError> {
+        self.transaction
+            .take()
+            .map(|_| ())
+            .ok_or(RepositoryError::TransactionError(
+                "no transaction in progress",
+            ))
+    }
+}
+
 impl<Statistic: PositionSummariser> Debug for RedisRepository<Statistic>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
@@ -181,10 +329,75 @@ where
     pub fn new(connection: Connection) -> Self {
         Self {
             conn: connection,
+            transaction: None,
             _statistic_marker: PhantomData,
         }
     }
 
+    /// Get the value at the provided key, observing the writes of any in progress transaction.
+    fn get(&mut self, key: &str) -> Result<String, RepositoryError> {
+        self.get_optional(key)?.ok_or(RepositoryError::ReadError)
+    }
+
+    /// Get the value at the provided key if it exists, observing the writes of any in progress
+    /// transaction.
+    fn get_optional(&mut self, key: &str) -> Result<Option<String>, RepositoryError> {
+        match self
+            .transaction
+            .as_ref()
+            .and_then(|transaction| transaction.writes.get(key))
+        {
+            Some(value) => Ok(value.clone()),
+            None => self.conn.get(key).map_err(|_| RepositoryError::ReadError),
+        }
+    }
+
+    /// Set the value at the provided key, or buffer the write if a transaction is in progress.
+    fn set(&mut self, key: String, value: String) -> Result<(), RepositoryError> {
+        match self.transaction.as_mut() {
+            Some(transaction) => {
+                transaction.pipeline.set(&key, &value).ignore();
+                transaction.writes.insert(key, Some(value));
+                Ok(())
+            }
+            None => self
+                .conn
+                .set(key, value)
+                .map_err(|_| RepositoryError::WriteError),
+        }
+    }
+
+    /// Delete the provided key, or buffer the delete if a transaction is in progress.
+    fn del(&mut self, key: String) -> Result<(), RepositoryError> {
+        match self.transaction.as_mut() {
+            Some(transaction) => {
+                transaction.pipeline.del(&key).ignore();
+                transaction.writes.insert(key, None);
+                Ok(())
+            }
+            None => self
+                .conn
+                .del::<_, ()>(key)
+                .map_err(|_| RepositoryError::DeleteError),
+        }
+    }
+
+    /// Push the value to the head of the list at the provided key, or buffer the push if a
+    /// transaction is in progress.
+    fn lpush(&mut self, key: String, value: String) -> Result<(), RepositoryError> {
+        match self.transaction.as_mut() {
+            Some(transaction) => {
+                transaction.pipeline.lpush(&key, &value).ignore();
+                transaction.pushes.entry(key).or_default().push(value);
+                Ok(())
+            }
+            None => self
+                .conn
+                .lpush(key, value)
+                .map_err(|_| RepositoryError::WriteError),
+        }
+    }
+
     /// Returns a [`RedisRepositoryBuilder`] instance.
     pub fn builder() -> RedisRepositoryBuilder<Statistic> {
         RedisRepositoryBuilder::new()
@@ -230,6 +443,7 @@ where
     pub fn build(self) -> Result<RedisRepository<Statistic>, PortfolioError> {
         Ok(RedisRepository {
             conn: self.conn.ok_or(PortfolioError::BuilderIncomplete("conn"))?,
+            transaction: None,
             _statistic_marker: PhantomData,
         })
     }
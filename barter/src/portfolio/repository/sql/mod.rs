@@ -0,0 +1,710 @@
+use crate::{
+    execution::ClientOrderId,
+    portfolio::{
+        ledger::ExchangeAssetBalance,
+        position::{determine_position_id, Position, PositionId},
+        repository::{
+            error::RepositoryError, BalanceHandler, OrderHandler, PositionHandler,
+            StatisticHandler, TransactionHandler,
+        },
+        Balance, InFlightOrder,
+    },
+    statistic::summary::PositionSummariser,
+};
+use barter_instrument::market::{Market, MarketId};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+};
+use uuid::Uuid;
+
+/// [`SqlConnection`] implementation for a SQLite database, suitable for local & backtest use.
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+/// [`SqlConnection`] implementation for a Postgres database.
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+/// Ordered schema [`Migration`]s applied by [`SqlRepository::migrate`].
+///
+/// Every timestamp column holds nanoseconds since the Unix epoch, and every exited or open
+/// [`Position`] row includes the full JSON [`Position`] alongside the columns used for querying.
+///
+/// [`Balance`] rows are keyed by a per-engine sequence number assigned on insert, so the latest
+/// [`Balance`] is the last one set, even if it's time is not later than the previous one.
+///
+/// Market statistics are keyed by engine_id & [`MarketId`], so engines sharing a database do
+/// not overwrite each other's statistics.
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    sql: "
+            CREATE TABLE open_positions (
+                position_id TEXT PRIMARY KEY,
+                exchange TEXT NOT NULL,
+                instrument TEXT NOT NULL,
+                side TEXT NOT NULL,
+                quantity DOUBLE PRECISION NOT NULL,
+                enter_time BIGINT NOT NULL,
+                update_time BIGINT NOT NULL,
+                enter_avg_price_gross DOUBLE PRECISION NOT NULL,
+                current_price DOUBLE PRECISION NOT NULL,
+                unrealised_profit_loss DOUBLE PRECISION NOT NULL,
+                position TEXT NOT NULL
+            );
+
+            CREATE TABLE exited_positions (
+                engine_id TEXT NOT NULL,
+                position_id TEXT NOT NULL,
+                exchange TEXT NOT NULL,
+                instrument TEXT NOT NULL,
+                side TEXT NOT NULL,
+                quantity DOUBLE PRECISION NOT NULL,
+                enter_time BIGINT NOT NULL,
+                exit_time BIGINT NOT NULL,
+                enter_avg_price_gross DOUBLE PRECISION NOT NULL,
+                exit_avg_price_gross DOUBLE PRECISION NOT NULL,
+                fees DOUBLE PRECISION NOT NULL,
+                realised_profit_loss DOUBLE PRECISION NOT NULL,
+                position TEXT NOT NULL,
+                PRIMARY KEY (engine_id, position_id, exit_time)
+            );
+
+            CREATE INDEX exited_positions_exit_time ON exited_positions (engine_id, exit_time);
+
+            CREATE TABLE balances (
+                engine_id TEXT NOT NULL,
+                seq BIGINT NOT NULL,
+                time BIGINT NOT NULL,
+                total DOUBLE PRECISION NOT NULL,
+                available DOUBLE PRECISION NOT NULL,
+                PRIMARY KEY (engine_id, seq)
+            );
+
+            CREATE INDEX balances_time ON balances (engine_id, time);
+
+            CREATE TABLE asset_balances (
+                engine_id TEXT NOT NULL,
+                exchange TEXT NOT NULL,
+                asset TEXT NOT NULL,
+                total DOUBLE PRECISION NOT NULL,
+                available DOUBLE PRECISION NOT NULL,
+                asset_balance TEXT NOT NULL,
+                PRIMARY KEY (engine_id, exchange, asset)
+            );
+
+            CREATE TABLE in_flight_orders (
+                engine_id TEXT NOT NULL,
+                cid TEXT NOT NULL,
+                exchange TEXT NOT NULL,
+                instrument TEXT NOT NULL,
+                in_flight_order TEXT NOT NULL,
+                PRIMARY KEY (engine_id, cid)
+            );
+
+            CREATE TABLE statistics (
+                engine_id TEXT NOT NULL,
+                market_id TEXT NOT NULL,
+                statistic TEXT NOT NULL,
+                PRIMARY KEY (engine_id, market_id)
+            );
+        ",
+}];
+
+/// Versioned schema change applied once to a SQL database.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Migration {
+    pub version: i64,
+    pub sql: &'static str,
+}
+
+/// Value bound to, or read from, a SQL statement.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SqlValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+
+/// Row of [`SqlValue`]s returned by a query, in the order the columns were selected.
+pub type SqlRow = Vec<SqlValue>;
+
+/// Connection to a SQL database used by a [`SqlRepository`].
+///
+/// Statements use the `$1..$N` parameter syntax shared by SQLite & Postgres.
+pub trait SqlConnection {
+    /// Execute every `;` separated statement of the provided SQL, without parameters.
+    fn execute_batch(&mut self, sql: &str) -> Result<(), RepositoryError>;
+
+    /// Execute a single statement, returning the number of affected rows.
+    fn execute(&mut self, sql: &str, params: &[SqlValue]) -> Result<u64, RepositoryError>;
+
+    /// Execute a single query, returning every [`SqlRow`].
+    fn query(&mut self, sql: &str, params: &[SqlValue]) -> Result<Vec<SqlRow>, RepositoryError>;
+}
+
+/// Realised performance of a market, aggregated from the exited [`Position`]s of an engine.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarketPerformance {
+    pub exchange: String,
+    pub instrument: String,
+    pub trades: u64,
+    pub wins: u64,
+    pub fees: f64,
+    pub realised_profit_loss: f64,
+}
+
+/// SQL persisted repository that implements [`PositionHandler`], [`BalanceHandler`],
+/// [`OrderHandler`], [`StatisticHandler`] & [`TransactionHandler`], backed by any
+/// [`SqlConnection`] (eg/ SQLite or Postgres). Transactions are native database transactions.
+///
+/// Unlike the [`RedisRepository`](super::redis::RedisRepository), every [`Balance`] is kept
+/// as a time series, and exited [`Position`]s are stored in a queryable table, supporting
+/// post-trade analysis via the query helpers (eg/ [`Self::market_performance`]).
+pub struct SqlRepository<Conn, Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    conn: Conn,
+    engine_id: Uuid,
+    _statistic_marker: PhantomData<Statistic>,
+}
+
+impl<Conn, Statistic> SqlRepository<Conn, Statistic>
+where
+    Conn: SqlConnection,
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    /// Constructs a new [`SqlRepository`] using the provided [`SqlConnection`], applying any
+    /// outstanding schema [`MIGRATIONS`].
+    ///
+    /// The [`StatisticHandler`] methods are only keyed by [`MarketId`], so market statistics
+    /// are scoped to the provided engine_id.
+    pub fn new(conn: Conn, engine_id: Uuid) -> Result<Self, RepositoryError> {
+        let mut repository = Self {
+            conn,
+            engine_id,
+            _statistic_marker: PhantomData,
+        };
+        repository.migrate()?;
+        Ok(repository)
+    }
+
+    /// Apply every schema [`Migration`] that has not yet been applied, each in it's own
+    /// transaction. Returns the latest applied schema version.
+    pub fn migrate(&mut self) -> Result<i64, RepositoryError> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (version BIGINT PRIMARY KEY);",
+        )?;
+
+        let applied = self
+            .conn
+            .query("SELECT MAX(version) FROM schema_migrations", &[])?
+            .first()
+            .and_then(|row| row.first())
+            .map(integer_or_null)
+            .transpose()?
+            .flatten()
+            .unwrap_or_default();
+
+        let mut current = applied;
+        for migration in MIGRATIONS.iter().filter(|m| m.version > applied) {
+            let applied = self.conn.execute_batch(&format!(
+                "BEGIN;\n{}\nINSERT INTO schema_migrations (version) VALUES ({});\nCOMMIT;",
+                migration.sql, migration.version
+            ));
+
+            if let Err(error) = applied {
+                // Discard the partially applied migration, the original error is more useful
+                let _ = self.conn.execute_batch("ROLLBACK;");
+                return Err(error);
+            }
+
+            current = migration.version;
+        }
+
+        Ok(current)
+    }
+
+    /// Get every exited [`Position`] of the engine_id that exited within the time range
+    /// `[start, end)`, ordered by exit time.
+    pub fn exited_positions_between(
+        &mut self,
+        engine_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Position>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT position FROM exited_positions \
+                 WHERE engine_id = $1 AND exit_time >= $2 AND exit_time < $3 \
+                 ORDER BY exit_time, enter_time",
+                &[
+                    SqlValue::Text(engine_id.to_string()),
+                    SqlValue::Integer(nanos(start)?),
+                    SqlValue::Integer(nanos(end)?),
+                ],
+            )?
+            .iter()
+            .map(|row| decode_json(column(row, 0)?))
+            .collect()
+    }
+
+    /// Get every [`Balance`] of the engine_id within the time range `[start, end)`, ordered by
+    /// time & then the order they were set in.
+    pub fn balance_history(
+        &mut self,
+        engine_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Balance>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT time, total, available FROM balances \
+                 WHERE engine_id = $1 AND time >= $2 AND time < $3 \
+                 ORDER BY time, seq",
+                &[
+                    SqlValue::Text(engine_id.to_string()),
+                    SqlValue::Integer(nanos(start)?),
+                    SqlValue::Integer(nanos(end)?),
+                ],
+            )?
+            .iter()
+            .map(decode_balance)
+            .collect()
+    }
+
+    /// Aggregate the exited [`Position`]s of the engine_id into the [`MarketPerformance`] of
+    /// each market, ordered by exchange & instrument.
+    pub fn market_performance(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<MarketPerformance>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT exchange, instrument, COUNT(*), \
+                 SUM(CASE WHEN realised_profit_loss > 0 THEN 1 ELSE 0 END), \
+                 SUM(fees), SUM(realised_profit_loss) \
+                 FROM exited_positions WHERE engine_id = $1 \
+                 GROUP BY exchange, instrument ORDER BY exchange, instrument",
+                &[SqlValue::Text(engine_id.to_string())],
+            )?
+            .iter()
+            .map(|row| {
+                Ok(MarketPerformance {
+                    exchange: text(column(row, 0)?)?.to_owned(),
+                    instrument: text(column(row, 1)?)?.to_owned(),
+                    trades: integer(column(row, 2)?)?.max(0) as u64,
+                    wins: integer(column(row, 3)?)?.max(0) as u64,
+                    fees: real(column(row, 4)?)?,
+                    realised_profit_loss: real(column(row, 5)?)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns a mutable reference to the underlying [`SqlConnection`], for custom queries.
+    pub fn connection(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+}
+
+impl<Conn, Statistic> PositionHandler for SqlRepository<Conn, Statistic>
+where
+    Conn: SqlConnection,
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO open_positions (position_id, exchange, instrument, side, quantity, \
+                 enter_time, update_time, enter_avg_price_gross, current_price, \
+                 unrealised_profit_loss, position) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) \
+                 ON CONFLICT (position_id) DO UPDATE SET \
+                 exchange = excluded.exchange, instrument = excluded.instrument, \
+                 side = excluded.side, quantity = excluded.quantity, \
+                 enter_time = excluded.enter_time, update_time = excluded.update_time, \
+                 enter_avg_price_gross = excluded.enter_avg_price_gross, \
+                 current_price = excluded.current_price, \
+                 unrealised_profit_loss = excluded.unrealised_profit_loss, \
+                 position = excluded.position",
+                &[
+                    SqlValue::Text(position.position_id.to_string()),
+                    SqlValue::Text(position.exchange.as_str().to_owned()),
+                    SqlValue::Text(position.instrument.to_string()),
+                    SqlValue::Text(position.side.to_string()),
+                    SqlValue::Real(position.quantity),
+                    SqlValue::Integer(nanos(position.meta.enter_time)?),
+                    SqlValue::Integer(nanos(position.meta.update_time)?),
+                    SqlValue::Real(position.enter_avg_price_gross),
+                    SqlValue::Real(position.current_price),
+                    SqlValue::Real(position.unrealised_profit_loss),
+                    SqlValue::Text(serde_json::to_string(&position)?),
+                ],
+            )
+            .map(|_| ())
+    }
+
+    fn get_open_position(
+        &mut self,
+        position_id: &PositionId,
+    ) -> Result<Option<Position>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT position FROM open_positions WHERE position_id = $1",
+                &[SqlValue::Text(position_id.to_string())],
+            )?
+            .first()
+            .map(|row| decode_json(column(row, 0)?))
+            .transpose()
+    }
+
+    fn get_open_positions<'a, Markets: Iterator<Item = &'a Market>>(
+        &mut self,
+        engine_id: Uuid,
+        markets: Markets,
+    ) -> Result<Vec<Position>, RepositoryError> {
+        markets
+            .filter_map(|market| {
+                self.get_open_position(&determine_position_id(
+                    engine_id,
+                    &market.exchange,
+                    &market.instrument,
+                ))
+                .transpose()
+            })
+            .collect()
+    }
+
+    fn remove_position(
+        &mut self,
+        position_id: &PositionId,
+    ) -> Result<Option<Position>, RepositoryError> {
+        let position = self.get_open_position(position_id)?;
+
+        self.conn.execute(
+            "DELETE FROM open_positions WHERE position_id = $1",
+            &[SqlValue::Text(position_id.to_string())],
+        )?;
+
+        Ok(position)
+    }
+
+    fn set_exited_position(
+        &mut self,
+        engine_id: Uuid,
+        position: Position,
+    ) -> Result<(), RepositoryError> {
+        let exit_time = position
+            .meta
+            .exit_balance
+            .map(|balance| balance.time)
+            .unwrap_or(position.meta.update_time);
+
+        self.conn
+            .execute(
+                "INSERT INTO exited_positions (engine_id, position_id, exchange, instrument, \
+                 side, quantity, enter_time, exit_time, enter_avg_price_gross, \
+                 exit_avg_price_gross, fees, realised_profit_loss, position) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
+                &[
+                    SqlValue::Text(engine_id.to_string()),
+                    SqlValue::Text(position.position_id.to_string()),
+                    SqlValue::Text(position.exchange.as_str().to_owned()),
+                    SqlValue::Text(position.instrument.to_string()),
+                    SqlValue::Text(position.side.to_string()),
+                    SqlValue::Real(position.quantity),
+                    SqlValue::Integer(nanos(position.meta.enter_time)?),
+                    SqlValue::Integer(nanos(exit_time)?),
+                    SqlValue::Real(position.enter_avg_price_gross),
+                    SqlValue::Real(position.exit_avg_price_gross),
+                    SqlValue::Real(position.enter_fees_total + position.exit_fees_total),
+                    SqlValue::Real(position.realised_profit_loss),
+                    SqlValue::Text(serde_json::to_string(&position)?),
+                ],
+            )
+            .map(|_| ())
+    }
+
+    fn get_exited_positions(&mut self, engine_id: Uuid) -> Result<Vec<Position>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT position FROM exited_positions WHERE engine_id = $1 \
+                 ORDER BY exit_time, enter_time",
+                &[SqlValue::Text(engine_id.to_string())],
+            )?
+            .iter()
+            .map(|row| decode_json(column(row, 0)?))
+            .collect()
+    }
+}
+
+impl<Conn, Statistic> BalanceHandler for SqlRepository<Conn, Statistic>
+where
+    Conn: SqlConnection,
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO balances (engine_id, seq, time, total, available) \
+                 SELECT $1, COALESCE(MAX(seq), 0) + 1, $2, $3, $4 \
+                 FROM balances WHERE engine_id = $1",
+                &[
+                    SqlValue::Text(engine_id.to_string()),
+                    SqlValue::Integer(nanos(balance.time)?),
+                    SqlValue::Real(balance.total),
+                    SqlValue::Real(balance.available),
+                ],
+            )
+            .map(|_| ())
+    }
+
+    fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT time, total, available FROM balances WHERE engine_id = $1 \
+                 ORDER BY seq DESC LIMIT 1",
+                &[SqlValue::Text(engine_id.to_string())],
+            )?
+            .first()
+            .map(decode_balance)
+            .unwrap_or(Err(RepositoryError::ExpectedDataNotPresentError))
+    }
+
+    fn set_asset_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: ExchangeAssetBalance,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO asset_balances (engine_id, exchange, asset, total, available, \
+                 asset_balance) VALUES ($1, $2, $3, $4, $5, $6) \
+                 ON CONFLICT (engine_id, exchange, asset) DO UPDATE SET \
+                 total = excluded.total, available = excluded.available, \
+                 asset_balance = excluded.asset_balance",
+                &[
+                    SqlValue::Text(engine_id.to_string()),
+                    SqlValue::Text(balance.asset.exchange.as_str().to_owned()),
+                    SqlValue::Text(balance.asset.asset.to_string()),
+                    SqlValue::Real(balance.balance.total),
+                    SqlValue::Real(balance.balance.available),
+                    SqlValue::Text(serde_json::to_string(&balance)?),
+                ],
+            )
+            .map(|_| ())
+    }
+
+    fn get_asset_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<ExchangeAssetBalance>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT asset_balance FROM asset_balances WHERE engine_id = $1 \
+                 ORDER BY exchange, asset",
+                &[SqlValue::Text(engine_id.to_string())],
+            )?
+            .iter()
+            .map(|row| decode_json(column(row, 0)?))
+            .collect()
+    }
+}
+
+impl<Conn, Statistic> OrderHandler for SqlRepository<Conn, Statistic>
+where
+    Conn: SqlConnection,
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        order: InFlightOrder,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO in_flight_orders (engine_id, cid, exchange, instrument, \
+                 in_flight_order) VALUES ($1, $2, $3, $4, $5) \
+                 ON CONFLICT (engine_id, cid) DO UPDATE SET \
+                 in_flight_order = excluded.in_flight_order",
+                &[
+                    SqlValue::Text(engine_id.to_string()),
+                    SqlValue::Text(order.order.cid.to_string()),
+                    SqlValue::Text(order.order.exchange.as_str().to_owned()),
+                    SqlValue::Text(order.order.instrument.to_string()),
+                    SqlValue::Text(serde_json::to_string(&order)?),
+                ],
+            )
+            .map(|_| ())
+    }
+
+    fn remove_in_flight_order(
+        &mut self,
+        engine_id: Uuid,
+        cid: &ClientOrderId,
+    ) -> Result<Option<InFlightOrder>, RepositoryError> {
+        let params = [
+            SqlValue::Text(engine_id.to_string()),
+            SqlValue::Text(cid.to_string()),
+        ];
+
+        let order = self
+            .conn
+            .query(
+                "SELECT in_flight_order FROM in_flight_orders WHERE engine_id = $1 AND cid = $2",
+                &params,
+            )?
+            .first()
+            .map(|row| decode_json(column(row, 0)?))
+            .transpose()?;
+
+        self.conn.execute(
+            "DELETE FROM in_flight_orders WHERE engine_id = $1 AND cid = $2",
+            &params,
+        )?;
+
+        Ok(order)
+    }
+
+    fn get_in_flight_orders(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<InFlightOrder>, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT in_flight_order FROM in_flight_orders WHERE engine_id = $1",
+                &[SqlValue::Text(engine_id.to_string())],
+            )?
+            .iter()
+            .map(|row| decode_json(column(row, 0)?))
+            .collect()
+    }
+}
+
+impl<Conn, Statistic> StatisticHandler<Statistic> for SqlRepository<Conn, Statistic>
+where
+    Conn: SqlConnection,
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_statistics(
+        &mut self,
+        market_id: MarketId,
+        statistic: Statistic,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO statistics (engine_id, market_id, statistic) VALUES ($1, $2, $3) \
+                 ON CONFLICT (engine_id, market_id) DO UPDATE SET statistic = excluded.statistic",
+                &[
+                    SqlValue::Text(self.engine_id.to_string()),
+                    SqlValue::Text(market_id.0.to_string()),
+                    SqlValue::Text(serde_json::to_string(&statistic)?),
+                ],
+            )
+            .map(|_| ())
+    }
+
+    fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
+        self.conn
+            .query(
+                "SELECT statistic FROM statistics WHERE engine_id = $1 AND market_id = $2",
+                &[
+                    SqlValue::Text(self.engine_id.to_string()),
+                    SqlValue::Text(market_id.0.to_string()),
+                ],
+            )?
+            .first()
+            .map(|row| decode_json(column(row, 0)?))
+            .unwrap_or(Err(RepositoryError::ExpectedDataNotPresentError))
+    }
+}
+
+impl<Conn, Statistic> TransactionHandler for SqlRepository<Conn, Statistic>
+where
+    Conn: SqlConnection,
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn begin(&mut self) -> Result<(), RepositoryError> {
+        self.conn.execute_batch("BEGIN;")
+    }
+
+    fn commit(&mut self) -> Result<(), RepositoryError> {
+        self.conn.execute_batch("COMMIT;")
+    }
+
+    fn rollback(&mut self) -> Result<(), RepositoryError> {
+        self.conn.execute_batch("ROLLBACK;")
+    }
+}
+
+impl<Conn, Statistic> Debug for SqlRepository<Conn, Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqlRepository").finish()
+    }
+}
+
+/// Nanoseconds since the Unix epoch of the provided time.
+fn nanos(time: DateTime<Utc>) -> Result<i64, RepositoryError> {
+    time.timestamp_nanos_opt()
+        .ok_or_else(|| RepositoryError::SqlError(format!("timestamp out of range: {time}")))
+}
+
+/// Get the [`SqlValue`] of the column at the provided index.
+fn column(row: &SqlRow, index: usize) -> Result<&SqlValue, RepositoryError> {
+    row.get(index)
+        .ok_or_else(|| RepositoryError::SqlError(format!("missing column at index {index}")))
+}
+
+fn text(value: &SqlValue) -> Result<&str, RepositoryError> {
+    match value {
+        SqlValue::Text(value) => Ok(value),
+        other => Err(unexpected("TEXT", other)),
+    }
+}
+
+fn integer(value: &SqlValue) -> Result<i64, RepositoryError> {
+    integer_or_null(value)?.ok_or_else(|| unexpected("INTEGER", value))
+}
+
+fn integer_or_null(value: &SqlValue) -> Result<Option<i64>, RepositoryError> {
+    match value {
+        SqlValue::Null => Ok(None),
+        SqlValue::Integer(value) => Ok(Some(*value)),
+        other => Err(unexpected("INTEGER", other)),
+    }
+}
+
+/// Decode a floating point [`SqlValue`]. Integers are accepted since SQLite may store integral
+/// floating point values as integers.
+fn real(value: &SqlValue) -> Result<f64, RepositoryError> {
+    match value {
+        SqlValue::Real(value) => Ok(*value),
+        SqlValue::Integer(value) => Ok(*value as f64),
+        other => Err(unexpected("REAL", other)),
+    }
+}
+
+fn unexpected(expected: &str, actual: &SqlValue) -> RepositoryError {
+    RepositoryError::SqlError(format!("expected {expected} column, found: {actual:?}"))
+}
+
+fn decode_json<T: DeserializeOwned>(value: &SqlValue) -> Result<T, RepositoryError> {
+    serde_json::from_str(text(value)?).map_err(RepositoryError::JsonSerDeError)
+}
+
+/// Decode a [`Balance`] from a `time, total, available` [`SqlRow`].
+fn decode_balance(row: &SqlRow) -> Result<Balance, RepositoryError> {
+    Ok(Balance {
+        time: DateTime::from_timestamp_nanos(integer(column(row, 0)?)?),
+        total: real(column(row, 1)?)?,
+        available: real(column(row, 2)?)?,
+    })
+}
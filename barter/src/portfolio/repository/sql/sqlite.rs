@@ -0,0 +1,490 @@
+use crate::{
+    portfolio::repository::{
+        error::RepositoryError,
+        sql::{SqlConnection, SqlRepository, SqlRow, SqlValue},
+    },
+    statistic::summary::PositionSummariser,
+};
+use rusqlite::{
+    types::{Value, ValueRef},
+    Connection,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::Path;
+use uuid::Uuid;
+
+/// [`SqlRepository`] backed by a SQLite database.
+pub type SqliteRepository<Statistic> = SqlRepository<Connection, Statistic>;
+
+impl From<rusqlite::Error> for RepositoryError {
+    fn from(error: rusqlite::Error) -> Self {
+        RepositoryError::SqlError(error.to_string())
+    }
+}
+
+impl<Statistic> SqlRepository<Connection, Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    /// Open (or create) the SQLite database at the provided path for the provided engine_id,
+    /// applying any outstanding schema migrations.
+    pub fn open<P: AsRef<Path>>(path: P, engine_id: Uuid) -> Result<Self, RepositoryError> {
+        Self::new(Connection::open(path)?, engine_id)
+    }
+
+    /// Open a new in-memory SQLite database for the provided engine_id, convenient for
+    /// backtests.
+    pub fn open_in_memory(engine_id: Uuid) -> Result<Self, RepositoryError> {
+        Self::new(Connection::open_in_memory()?, engine_id)
+    }
+}
+
+impl SqlConnection for Connection {
+    fn execute_batch(&mut self, sql: &str) -> Result<(), RepositoryError> {
+        Connection::execute_batch(self, sql).map_err(RepositoryError::from)
+    }
+
+    fn execute(&mut self, sql: &str, params: &[SqlValue]) -> Result<u64, RepositoryError> {
+        let mut statement = self.prepare_cached(sql)?;
+        bind(&mut statement, params)?;
+        Ok(statement.raw_execute()? as u64)
+    }
+
+    fn query(&mut self, sql: &str, params: &[SqlValue]) -> Result<Vec<SqlRow>, RepositoryError> {
+        let mut statement = self.prepare_cached(sql)?;
+        bind(&mut statement, params)?;
+
+        let columns = statement.column_count();
+        let mut rows = statement.raw_query();
+
+        let mut output = Vec::new();
+        while let Some(row) = rows.next()? {
+            output.push(
+                (0..columns)
+                    .map(|index| decode(row.get_ref(index)?))
+                    .collect::<Result<SqlRow, RepositoryError>>()?,
+            );
+        }
+
+        Ok(output)
+    }
+}
+
+/// Bind each [`SqlValue`] to it's `$N` parameter. SQLite treats `$N` as a named parameter, so
+/// parameters are bound by name rather than position.
+fn bind(
+    statement: &mut rusqlite::Statement<'_>,
+    params: &[SqlValue],
+) -> Result<(), RepositoryError> {
+    for (index, param) in params.iter().enumerate() {
+        let name = format!("${}", index + 1);
+        let index = statement
+            .parameter_index(&name)?
+            .ok_or_else(|| RepositoryError::SqlError(format!("unknown parameter {name}")))?;
+
+        let value = match param {
+            SqlValue::Null => Value::Null,
+            SqlValue::Integer(value) => Value::Integer(*value),
+            SqlValue::Real(value) => Value::Real(*value),
+            SqlValue::Text(value) => Value::Text(value.clone()),
+        };
+
+        statement.raw_bind_parameter(index, value)?;
+    }
+
+    Ok(())
+}
+
+fn decode(value: ValueRef<'_>) -> Result<SqlValue, RepositoryError> {
+    match value {
+        ValueRef::Null => Ok(SqlValue::Null),
+        ValueRef::Integer(value) => Ok(SqlValue::Integer(value)),
+        ValueRef::Real(value) => Ok(SqlValue::Real(value)),
+        ValueRef::Text(value) => Ok(SqlValue::Text(String::from_utf8_lossy(value).into_owned())),
+        ValueRef::Blob(_) => Err(RepositoryError::SqlError(
+            "BLOB columns are not supported".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::ClientOrderId,
+        portfolio::{
+            ledger::{ExchangeAsset, ExchangeAssetBalance},
+            position::{determine_position_id, Position},
+            repository::{
+                sql::MarketPerformance, BalanceHandler, OrderHandler, PositionHandler,
+                StatisticHandler, TransactionHandler,
+            },
+            Balance, InFlightOrder,
+        },
+        statistic::summary::pnl::PnLReturnSummary,
+        test_util,
+    };
+    use barter_execution::model::balance::Balance as ExchangeBalance;
+    use barter_instrument::{
+        exchange::ExchangeId,
+        market::{Market, MarketId},
+    };
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    fn repository() -> SqliteRepository<PnLReturnSummary> {
+        SqliteRepository::open_in_memory(Uuid::new_v4()).unwrap()
+    }
+
+    fn exited_position(exit_time: DateTime<Utc>, realised_profit_loss: f64) -> Position {
+        let mut position = test_util::position();
+        position.meta.exit_balance = Some(Balance::new(exit_time, 1000.0, 1000.0));
+        position.exit_fees_total = 1.0;
+        position.realised_profit_loss = realised_profit_loss;
+        position
+    }
+
+    #[test]
+    fn test_sqlite_repository_migrate_is_idempotent() {
+        let mut repository = repository();
+        assert_eq!(repository.migrate().unwrap(), 1);
+        assert_eq!(repository.migrate().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_sqlite_repository_in_flight_orders() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+
+        let mut order = InFlightOrder::new(test_util::order_event());
+        order.order.cid = ClientOrderId::random();
+        let other = InFlightOrder::new(test_util::order_event());
+
+        repository
+            .set_in_flight_order(engine_id, order.clone())
+            .unwrap();
+        repository
+            .set_in_flight_order(engine_id, other.clone())
+            .unwrap();
+
+        // Upsert replaces the InFlightOrder with the same ClientOrderId
+        order.filled_quantity = 0.5;
+        repository
+            .set_in_flight_order(engine_id, order.clone())
+            .unwrap();
+
+        // InFlightOrders of another engine are not returned
+        repository
+            .set_in_flight_order(Uuid::new_v4(), order.clone())
+            .unwrap();
+
+        let mut in_flight = repository.get_in_flight_orders(engine_id).unwrap();
+        in_flight.sort_by_key(|in_flight| in_flight.order.cid);
+        let mut expected = vec![order.clone(), other.clone()];
+        expected.sort_by_key(|in_flight| in_flight.order.cid);
+        assert_eq!(in_flight, expected);
+
+        assert_eq!(
+            repository
+                .remove_in_flight_order(engine_id, &order.order.cid)
+                .unwrap(),
+            Some(order.clone())
+        );
+        assert_eq!(
+            repository
+                .remove_in_flight_order(engine_id, &order.order.cid)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            repository.get_in_flight_orders(engine_id).unwrap(),
+            vec![other]
+        );
+    }
+
+    #[test]
+    fn test_sqlite_repository_open_positions() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+
+        let mut position = test_util::position();
+        position.position_id =
+            determine_position_id(engine_id, &position.exchange, &position.instrument);
+        let market = Market::new(position.exchange, position.instrument.clone());
+
+        repository.set_open_position(position.clone()).unwrap();
+
+        // Upsert replaces the open Position
+        position.current_price = 150.0;
+        position.unrealised_profit_loss = 50.0;
+        repository.set_open_position(position.clone()).unwrap();
+
+        assert_eq!(
+            repository.get_open_position(&position.position_id).unwrap(),
+            Some(position.clone())
+        );
+        assert_eq!(
+            repository
+                .get_open_positions(engine_id, std::iter::once(&market))
+                .unwrap(),
+            vec![position.clone()]
+        );
+        assert_eq!(
+            repository.remove_position(&position.position_id).unwrap(),
+            Some(position.clone())
+        );
+        assert_eq!(
+            repository.get_open_position(&position.position_id).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sqlite_repository_exited_positions() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        let positions = [
+            exited_position(start + Duration::hours(2), -5.0),
+            exited_position(start, 10.0),
+            exited_position(start + Duration::hours(1), 20.0),
+        ];
+        for position in positions.iter() {
+            repository
+                .set_exited_position(engine_id, position.clone())
+                .unwrap();
+        }
+
+        // Exited Positions of another engine are not returned
+        repository
+            .set_exited_position(Uuid::new_v4(), exited_position(start, 100.0))
+            .unwrap();
+
+        assert_eq!(
+            repository.get_exited_positions(engine_id).unwrap(),
+            vec![
+                positions[1].clone(),
+                positions[2].clone(),
+                positions[0].clone()
+            ]
+        );
+        assert_eq!(
+            repository
+                .exited_positions_between(engine_id, start, start + Duration::hours(2))
+                .unwrap(),
+            vec![positions[1].clone(), positions[2].clone()]
+        );
+        assert_eq!(
+            repository.market_performance(engine_id).unwrap(),
+            vec![MarketPerformance {
+                exchange: "binance_spot".to_string(),
+                instrument: positions[0].instrument.to_string(),
+                trades: 3,
+                wins: 2,
+                fees: 3.0,
+                realised_profit_loss: 25.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sqlite_repository_balances() {
+        struct TestCase {
+            input: Balance,
+            expected_latest: Balance,
+        }
+
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+        let start = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+
+        assert!(matches!(
+            repository.get_balance(engine_id),
+            Err(RepositoryError::ExpectedDataNotPresentError)
+        ));
+
+        let cases = vec![
+            // TC0: first Balance
+            TestCase {
+                input: Balance::new(start, 1000.0, 1000.0),
+                expected_latest: Balance::new(start, 1000.0, 1000.0),
+            },
+            // TC1: later Balance is the latest
+            TestCase {
+                input: Balance::new(start + Duration::minutes(1), 1010.0, 900.0),
+                expected_latest: Balance::new(start + Duration::minutes(1), 1010.0, 900.0),
+            },
+            // TC2: Balance at an existing time is kept in the history & is the latest
+            TestCase {
+                input: Balance::new(start + Duration::minutes(1), 1020.0, 950.0),
+                expected_latest: Balance::new(start + Duration::minutes(1), 1020.0, 950.0),
+            },
+            // TC3: out of order Balance is the latest, since it was the last Balance set
+            TestCase {
+                input: Balance::new(start - Duration::minutes(1), 990.0, 990.0),
+                expected_latest: Balance::new(start - Duration::minutes(1), 990.0, 990.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            repository.set_balance(engine_id, test.input).unwrap();
+            assert_eq!(
+                repository.get_balance(engine_id).unwrap(),
+                test.expected_latest,
+                "TC{} failed",
+                index
+            );
+        }
+
+        assert_eq!(
+            repository
+                .balance_history(engine_id, start, start + Duration::hours(1))
+                .unwrap(),
+            vec![
+                Balance::new(start, 1000.0, 1000.0),
+                Balance::new(start + Duration::minutes(1), 1010.0, 900.0),
+                Balance::new(start + Duration::minutes(1), 1020.0, 950.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sqlite_repository_asset_balances() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+        let usdt = ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt");
+        let eth = ExchangeAsset::new(ExchangeId::BinanceSpot, "eth");
+
+        assert!(repository.get_asset_balances(engine_id).unwrap().is_empty());
+
+        repository
+            .set_asset_balance(
+                engine_id,
+                ExchangeAssetBalance::new(usdt.clone(), ExchangeBalance::new(1000.0, 1000.0)),
+            )
+            .unwrap();
+        repository
+            .set_asset_balance(
+                engine_id,
+                ExchangeAssetBalance::new(eth.clone(), ExchangeBalance::new(1.0, 1.0)),
+            )
+            .unwrap();
+
+        // Upserted by exchange & asset
+        repository
+            .set_asset_balance(
+                engine_id,
+                ExchangeAssetBalance::new(usdt.clone(), ExchangeBalance::new(900.0, 800.0)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            repository.get_asset_balances(engine_id).unwrap(),
+            vec![
+                ExchangeAssetBalance::new(eth, ExchangeBalance::new(1.0, 1.0)),
+                ExchangeAssetBalance::new(usdt, ExchangeBalance::new(900.0, 800.0)),
+            ]
+        );
+        assert!(repository
+            .get_asset_balances(Uuid::new_v4())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sqlite_repository_statistics() {
+        let mut repository = repository();
+        let market_id = MarketId::new(
+            test_util::position().exchange,
+            &test_util::position().instrument,
+        );
+
+        assert!(matches!(
+            repository.get_statistics(&market_id),
+            Err(RepositoryError::ExpectedDataNotPresentError)
+        ));
+
+        let mut statistic = PnLReturnSummary::new();
+        statistic.total.count = 1;
+        repository
+            .set_statistics(market_id.clone(), statistic)
+            .unwrap();
+
+        statistic.total.count = 2;
+        repository
+            .set_statistics(market_id.clone(), statistic)
+            .unwrap();
+
+        assert_eq!(repository.get_statistics(&market_id).unwrap(), statistic);
+
+        // Statistics of engines sharing a database do not overwrite each other
+        let path = std::env::temp_dir().join(format!("barter_{}.sqlite", Uuid::new_v4()));
+        let mut engines = [Uuid::new_v4(), Uuid::new_v4()]
+            .map(|engine_id| SqliteRepository::<PnLReturnSummary>::open(&path, engine_id).unwrap());
+        for (count, repository) in engines.iter_mut().enumerate() {
+            statistic.total.count = count as u64;
+            repository
+                .set_statistics(market_id.clone(), statistic)
+                .unwrap();
+        }
+        for (count, repository) in engines.iter_mut().enumerate() {
+            assert_eq!(
+                repository.get_statistics(&market_id).unwrap().total.count,
+                count as u64
+            );
+        }
+
+        drop(engines);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_sqlite_repository_transaction() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let position = test_util::position();
+
+        repository.set_open_position(position.clone()).unwrap();
+        repository
+            .set_balance(engine_id, Balance::new(start, 100.0, 100.0))
+            .unwrap();
+
+        // Rolled back writes are discarded
+        repository.begin().unwrap();
+        repository.remove_position(&position.position_id).unwrap();
+        repository
+            .set_exited_position(engine_id, exited_position(start, 10.0))
+            .unwrap();
+        repository
+            .set_balance(engine_id, Balance::new(start, 110.0, 110.0))
+            .unwrap();
+        assert_eq!(repository.get_balance(engine_id).unwrap().total, 110.0);
+        repository.rollback().unwrap();
+
+        assert_eq!(
+            repository.get_open_position(&position.position_id).unwrap(),
+            Some(position.clone())
+        );
+        assert!(repository
+            .get_exited_positions(engine_id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(repository.get_balance(engine_id).unwrap().total, 100.0);
+
+        // Committed writes are persisted
+        repository.begin().unwrap();
+        repository.remove_position(&position.position_id).unwrap();
+        repository
+            .set_exited_position(engine_id, exited_position(start, 10.0))
+            .unwrap();
+        repository.commit().unwrap();
+
+        assert_eq!(
+            repository.get_open_position(&position.position_id).unwrap(),
+            None
+        );
+        assert_eq!(repository.get_exited_positions(engine_id).unwrap().len(), 1);
+    }
+}
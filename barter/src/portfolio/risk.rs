@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::portfolio::{OrderEvent, OrderType};
+use crate::portfolio::{error::PortfolioError, OrderEvent, OrderType};
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -11,10 +11,17 @@ pub trait OrderEvaluator {
     /// May return an amended [`OrderEvent`] if the associated risk is appropriate. Returns `None`
     /// if the risk is too high.
     fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent>;
+
+    /// Update the risk parameters at runtime. By default, runtime updates are unsupported.
+    fn update_parameters(&mut self, _parameters: serde_json::Value) -> Result<(), PortfolioError> {
+        Err(PortfolioError::RiskUpdateUnsupported)
+    }
 }
 
-/// Default risk manager that implements [`OrderEvaluator`].
+/// Default risk manager that implements [`OrderEvaluator`]. It has no parameters, so runtime
+/// updates only accept an empty JSON object.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct DefaultRisk {}
 
 impl OrderEvaluator for DefaultRisk {
@@ -27,6 +34,11 @@ impl OrderEvaluator for DefaultRisk {
         order.order_type = DefaultRisk::DEFAULT_ORDER_TYPE;
         Some(order)
     }
+
+    fn update_parameters(&mut self, parameters: serde_json::Value) -> Result<(), PortfolioError> {
+        *self = serde_json::from_value(parameters)?;
+        Ok(())
+    }
 }
 
 impl DefaultRisk {
@@ -34,3 +46,47 @@ impl DefaultRisk {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_risk_update_parameters() {
+        struct TestCase {
+            input: serde_json::Value,
+            expected_ok: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: empty parameters are accepted
+                input: json!({}),
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: unknown parameters are rejected
+                input: json!({ "max_order_value": 100.0 }),
+                expected_ok: false,
+            },
+            TestCase {
+                // TC2: non-object parameters are rejected
+                input: json!(1.0),
+                expected_ok: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = DefaultRisk {}.update_parameters(test.input);
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{} failed", index);
+            if !test.expected_ok {
+                assert!(
+                    matches!(actual, Err(PortfolioError::RiskParameters(_))),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
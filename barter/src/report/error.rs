@@ -0,0 +1,18 @@
+use crate::portfolio::repository::error::RepositoryError;
+use thiserror::Error;
+
+/// All errors generated in the barter::report module.
+#[derive(Error, Debug)]
+pub enum ReportError {
+    #[error("Failed to build struct due to missing attributes: {0}")]
+    BuilderIncomplete(&'static str),
+
+    #[error("Failed to serialise Report: {0}")]
+    Serialise(#[from] serde_json::Error),
+
+    #[error("Failed to write Report: {0}")]
+    Write(#[from] std::io::Error),
+
+    #[error("Failed to interact with repository")]
+    RepositoryInteraction(#[from] RepositoryError),
+}
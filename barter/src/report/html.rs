@@ -0,0 +1,258 @@
+use super::{error::ReportError, Report};
+use crate::statistic::{metric::EquityPoint, summary::TableBuilder};
+use chrono::{DateTime, Utc};
+use prettytable::Row;
+use serde::Serialize;
+
+/// Width of every inline SVG chart.
+const CHART_WIDTH: f64 = 960.0;
+
+/// Height of every inline SVG chart.
+const CHART_HEIGHT: f64 = 260.0;
+
+/// Padding between the SVG chart border & the plotted series, leaving room for axis labels.
+const CHART_PADDING: f64 = 48.0;
+
+/// Inline stylesheet so the rendered tear sheet has no external dependencies.
+const STYLE: &str =
+    "body{font-family:-apple-system,Helvetica,Arial,sans-serif;margin:2em;color:#222}\
+h1{font-size:1.6em}h2{font-size:1.2em;margin-top:2em}\
+table{border-collapse:collapse;font-size:0.85em}\
+th,td{border:1px solid #ddd;padding:4px 8px;text-align:right}th{background:#f4f4f4}\
+svg{background:#fcfcfc;border:1px solid #ddd}pre{background:#f4f4f4;padding:1em}";
+
+/// Render the [`Report`] to a self-contained HTML tear sheet, including inline SVG charts of the
+/// equity curve & drawdowns.
+pub fn render<Config>(report: &Report<Config>) -> Result<String, ReportError>
+where
+    Config: Serialize,
+{
+    let config = serde_json::to_string_pretty(&report.config)?;
+
+    Ok(format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+        <title>Barter Tear Sheet</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+        <h1>Barter Tear Sheet</h1>\n\
+        <h2>Summary</h2>\n{summary}\n\
+        <h2>Equity Curve</h2>\n{equity}\n\
+        <h2>Drawdown</h2>\n{underwater}\n\
+        <h2>Drawdown Periods</h2>\n{drawdowns}\n\
+        <h2>Trades</h2>\n{trades}\n\
+        <h2>Configuration</h2>\n<pre>{config}</pre>\n\
+        </body>\n</html>\n",
+        summary = summary_table(report),
+        equity = line_chart(&equity_series(&report.equity_curve), "#1f77b4"),
+        underwater = line_chart(&underwater_series(&report.equity_curve), "#d62728"),
+        drawdowns = drawdowns_table(report),
+        trades = trades_table(report),
+        config = escape(&config),
+    ))
+}
+
+/// Render the [`TradingSummary`](crate::statistic::summary::trading::TradingSummary) of every
+/// market as an HTML table, re-using the [`TableBuilder`] titles & rows.
+fn summary_table<Config>(report: &Report<Config>) -> String {
+    let Some(first) = report.markets.first() else {
+        return "<p>No markets</p>".to_string();
+    };
+
+    let rows = report
+        .markets
+        .iter()
+        .map(|market| {
+            format!(
+                "<tr><th>{}</th>{}</tr>",
+                escape(&market.market.0),
+                cells(&market.summary.row(), "td")
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "<table>\n<tr><th></th>{}</tr>\n{}\n</table>",
+        cells(&first.summary.titles(), "th"),
+        rows
+    )
+}
+
+/// Render the drawdown periods as an HTML table.
+fn drawdowns_table<Config>(report: &Report<Config>) -> String {
+    let rows = report
+        .drawdowns
+        .iter()
+        .map(|drawdown| {
+            format!(
+                "<tr><td>{}</td><td>{:.3}</td><td>{:.3}</td><td>{:.3}</td></tr>",
+                format_time(drawdown.start_time),
+                drawdown.duration.num_seconds() as f64 / 3600.0,
+                drawdown.equity_range.high,
+                drawdown.drawdown,
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "<table>\n<tr><th>Start</th><th>Duration Hours</th><th>Peak Equity</th>\
+        <th>Drawdown</th></tr>\n{rows}\n</table>"
+    )
+}
+
+/// Render every exited [`Position`](crate::portfolio::position::Position) as an HTML table.
+fn trades_table<Config>(report: &Report<Config>) -> String {
+    let rows = report
+        .trades
+        .iter()
+        .map(|position| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{:?}</td><td>{}</td><td>{}</td><td>{:.6}</td>\
+                <td>{:.6}</td><td>{:.6}</td><td>{:.3}</td><td>{:.3}</td><td>{:.3}</td></tr>",
+                escape(&position.exchange.to_string()),
+                escape(&position.instrument.to_string()),
+                position.side,
+                format_time(position.meta.enter_time),
+                format_time(position.meta.update_time),
+                position.quantity,
+                position.enter_avg_price_gross,
+                position.exit_avg_price_gross,
+                position.enter_fees_total + position.exit_fees_total,
+                position.realised_profit_loss,
+                position.calculate_profit_loss_return(),
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "<table>\n<tr><th>Exchange</th><th>Instrument</th><th>Side</th><th>Enter Time</th>\
+        <th>Exit Time</th><th>Quantity</th><th>Enter Price</th><th>Exit Price</th><th>Fees</th>\
+        <th>Realised PnL</th><th>Return</th></tr>\n{rows}\n</table>"
+    )
+}
+
+/// Render the cells of a [`TableBuilder`] [`Row`] using the provided HTML tag.
+fn cells(row: &Row, tag: &str) -> String {
+    row.iter()
+        .map(|cell| format!("<{tag}>{}</{tag}>", escape(&cell.get_content())))
+        .collect()
+}
+
+/// Equity curve series.
+fn equity_series(equity_curve: &[EquityPoint]) -> Vec<(DateTime<Utc>, f64)> {
+    equity_curve
+        .iter()
+        .map(|point| (point.time, point.total))
+        .collect()
+}
+
+/// Underwater series of the equity curve, being the drawdown from the running peak equity.
+fn underwater_series(equity_curve: &[EquityPoint]) -> Vec<(DateTime<Utc>, f64)> {
+    let mut peak = f64::MIN;
+
+    equity_curve
+        .iter()
+        .map(|point| {
+            peak = peak.max(point.total);
+            let drawdown = match peak == 0.0 {
+                true => 0.0,
+                false => point.total / peak - 1.0,
+            };
+            (point.time, drawdown)
+        })
+        .collect()
+}
+
+/// Render a time series as an inline SVG line chart, labelling the value & time ranges.
+fn line_chart(series: &[(DateTime<Utc>, f64)], colour: &str) -> String {
+    let (Some(first), Some(last)) = (series.first(), series.last()) else {
+        return "<p>No data</p>".to_string();
+    };
+
+    let (low, high) = series
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), (_, value)| {
+            (low.min(*value), high.max(*value))
+        });
+
+    let start = first.0.timestamp() as f64;
+    let time_range = (last.0.timestamp() as f64 - start).max(1.0);
+    let value_range = match high - low {
+        range if range > 0.0 => range,
+        _ => 1.0,
+    };
+
+    let plot_width = CHART_WIDTH - 2.0 * CHART_PADDING;
+    let plot_height = CHART_HEIGHT - 2.0 * CHART_PADDING;
+
+    let points = series
+        .iter()
+        .map(|(time, value)| {
+            let x = CHART_PADDING + (time.timestamp() as f64 - start) / time_range * plot_width;
+            let y = CHART_PADDING + (high - value) / value_range * plot_height;
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let bottom = CHART_HEIGHT - CHART_PADDING;
+    let right = CHART_WIDTH - CHART_PADDING;
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" \
+        viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" font-size=\"11\">\
+        <line x1=\"{CHART_PADDING}\" y1=\"{bottom}\" x2=\"{right}\" y2=\"{bottom}\" stroke=\"#999\"/>\
+        <line x1=\"{CHART_PADDING}\" y1=\"{CHART_PADDING}\" x2=\"{CHART_PADDING}\" y2=\"{bottom}\" stroke=\"#999\"/>\
+        <text x=\"4\" y=\"{CHART_PADDING}\">{high:.3}</text>\
+        <text x=\"4\" y=\"{bottom}\">{low:.3}</text>\
+        <text x=\"{CHART_PADDING}\" y=\"{label}\">{start_label}</text>\
+        <text x=\"{right}\" y=\"{label}\" text-anchor=\"end\">{end_label}</text>\
+        <polyline fill=\"none\" stroke=\"{colour}\" stroke-width=\"1.5\" points=\"{points}\"/>\
+        </svg>",
+        label = CHART_HEIGHT - CHART_PADDING / 2.0,
+        start_label = format_time(first.0),
+        end_label = format_time(last.0),
+    )
+}
+
+/// Format a timestamp for display in the tear sheet.
+fn format_time(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M").to_string()
+}
+
+/// Escape the HTML special characters of the input text.
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::report;
+
+    #[test]
+    fn test_render_html_tear_sheet() {
+        let html = render(&report()).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("binance_spot_btc_usdt"));
+        assert!(html.contains("Sharpe Ratio"));
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert_eq!(html.matches("<polyline").count(), 2);
+        assert!(!html.contains("src=\"http"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use self::error::ReportError;
+use crate::{
+    portfolio::{
+        allocator::OrderAllocator,
+        portfolio::MetaPortfolio,
+        position::Position,
+        repository::{BalanceHandler, OrderHandler, PositionHandler, StatisticHandler},
+        risk::OrderEvaluator,
+    },
+    statistic::{
+        metric::{drawdown::Drawdown, EquityPoint},
+        summary::{equity::EquitySummary, trading::TradingSummary},
+    },
+};
+use barter_instrument::market::{Market, MarketId};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+/// Barter report module specific errors.
+pub mod error;
+
+/// Offline single-file HTML tear sheet rendering of a [`Report`], with inline SVG charts.
+pub mod html;
+
+/// Full trading session report, including the session configuration, the [`TradingSummary`] of
+/// every [`Market`], the mark-to-market equity curve & it's drawdown periods, and every exited
+/// [`Position`].
+///
+/// Serialises to JSON, and renders to an offline HTML tear sheet, so results can be archived &
+/// compared across runs.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Report<Config> {
+    pub config: Config,
+    pub markets: Vec<MarketReport>,
+    pub equity_curve: Vec<EquityPoint>,
+    pub drawdowns: Vec<Drawdown>,
+    pub trades: Vec<Position>,
+}
+
+/// [`TradingSummary`] of a single [`Market`] traded during the session.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarketReport {
+    pub market: MarketId,
+    pub summary: TradingSummary,
+}
+
+impl<Config> Report<Config>
+where
+    Config: Serialize,
+{
+    /// Returns a [`ReportBuilder`] instance.
+    pub fn builder() -> ReportBuilder<Config> {
+        ReportBuilder::new()
+    }
+
+    /// Constructs a [`Report`] from the current state of a [`MetaPortfolio`], including the
+    /// [`TradingSummary`] of each provided [`Market`].
+    pub fn from_portfolio<Repository, Allocator, RiskManager>(
+        config: Config,
+        portfolio: &mut MetaPortfolio<Repository, Allocator, RiskManager, TradingSummary>,
+        markets: &[Market],
+    ) -> Result<Self, ReportError>
+    where
+        Repository:
+            PositionHandler + BalanceHandler + OrderHandler + StatisticHandler<TradingSummary>,
+        Allocator: OrderAllocator,
+        RiskManager: OrderEvaluator,
+    {
+        let builder = markets
+            .iter()
+            .try_fold(Self::builder(), |builder, market| {
+                let market_id = MarketId::from(market);
+                portfolio
+                    .get_statistics(&market_id)
+                    .map(|summary| builder.market(market_id, summary))
+            })?;
+
+        builder
+            .config(config)
+            .equity(portfolio.equity())
+            .trades(portfolio.get_exited_positions(portfolio.engine_id())?)
+            .build()
+    }
+
+    /// Serialise the [`Report`] to a pretty-printed JSON `String`.
+    pub fn to_json(&self) -> Result<String, ReportError> {
+        serde_json::to_string_pretty(self).map_err(ReportError::from)
+    }
+
+    /// Render the [`Report`] to a self-contained HTML tear sheet `String`.
+    pub fn to_html(&self) -> Result<String, ReportError> {
+        html::render(self)
+    }
+
+    /// Write the [`Report`] as JSON to the provided file path.
+    pub fn write_json<P: AsRef<Path>>(&self, path: P) -> Result<(), ReportError> {
+        fs::write(path, self.to_json()?).map_err(ReportError::from)
+    }
+
+    /// Write the [`Report`] as an HTML tear sheet to the provided file path.
+    pub fn write_html<P: AsRef<Path>>(&self, path: P) -> Result<(), ReportError> {
+        fs::write(path, self.to_html()?).map_err(ReportError::from)
+    }
+}
+
+/// Calculates every drawdown period of the equity curve, including any drawdown that is still
+/// ongoing at the end of the curve.
+pub fn drawdown_periods(equity_curve: &[EquityPoint]) -> Vec<Drawdown> {
+    let Some(first) = equity_curve.first() else {
+        return vec![];
+    };
+
+    let mut current = Drawdown::init(first.total);
+    current.start_time = first.time;
+
+    let mut periods = equity_curve
+        .iter()
+        .skip(1)
+        .filter_map(|point| current.update(*point))
+        .collect::<Vec<_>>();
+
+    if !current.is_waiting_for_peak() {
+        periods.push(current);
+    }
+
+    periods
+}
+
+/// Builder to construct [`Report`] instances.
+#[derive(Debug)]
+pub struct ReportBuilder<Config> {
+    config: Option<Config>,
+    markets: Vec<MarketReport>,
+    equity_curve: Option<Vec<EquityPoint>>,
+    trades: Option<Vec<Position>>,
+}
+
+impl<Config> Default for ReportBuilder<Config> {
+    fn default() -> Self {
+        Self {
+            config: None,
+            markets: vec![],
+            equity_curve: None,
+            trades: None,
+        }
+    }
+}
+
+impl<Config> ReportBuilder<Config> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn config(self, value: Config) -> Self {
+        Self {
+            config: Some(value),
+            ..self
+        }
+    }
+
+    pub fn market(mut self, market: MarketId, summary: TradingSummary) -> Self {
+        self.markets.push(MarketReport { market, summary });
+        self
+    }
+
+    pub fn equity_curve(self, value: Vec<EquityPoint>) -> Self {
+        Self {
+            equity_curve: Some(value),
+            ..self
+        }
+    }
+
+    pub fn equity(self, value: &EquitySummary) -> Self {
+        self.equity_curve(value.curve.clone())
+    }
+
+    pub fn trades(self, value: Vec<Position>) -> Self {
+        Self {
+            trades: Some(value),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<Report<Config>, ReportError> {
+        let equity_curve = self
+            .equity_curve
+            .ok_or(ReportError::BuilderIncomplete("equity_curve"))?;
+
+        Ok(Report {
+            config: self
+                .config
+                .ok_or(ReportError::BuilderIncomplete("config"))?,
+            markets: self.markets,
+            drawdowns: drawdown_periods(&equity_curve),
+            equity_curve,
+            trades: self.trades.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        statistic::summary::{trading::Config as StatisticConfig, Initialiser},
+        test_util,
+    };
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn time(hours: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hours)
+    }
+
+    fn curve(totals: &[f64]) -> Vec<EquityPoint> {
+        totals
+            .iter()
+            .enumerate()
+            .map(|(hours, total)| EquityPoint {
+                time: time(hours as i64),
+                total: *total,
+            })
+            .collect()
+    }
+
+    pub(crate) fn report() -> Report<StatisticConfig> {
+        let config = StatisticConfig {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        };
+
+        Report::builder()
+            .config(config)
+            .market(
+                MarketId("binance_spot_btc_usdt".into()),
+                TradingSummary::init_at(config, time(0)),
+            )
+            .equity_curve(curve(&[100.0, 90.0, 110.0, 105.0, 100.0]))
+            .trades(vec![test_util::position()])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_drawdown_periods() {
+        struct TestCase {
+            input: Vec<EquityPoint>,
+            expected: Vec<(f64, i64)>,
+        }
+
+        let cases = vec![
+            // TC0: empty curve
+            TestCase {
+                input: vec![],
+                expected: vec![],
+            },
+            // TC1: monotonically increasing curve has no drawdowns
+            TestCase {
+                input: curve(&[100.0, 110.0, 120.0]),
+                expected: vec![],
+            },
+            // TC2: recovered drawdown followed by an ongoing drawdown
+            TestCase {
+                input: curve(&[100.0, 90.0, 80.0, 110.0, 99.0]),
+                expected: vec![(-0.2, 1), (-0.1, 0)],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = drawdown_periods(&test.input)
+                .into_iter()
+                .map(|drawdown| (drawdown.drawdown, drawdown.duration.num_hours()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+            for (actual, expected) in actual.iter().zip(test.expected.iter()) {
+                assert!((actual.0 - expected.0).abs() < 1e-10, "TC{} failed", index);
+                assert_eq!(actual.1, expected.1, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_report_json_round_trip() {
+        let report = report();
+
+        let json = report.to_json().unwrap();
+        let deserialised = serde_json::from_str::<Report<StatisticConfig>>(&json).unwrap();
+
+        assert_eq!(deserialised.markets.len(), 1);
+        assert_eq!(deserialised.equity_curve, report.equity_curve);
+        assert_eq!(deserialised.drawdowns.len(), 2);
+        assert_eq!(deserialised.trades.len(), 1);
+    }
+
+    #[test]
+    fn test_report_builder_incomplete() {
+        assert!(matches!(
+            Report::<()>::builder().config(()).build(),
+            Err(ReportError::BuilderIncomplete("equity_curve"))
+        ));
+    }
+}
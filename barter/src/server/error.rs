@@ -0,0 +1,46 @@
+use crate::engine::error::EngineError;
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use thiserror::Error;
+
+/// All errors generated in the barter::server module.
+#[derive(Error, Debug)]
+pub enum ServerError {
+    #[error("missing or invalid bearer token")]
+    Unauthorised,
+
+    #[error("bearer token must not be empty")]
+    EmptyBearerToken,
+
+    #[error("Engine is not running: Command receiver dropped")]
+    EngineUnavailable,
+
+    #[error("Engine dropped the Command response transmitter")]
+    NoResponse,
+
+    #[error("Engine error: {0}")]
+    Engine(#[from] EngineError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            ServerError::Unauthorised => StatusCode::UNAUTHORIZED,
+            ServerError::EngineUnavailable | ServerError::NoResponse => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            ServerError::EmptyBearerToken | ServerError::Engine(_) | ServerError::Io(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
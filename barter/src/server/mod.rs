@@ -0,0 +1,260 @@
+use crate::{
+    engine::{error::EngineError, Command},
+    event::Event,
+    portfolio::{position::Position, Balance},
+    server::error::ServerError,
+};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Request, State,
+    },
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::{self, Next},
+    response::Response,
+    routing::{get, post},
+    Json, Router,
+};
+use barter_instrument::market::Market;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tokio::{
+    net::TcpListener,
+    sync::{broadcast, mpsc, oneshot},
+};
+use tracing::{info, warn};
+
+/// Barter server module specific errors.
+pub mod error;
+
+/// Prometheus exporter serving collected [`Metric`](barter_integration::metric::Metric)s over
+/// HTTP.
+pub mod prometheus;
+
+/// Capacity of the [`Event`] feed broadcast to websocket clients. Clients lagging further behind
+/// skip the oldest [`Event`]s.
+const EVENT_FEED_CAPACITY: usize = 1024;
+
+/// Request body for `POST /terminate`.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TerminateRequest {
+    pub message: String,
+}
+
+/// REST & websocket server for remote control & observation of a live
+/// [`Engine`](crate::engine::Engine).
+///
+/// Routes:
+/// - `GET /positions`: fetch open [`Position`]s.
+/// - `POST /positions/exit`: exit the [`Position`] for the [`Market`] in the JSON body.
+/// - `POST /positions/exit_all`: exit every open [`Position`].
+/// - `GET /balance`: fetch the current [`Balance`].
+/// - `POST /statistics`: fetch the statistics for the [`Market`] in the JSON body.
+/// - `POST /terminate`: terminate the [`Engine`](crate::engine::Engine) with a
+///   [`TerminateRequest`] JSON body.
+/// - `GET /events`: websocket streaming every [`Event`] as JSON.
+///
+/// Every route requires an `Authorization: Bearer <token>` header matching the token the
+/// [`Server`] was constructed with. Requests without it are rejected with `401 Unauthorized`.
+#[derive(Debug)]
+pub struct Server {
+    /// [`Command`] transmitter used to control the associated [`Engine`](crate::engine::Engine).
+    command_tx: mpsc::Sender<Command>,
+    /// [`Event`] receiver for the associated [`Engine`](crate::engine::Engine)'s
+    /// [`EventTx`](crate::event::EventTx) channel.
+    event_rx: mpsc::UnboundedReceiver<Event>,
+    /// Bearer token every request must be authorised with.
+    bearer_token: Arc<str>,
+}
+
+/// State shared between every route handler.
+#[derive(Debug, Clone)]
+struct ServerState {
+    command_tx: mpsc::Sender<Command>,
+    event_feed: broadcast::Sender<Event>,
+}
+
+impl Server {
+    /// Constructs a new [`Server`] that controls an [`Engine`](crate::engine::Engine) via the
+    /// provided `command_tx`, and streams every [`Event`] received via the `event_rx`. Every
+    /// request must be authorised with the provided `bearer_token`, which must not be empty.
+    pub fn new(
+        command_tx: mpsc::Sender<Command>,
+        event_rx: mpsc::UnboundedReceiver<Event>,
+        bearer_token: impl Into<String>,
+    ) -> Result<Self, ServerError> {
+        let bearer_token = bearer_token.into();
+        if bearer_token.is_empty() {
+            return Err(ServerError::EmptyBearerToken);
+        }
+
+        Ok(Self {
+            command_tx,
+            event_rx,
+            bearer_token: Arc::from(bearer_token),
+        })
+    }
+
+    /// Serve the REST & websocket routes on the provided [`TcpListener`] until the server fails.
+    pub async fn serve(self, listener: TcpListener) -> Result<(), ServerError> {
+        let (event_feed, _) = broadcast::channel(EVENT_FEED_CAPACITY);
+
+        // Forward every Event to the websocket clients
+        tokio::spawn(forward_events(self.event_rx, event_feed.clone()));
+
+        let router = Router::new()
+            .route("/positions", get(fetch_open_positions))
+            .route("/positions/exit", post(exit_position))
+            .route("/positions/exit_all", post(exit_all_positions))
+            .route("/balance", get(fetch_balance))
+            .route("/statistics", post(fetch_statistics))
+            .route("/terminate", post(terminate))
+            .route("/events", get(stream_events))
+            .route_layer(middleware::from_fn_with_state(self.bearer_token, authorise))
+            .with_state(ServerState {
+                command_tx: self.command_tx,
+                event_feed,
+            });
+
+        info!(address = ?listener.local_addr()?, "serving Engine REST & websocket API");
+        axum::serve(listener, router)
+            .await
+            .map_err(ServerError::from)
+    }
+}
+
+/// Rejects any request without an `Authorization: Bearer <token>` header matching the
+/// [`Server`] bearer token. Tokens are compared in constant time, so the response time does not
+/// leak how much of the token matched.
+async fn authorise(
+    State(bearer_token): State<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ServerError> {
+    let authorised = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| bool::from(token.as_bytes().ct_eq(bearer_token.as_bytes())));
+
+    match authorised {
+        true => Ok(next.run(request).await),
+        false => Err(ServerError::Unauthorised),
+    }
+}
+
+/// Forwards every [`Event`] received from the Engine to the broadcast [`Event`] feed.
+async fn forward_events(
+    mut event_rx: mpsc::UnboundedReceiver<Event>,
+    event_feed: broadcast::Sender<Event>,
+) {
+    loop {
+        let Some(event) = event_rx.recv().await else {
+            break;
+        };
+
+        // Error only signifies there are no connected websocket clients
+        let _ = event_feed.send(event);
+    }
+}
+
+impl ServerState {
+    /// Send a [`Command`] to the [`Engine`](crate::engine::Engine).
+    async fn send(&self, command: Command) -> Result<(), ServerError> {
+        self.command_tx
+            .send(command)
+            .await
+            .map_err(|_| ServerError::EngineUnavailable)
+    }
+
+    /// Send a [`Command`] to the [`Engine`](crate::engine::Engine) & await the `oneshot` response.
+    async fn request<T>(
+        &self,
+        command: impl FnOnce(oneshot::Sender<Result<T, EngineError>>) -> Command,
+    ) -> Result<T, ServerError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.send(command(response_tx)).await?;
+        response_rx
+            .await
+            .map_err(|_| ServerError::NoResponse)?
+            .map_err(ServerError::from)
+    }
+}
+
+async fn fetch_open_positions(
+    State(state): State<ServerState>,
+) -> Result<Json<Vec<Position>>, ServerError> {
+    state.request(Command::FetchOpenPositions).await.map(Json)
+}
+
+async fn exit_position(
+    State(state): State<ServerState>,
+    Json(market): Json<Market>,
+) -> Result<StatusCode, ServerError> {
+    state.send(Command::ExitPosition(market)).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn exit_all_positions(State(state): State<ServerState>) -> Result<StatusCode, ServerError> {
+    state.send(Command::ExitAllPositions).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn fetch_balance(State(state): State<ServerState>) -> Result<Json<Balance>, ServerError> {
+    state.request(Command::FetchBalance).await.map(Json)
+}
+
+async fn fetch_statistics(
+    State(state): State<ServerState>,
+    Json(market): Json<Market>,
+) -> Result<Json<serde_json::Value>, ServerError> {
+    state
+        .request(|statistics_tx| Command::FetchStatistics(market, statistics_tx))
+        .await
+        .map(Json)
+}
+
+async fn terminate(
+    State(state): State<ServerState>,
+    Json(request): Json<TerminateRequest>,
+) -> Result<StatusCode, ServerError> {
+    state.send(Command::Terminate(request.message)).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn stream_events(State(state): State<ServerState>, upgrade: WebSocketUpgrade) -> Response {
+    let event_rx = state.event_feed.subscribe();
+    upgrade.on_upgrade(move |socket| send_events(socket, event_rx))
+}
+
+/// Sends every [`Event`] in the feed to the websocket client as JSON, until the client
+/// disconnects.
+async fn send_events(mut socket: WebSocket, mut event_rx: broadcast::Receiver<Event>) {
+    loop {
+        let event = match event_rx.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!(
+                    skipped,
+                    "websocket client lagging behind Event feed, skipped Events"
+                );
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        let message = match serde_json::to_string(&event) {
+            Ok(message) => message,
+            Err(error) => {
+                warn!(%error, ?event, "failed to serialise Event for websocket client");
+                continue;
+            }
+        };
+
+        if socket.send(Message::Text(message)).await.is_err() {
+            break;
+        }
+    }
+}
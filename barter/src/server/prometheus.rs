@@ -0,0 +1,41 @@
+use crate::{metric::MetricRegistry, server::error::ServerError};
+use axum::{extract::State, http::header, response::IntoResponse, routing::get, Router};
+use tokio::net::TcpListener;
+use tracing::info;
+
+/// Content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// HTTP exporter serving the [`Metric`](barter_integration::metric::Metric)s collected by a
+/// [`MetricRegistry`] in the Prometheus text exposition format.
+///
+/// Routes:
+/// - `GET /metrics`: scrape every collected metric family.
+#[derive(Debug, Clone)]
+pub struct PrometheusExporter {
+    registry: MetricRegistry,
+}
+
+impl PrometheusExporter {
+    /// Constructs a new [`PrometheusExporter`] serving the metrics collected by the provided
+    /// [`MetricRegistry`].
+    pub fn new(registry: MetricRegistry) -> Self {
+        Self { registry }
+    }
+
+    /// Serve the `/metrics` route on the provided [`TcpListener`] until the server fails.
+    pub async fn serve(self, listener: TcpListener) -> Result<(), ServerError> {
+        let router = Router::new()
+            .route("/metrics", get(scrape))
+            .with_state(self.registry);
+
+        info!(address = ?listener.local_addr()?, "serving Prometheus metrics");
+        axum::serve(listener, router)
+            .await
+            .map_err(ServerError::from)
+    }
+}
+
+async fn scrape(State(registry): State<MetricRegistry>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], registry.encode())
+}
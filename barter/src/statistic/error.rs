@@ -8,4 +8,19 @@ pub enum StatisticError {
 
     #[error("Failed to build struct due to insufficient metrics provided")]
     BuilderNoMetricsProvided,
+
+    #[error("Monte Carlo simulation requires at least one exited Position")]
+    MonteCarloNoPositions,
+
+    #[error("Invalid Monte Carlo configuration: {0}")]
+    MonteCarloInvalidConfig(&'static str),
+
+    #[error("Monte Carlo simulation requires every exited Position to have a positive & finite enter_value_gross")]
+    MonteCarloInvalidPosition,
+
+    #[error("Equity curve sampling interval must be positive")]
+    EquityIntervalNotPositive,
+
+    #[error("Rolling statistics window must contain at least one trade or a positive duration")]
+    RollingWindowEmpty,
 }
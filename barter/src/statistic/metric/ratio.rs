@@ -1,4 +1,4 @@
-use crate::statistic::summary::pnl::PnLReturnSummary;
+use crate::statistic::summary::{data::DataSummary, pnl::PnLReturnSummary};
 use serde::{Deserialize, Serialize};
 
 pub trait Ratio {
@@ -40,16 +40,19 @@ impl Ratio for SharpeRatio {
 
 impl SharpeRatio {
     pub fn update(&mut self, pnl_returns: &PnLReturnSummary) {
+        self.update_from_returns(&pnl_returns.total, pnl_returns.trades_per_day)
+    }
+
+    /// Updates the [`SharpeRatio`] using a summary of returns, where each return is either a
+    /// trade or a fixed period of an equity curve, occurring `returns_per_day` times a day.
+    pub fn update_from_returns(&mut self, returns: &DataSummary, returns_per_day: f64) {
         // Update Trades Per Day
-        self.trades_per_day = pnl_returns.trades_per_day;
+        self.trades_per_day = returns_per_day;
 
         // Calculate Sharpe Ratio Per Trade
-        self.sharpe_ratio_per_trade = match pnl_returns.total.dispersion.std_dev == 0.0 {
+        self.sharpe_ratio_per_trade = match returns.dispersion.std_dev == 0.0 {
             true => 0.0,
-            false => {
-                (pnl_returns.total.mean - self.risk_free_return)
-                    / pnl_returns.total.dispersion.std_dev
-            }
+            false => (returns.mean - self.risk_free_return) / returns.dispersion.std_dev,
         };
     }
 }
@@ -81,16 +84,29 @@ impl Ratio for SortinoRatio {
 
 impl SortinoRatio {
     pub fn update(&mut self, pnl_returns: &PnLReturnSummary) {
+        self.update_from_returns(
+            &pnl_returns.total,
+            &pnl_returns.losses,
+            pnl_returns.trades_per_day,
+        )
+    }
+
+    /// Updates the [`SortinoRatio`] using a summary of all returns & the negative returns, where
+    /// each return is either a trade or a fixed period of an equity curve, occurring
+    /// `returns_per_day` times a day.
+    pub fn update_from_returns(
+        &mut self,
+        returns: &DataSummary,
+        losses: &DataSummary,
+        returns_per_day: f64,
+    ) {
         // Update Trades Per Day
-        self.trades_per_day = pnl_returns.trades_per_day;
+        self.trades_per_day = returns_per_day;
 
         // Calculate Sortino Ratio Per Trade
-        self.sortino_ratio_per_trade = match pnl_returns.losses.dispersion.std_dev == 0.0 {
+        self.sortino_ratio_per_trade = match losses.dispersion.std_dev == 0.0 {
             true => 0.0,
-            false => {
-                (pnl_returns.total.mean - self.risk_free_return)
-                    / pnl_returns.losses.dispersion.std_dev
-            }
+            false => (returns.mean - self.risk_free_return) / losses.dispersion.std_dev,
         };
     }
 }
@@ -122,13 +138,25 @@ impl Ratio for CalmarRatio {
 
 impl CalmarRatio {
     pub fn update(&mut self, pnl_returns: &PnLReturnSummary, max_drawdown: f64) {
+        self.update_from_returns(&pnl_returns.total, max_drawdown, pnl_returns.trades_per_day)
+    }
+
+    /// Updates the [`CalmarRatio`] using a summary of returns & the max drawdown, where each
+    /// return is either a trade or a fixed period of an equity curve, occurring
+    /// `returns_per_day` times a day.
+    pub fn update_from_returns(
+        &mut self,
+        returns: &DataSummary,
+        max_drawdown: f64,
+        returns_per_day: f64,
+    ) {
         // Update Trades Per Day
-        self.trades_per_day = pnl_returns.trades_per_day;
+        self.trades_per_day = returns_per_day;
 
         // Calculate Calmar Ratio Per Trade
         self.calmar_ratio_per_trade = match max_drawdown == 0.0 {
             true => 0.0,
-            false => (pnl_returns.total.mean - self.risk_free_return) / max_drawdown.abs(),
+            false => (returns.mean - self.risk_free_return) / max_drawdown.abs(),
         };
     }
 }
@@ -5,6 +5,7 @@ pub mod algorithm;
 pub mod dispersion;
 pub mod error;
 pub mod metric;
+pub mod monte_carlo;
 pub mod summary;
 
 /// Serialize a [`Duration`] into a `u64` representing the associated seconds.
@@ -0,0 +1,538 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        error::StatisticError,
+        summary::{data::DataSummary, TableBuilder},
+    },
+};
+use barter_integration::Side;
+use prettytable::Row;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Method used to resample the exited [`Position`] sequence for each simulated path.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum Resample {
+    /// Randomly reorder every trade without replacement, preserving the set of trades.
+    Shuffle,
+    /// Draw trades with replacement, producing paths with the same number of trades.
+    Bootstrap,
+}
+
+/// Optional perturbation applied to every resampled trade, simulating execution uncertainty.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Perturbation {
+    /// Maximum relative shock uniformly applied to each exit fill price in decimal form
+    /// (eg/ 0.001 for ±0.1%).
+    pub price_pct: f64,
+    /// Maximum relative shock uniformly applied to each trade's total fees in decimal form
+    /// (eg/ 0.5 for ±50%).
+    pub fees_pct: f64,
+}
+
+/// Configuration for a Monte Carlo simulation via [`simulate`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Number of simulated paths.
+    pub simulations: usize,
+    /// Method used to resample the exited [`Position`]s.
+    pub resample: Resample,
+    /// Optional [`Perturbation`] applied to every resampled trade.
+    pub perturbation: Option<Perturbation>,
+    /// Equity each simulated path starts with.
+    pub starting_equity: f64,
+    /// Fraction of starting equity at or below which a path is considered ruined (eg/ 0.5).
+    pub ruin_threshold: f64,
+    /// Risk-free return per trade used to calculate the Sharpe Ratio.
+    pub risk_free_return: f64,
+    /// Confidence level of the reported intervals in decimal form (eg/ 0.95 for 95%).
+    pub confidence: f64,
+    /// Seed for the random number generator, so simulations are reproducible.
+    pub seed: u64,
+}
+
+/// Distribution of a metric across every simulated path.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Distribution {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+    /// Lower bound of the confidence interval.
+    pub lower: f64,
+    /// Upper bound of the confidence interval.
+    pub upper: f64,
+}
+
+impl Distribution {
+    /// Calculates the [`Distribution`] of the provided samples, with a two-sided confidence
+    /// interval at the provided confidence level.
+    pub fn new(mut samples: Vec<f64>, confidence: f64) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        samples.sort_by(f64::total_cmp);
+
+        let summary = samples
+            .iter()
+            .fold(DataSummary::default(), |mut summary, sample| {
+                summary.update(*sample);
+                summary
+            });
+
+        let tail = (1.0 - confidence) / 2.0;
+
+        Self {
+            mean: summary.mean,
+            std_dev: summary.dispersion.std_dev,
+            min: samples[0],
+            median: percentile(&samples, 0.5),
+            max: samples[samples.len() - 1],
+            lower: percentile(&samples, tail),
+            upper: percentile(&samples, 1.0 - tail),
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted samples, where `0.0 <= quantile <= 1.0`.
+fn percentile(sorted: &[f64], quantile: f64) -> f64 {
+    let rank = quantile * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Monte Carlo simulation summary of the exited [`Position`] sequence.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MonteCarloSummary {
+    pub simulations: usize,
+    pub confidence: f64,
+    pub final_equity: Distribution,
+    /// Maximum drawdown of each path as a negative fraction of the peak equity.
+    pub max_drawdown: Distribution,
+    /// Sharpe Ratio per trade of each path.
+    pub sharpe_ratio: Distribution,
+    /// Fraction of paths that reached the ruin threshold.
+    pub risk_of_ruin: f64,
+}
+
+impl TableBuilder for MonteCarloSummary {
+    fn titles(&self) -> Row {
+        let confidence = format!("{:.0}%", self.confidence * 100.0);
+        row![
+            "Simulations",
+            "Final Equity Mean",
+            format!("Final Equity {confidence} CI"),
+            "Max Drawdown Mean",
+            format!("Max Drawdown {confidence} CI"),
+            "Sharpe Ratio Mean",
+            format!("Sharpe Ratio {confidence} CI"),
+            "Risk Of Ruin",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.simulations.to_string(),
+            format!("{:.3}", self.final_equity.mean),
+            format!(
+                "[{:.3}, {:.3}]",
+                self.final_equity.lower, self.final_equity.upper
+            ),
+            format!("{:.3}", self.max_drawdown.mean),
+            format!(
+                "[{:.3}, {:.3}]",
+                self.max_drawdown.lower, self.max_drawdown.upper
+            ),
+            format!("{:.3}", self.sharpe_ratio.mean),
+            format!(
+                "[{:.3}, {:.3}]",
+                self.sharpe_ratio.lower, self.sharpe_ratio.upper
+            ),
+            format!("{:.3}", self.risk_of_ruin),
+        ]
+    }
+}
+
+/// Trade values of an exited [`Position`] required to resimulate its profit & loss.
+#[derive(Copy, Clone, Debug)]
+struct Trade {
+    side: Side,
+    enter_value_gross: f64,
+    exit_value_gross: f64,
+    fees_total: f64,
+}
+
+impl From<&Position> for Trade {
+    fn from(position: &Position) -> Self {
+        Self {
+            side: position.side,
+            enter_value_gross: position.enter_value_gross,
+            exit_value_gross: position.exit_value_gross,
+            fees_total: position.enter_fees_total + position.exit_fees_total,
+        }
+    }
+}
+
+impl Trade {
+    /// Calculates the realised profit & loss of the [`Trade`] with the exit value & fees
+    /// scaled by the provided factors.
+    fn profit_loss(&self, exit_scale: f64, fees_scale: f64) -> f64 {
+        let exit_value_gross = self.exit_value_gross * exit_scale;
+        let fees_total = self.fees_total * fees_scale;
+
+        match self.side {
+            Side::Buy => exit_value_gross - self.enter_value_gross - fees_total,
+            Side::Sell => self.enter_value_gross - exit_value_gross - fees_total,
+        }
+    }
+}
+
+/// Outcome of a single simulated path.
+struct Path {
+    final_equity: f64,
+    max_drawdown: f64,
+    sharpe_ratio: f64,
+    ruined: bool,
+}
+
+/// Relative drawdown of the equity from it's peak. The peak is at least the positive starting
+/// equity, but a total drawdown is returned if it is not positive rather than flipping sign.
+fn drawdown(equity: f64, peak: f64) -> f64 {
+    match peak > 0.0 {
+        true => (equity - peak) / peak,
+        false => -1.0,
+    }
+}
+
+/// Run a Monte Carlo simulation by resampling the sequence of exited [`Position`]s, producing
+/// the [`Distribution`] of final equity, max drawdown & Sharpe Ratio, as well as the risk of ruin.
+pub fn simulate(
+    config: &Config,
+    positions: &[Position],
+) -> Result<MonteCarloSummary, StatisticError> {
+    if positions.is_empty() {
+        return Err(StatisticError::MonteCarloNoPositions);
+    }
+    if config.simulations == 0 {
+        return Err(StatisticError::MonteCarloInvalidConfig(
+            "simulations must be positive",
+        ));
+    }
+    if !(0.0..1.0).contains(&config.confidence) {
+        return Err(StatisticError::MonteCarloInvalidConfig(
+            "confidence must be within [0, 1)",
+        ));
+    }
+    if !(config.starting_equity.is_finite() && config.starting_equity > 0.0) {
+        return Err(StatisticError::MonteCarloInvalidConfig(
+            "starting_equity must be positive & finite",
+        ));
+    }
+
+    // Returns are relative to enter_value_gross, so it must be positive to be well defined
+    let trades = positions.iter().map(Trade::from).collect::<Vec<_>>();
+    if trades
+        .iter()
+        .any(|trade| !(trade.enter_value_gross.is_finite() && trade.enter_value_gross > 0.0))
+    {
+        return Err(StatisticError::MonteCarloInvalidPosition);
+    }
+    let mut rng = StdRng::seed_from_u64(config.seed);
+
+    let paths = (0..config.simulations)
+        .map(|_| simulate_path(config, &trades, &mut rng))
+        .collect::<Vec<_>>();
+
+    let ruined = paths.iter().filter(|path| path.ruined).count();
+
+    Ok(MonteCarloSummary {
+        simulations: config.simulations,
+        confidence: config.confidence,
+        final_equity: Distribution::new(
+            paths.iter().map(|path| path.final_equity).collect(),
+            config.confidence,
+        ),
+        max_drawdown: Distribution::new(
+            paths.iter().map(|path| path.max_drawdown).collect(),
+            config.confidence,
+        ),
+        sharpe_ratio: Distribution::new(
+            paths.iter().map(|path| path.sharpe_ratio).collect(),
+            config.confidence,
+        ),
+        risk_of_ruin: ruined as f64 / config.simulations as f64,
+    })
+}
+
+/// Simulate a single path of resampled, and optionally perturbed, [`Trade`]s.
+fn simulate_path(config: &Config, trades: &[Trade], rng: &mut StdRng) -> Path {
+    let resampled = match config.resample {
+        Resample::Shuffle => {
+            let mut shuffled = trades.to_vec();
+            shuffled.shuffle(rng);
+            shuffled
+        }
+        Resample::Bootstrap => (0..trades.len())
+            .map(|_| trades[rng.gen_range(0..trades.len())])
+            .collect(),
+    };
+
+    let ruin_equity = config.starting_equity * config.ruin_threshold;
+    let mut equity = config.starting_equity;
+    let mut peak = equity;
+    let mut max_drawdown = 0.0_f64;
+    let mut ruined = equity <= ruin_equity;
+    let mut returns = DataSummary::default();
+
+    for trade in resampled {
+        let (exit_scale, fees_scale) = match config.perturbation {
+            Some(perturbation) => (
+                1.0 + shock(rng, perturbation.price_pct),
+                1.0 + shock(rng, perturbation.fees_pct),
+            ),
+            None => (1.0, 1.0),
+        };
+
+        let profit_loss = trade.profit_loss(exit_scale, fees_scale);
+        returns.update(profit_loss / trade.enter_value_gross);
+
+        equity += profit_loss;
+        peak = peak.max(equity);
+        max_drawdown = max_drawdown.min(drawdown(equity, peak));
+        ruined |= equity <= ruin_equity;
+    }
+
+    let sharpe_ratio = match returns.dispersion.std_dev == 0.0 {
+        true => 0.0,
+        false => (returns.mean - config.risk_free_return) / returns.dispersion.std_dev,
+    };
+
+    Path {
+        final_equity: equity,
+        max_drawdown,
+        sharpe_ratio,
+        ruined,
+    }
+}
+
+/// Uniformly distributed shock within `[-max, max]`.
+fn shock(rng: &mut StdRng, max: f64) -> f64 {
+    match max > 0.0 {
+        true => rng.gen_range(-max..=max),
+        false => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+
+    fn trade(side: Side, enter_value_gross: f64, exit_value_gross: f64) -> Position {
+        let mut position = position();
+        position.side = side;
+        position.enter_value_gross = enter_value_gross;
+        position.exit_value_gross = exit_value_gross;
+        position.enter_fees_total = 0.0;
+        position.exit_fees_total = 0.0;
+        position
+    }
+
+    fn config(resample: Resample) -> Config {
+        Config {
+            simulations: 500,
+            resample,
+            perturbation: None,
+            starting_equity: 1000.0,
+            ruin_threshold: 0.5,
+            risk_free_return: 0.0,
+            confidence: 0.9,
+            seed: 42,
+        }
+    }
+
+    #[test]
+    fn test_distribution_new() {
+        struct TestCase {
+            samples: Vec<f64>,
+            confidence: f64,
+            expected: Distribution,
+        }
+
+        let cases = vec![
+            // TC0: no samples
+            TestCase {
+                samples: vec![],
+                confidence: 0.9,
+                expected: Distribution::default(),
+            },
+            // TC1: unsorted samples with interpolated percentiles
+            TestCase {
+                samples: vec![5.0, 1.0, 3.0, 2.0, 4.0],
+                confidence: 0.5,
+                expected: Distribution {
+                    mean: 3.0,
+                    std_dev: 2.0_f64.sqrt(),
+                    min: 1.0,
+                    median: 3.0,
+                    max: 5.0,
+                    lower: 2.0,
+                    upper: 4.0,
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Distribution::new(test.samples, test.confidence);
+            assert!(
+                (actual.mean - test.expected.mean).abs() < 1e-9
+                    && (actual.std_dev - test.expected.std_dev).abs() < 1e-9
+                    && actual.min == test.expected.min
+                    && actual.median == test.expected.median
+                    && actual.max == test.expected.max
+                    && actual.lower == test.expected.lower
+                    && actual.upper == test.expected.upper,
+                "TC{} failed: {:?}",
+                index,
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulate_shuffle_preserves_final_equity() {
+        let positions = vec![
+            trade(Side::Buy, 100.0, 150.0),
+            trade(Side::Buy, 100.0, 40.0),
+            trade(Side::Sell, 100.0, 80.0),
+            trade(Side::Sell, 100.0, 130.0),
+        ];
+
+        let summary = simulate(&config(Resample::Shuffle), &positions).unwrap();
+
+        // Shuffling reorders trades, so only the path (eg/ drawdown) varies
+        assert!((summary.final_equity.min - 980.0).abs() < 1e-9);
+        assert!((summary.final_equity.max - 980.0).abs() < 1e-9);
+        assert!(summary.max_drawdown.min < summary.max_drawdown.max);
+        assert!(summary.max_drawdown.max <= 0.0);
+        assert_eq!(summary.risk_of_ruin, 0.0);
+    }
+
+    #[test]
+    fn test_simulate_is_reproducible_with_seed() {
+        let positions = vec![
+            trade(Side::Buy, 100.0, 150.0),
+            trade(Side::Buy, 100.0, 40.0),
+            trade(Side::Sell, 100.0, 90.0),
+        ];
+        let config = Config {
+            perturbation: Some(Perturbation {
+                price_pct: 0.01,
+                fees_pct: 0.5,
+            }),
+            ..config(Resample::Bootstrap)
+        };
+
+        let first = simulate(&config, &positions).unwrap();
+        let second = simulate(&config, &positions).unwrap();
+        assert_eq!(first, second);
+
+        let reseeded = simulate(&Config { seed: 7, ..config }, &positions).unwrap();
+        assert_ne!(first, reseeded);
+    }
+
+    #[test]
+    fn test_simulate_risk_of_ruin() {
+        // Every trade loses 300, so the 2nd trade always breaches the 500 ruin threshold
+        let positions = vec![
+            trade(Side::Buy, 400.0, 100.0),
+            trade(Side::Buy, 400.0, 100.0),
+        ];
+
+        let summary = simulate(&config(Resample::Bootstrap), &positions).unwrap();
+        assert_eq!(summary.risk_of_ruin, 1.0);
+        assert!((summary.final_equity.mean - 400.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simulate_invalid_input() {
+        assert!(matches!(
+            simulate(&config(Resample::Shuffle), &[]),
+            Err(StatisticError::MonteCarloNoPositions)
+        ));
+        assert!(matches!(
+            simulate(
+                &Config {
+                    simulations: 0,
+                    ..config(Resample::Shuffle)
+                },
+                &[trade(Side::Buy, 100.0, 110.0)]
+            ),
+            Err(StatisticError::MonteCarloInvalidConfig(_))
+        ));
+        assert!(matches!(
+            simulate(
+                &Config {
+                    starting_equity: 0.0,
+                    ..config(Resample::Shuffle)
+                },
+                &[trade(Side::Buy, 100.0, 110.0)]
+            ),
+            Err(StatisticError::MonteCarloInvalidConfig(_))
+        ));
+        assert!(matches!(
+            simulate(
+                &config(Resample::Bootstrap),
+                &[trade(Side::Buy, 100.0, 110.0), trade(Side::Buy, 0.0, 10.0)]
+            ),
+            Err(StatisticError::MonteCarloInvalidPosition)
+        ));
+    }
+
+    #[test]
+    fn test_drawdown() {
+        struct TestCase {
+            equity: f64,
+            peak: f64,
+            expected: f64,
+        }
+
+        let cases = vec![
+            // TC0: Equity at it's peak
+            TestCase {
+                equity: 1000.0,
+                peak: 1000.0,
+                expected: 0.0,
+            },
+            // TC1: Equity below it's peak
+            TestCase {
+                equity: 750.0,
+                peak: 1000.0,
+                expected: -0.25,
+            },
+            // TC2: Negative equity exceeds a total drawdown
+            TestCase {
+                equity: -500.0,
+                peak: 1000.0,
+                expected: -1.5,
+            },
+            // TC3: Non-positive peak is a total drawdown rather than flipping sign
+            TestCase {
+                equity: -500.0,
+                peak: -100.0,
+                expected: -1.0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(
+                drawdown(test.equity, test.peak),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use crate::{
+    data::MarketMeta,
+    statistic::{
+        error::StatisticError,
+        metric::{ratio::calculate_daily, EquityPoint},
+        summary::{
+            data::DataSummary,
+            equity::{Config as EquityConfig, EquitySummary},
+            TableBuilder,
+        },
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::{instrument::market_data::MarketDataInstrument, market::Market};
+use chrono::{DateTime, Utc};
+use prettytable::Row;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Buy-and-hold benchmark of a single [`Market`], valued from the close price of the
+/// [`MarketEvent`]s it is updated with.
+///
+/// The benchmark equity is sampled on the same fixed schedule as the strategy
+/// [`EquitySummary`], so the two curves can be compared period by period via
+/// [`BenchmarkSummary::generate`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Benchmark {
+    /// [`Market`] held from the first observed price until the end of the session.
+    pub market: Market<MarketDataInstrument>,
+    /// Equity invested in the benchmark [`Market`] at the first observed price.
+    pub starting_equity: f64,
+    /// First observed close price, at which the benchmark [`Market`] is bought.
+    pub initial_price: Option<f64>,
+    /// Latest observed close price.
+    pub price: Option<f64>,
+    /// Benchmark equity curve sampled on a fixed schedule.
+    pub equity: EquitySummary,
+}
+
+impl Benchmark {
+    /// Constructs a new [`Benchmark`] holding the provided [`Market`], with the equity curve
+    /// starting at the provided [`EquityPoint`].
+    pub fn new(
+        market: Market<MarketDataInstrument>,
+        config: EquityConfig,
+        start: EquityPoint,
+    ) -> Result<Self, StatisticError> {
+        Ok(Self {
+            market,
+            starting_equity: start.total,
+            initial_price: None,
+            price: None,
+            equity: EquitySummary::new(config, start)?,
+        })
+    }
+
+    /// Updates the latest benchmark price if the [`MarketEvent`] is associated with the
+    /// benchmark [`Market`]. Returns true if the price was updated.
+    pub fn update_from_market(
+        &mut self,
+        market: &MarketEvent<MarketDataInstrument, DataKind>,
+    ) -> bool {
+        if market.exchange != self.market.exchange || market.instrument != self.market.instrument {
+            return false;
+        }
+
+        match MarketMeta::from_market_event(market) {
+            Some(meta) => {
+                self.initial_price.get_or_insert(meta.close);
+                self.price = Some(meta.close);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marks the benchmark equity to market at the provided time, updating the benchmark
+    /// [`EquitySummary`].
+    pub fn mark(&mut self, time: DateTime<Utc>) {
+        self.equity.update(EquityPoint {
+            time,
+            total: self.current_equity(),
+        });
+    }
+
+    /// Current value of the benchmark holding. Equal to the starting equity until the first
+    /// price is observed.
+    pub fn current_equity(&self) -> f64 {
+        match (self.initial_price, self.price) {
+            (Some(initial), Some(price)) if initial != 0.0 => {
+                self.starting_equity * price / initial
+            }
+            _ => self.starting_equity,
+        }
+    }
+}
+
+/// Statistics of strategy returns relative to the returns of a [`Benchmark`], calculated from
+/// paired returns of the same sampling periods.
+///
+/// Alpha, beta, correlation, tracking error & information ratio are per sampling period, with
+/// the daily equivalents used in the [`TableBuilder`] output.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct BenchmarkSummary {
+    /// Risk-free return per sampling period.
+    pub risk_free_return: f64,
+    /// Number of sampling periods per day, used to calculate daily statistics.
+    pub samples_per_day: f64,
+    pub strategy: DataSummary,
+    pub benchmark: DataSummary,
+    /// Strategy returns in excess of the benchmark returns.
+    pub active: DataSummary,
+    /// Sum of the products of strategy & benchmark return deviations from their means.
+    pub co_moment: f64,
+    /// Strategy returns in periods where the benchmark return was positive.
+    pub up_strategy: DataSummary,
+    /// Positive benchmark returns.
+    pub up_benchmark: DataSummary,
+    /// Strategy returns in periods where the benchmark return was negative.
+    pub down_strategy: DataSummary,
+    /// Negative benchmark returns.
+    pub down_benchmark: DataSummary,
+}
+
+impl BenchmarkSummary {
+    pub fn new(risk_free_return: f64, samples_per_day: f64) -> Self {
+        Self {
+            risk_free_return,
+            samples_per_day,
+            ..Self::default()
+        }
+    }
+
+    /// Generates a [`BenchmarkSummary`] from the strategy & benchmark equity curves, joining
+    /// the returns of periods sampled at the same times. Periods only present in one of the
+    /// curves (eg/ before the benchmark started) are skipped.
+    pub fn generate(strategy: &EquitySummary, benchmark: &EquitySummary) -> Self {
+        let mut summary = Self::new(
+            strategy.tear_sheet.sharpe_ratio.risk_free_return,
+            strategy.samples_per_day(),
+        );
+
+        let benchmark = benchmark
+            .curve
+            .iter()
+            .map(|point| (point.time, point))
+            .collect::<HashMap<_, _>>();
+
+        strategy
+            .curve
+            .windows(2)
+            .filter_map(|strategy| {
+                let previous = benchmark.get(&strategy[0].time)?;
+                let next = benchmark.get(&strategy[1].time)?;
+                Some((strategy, previous, next))
+            })
+            .for_each(|(strategy, previous, next)| {
+                summary.update(
+                    period_return(&strategy[0], &strategy[1]),
+                    period_return(previous, next),
+                )
+            });
+
+        summary
+    }
+
+    /// Updates the [`BenchmarkSummary`] with the strategy & benchmark returns of the next
+    /// sampling period.
+    pub fn update(&mut self, strategy_return: f64, benchmark_return: f64) {
+        // Covariance co-moment uses the strategy mean before & the benchmark mean after update
+        let strategy_deviation = strategy_return - self.strategy.mean;
+        self.benchmark.update(benchmark_return);
+        self.co_moment += strategy_deviation * (benchmark_return - self.benchmark.mean);
+        self.strategy.update(strategy_return);
+
+        self.active.update(strategy_return - benchmark_return);
+
+        if benchmark_return > 0.0 {
+            self.up_strategy.update(strategy_return);
+            self.up_benchmark.update(benchmark_return);
+        } else if benchmark_return < 0.0 {
+            self.down_strategy.update(strategy_return);
+            self.down_benchmark.update(benchmark_return);
+        }
+    }
+
+    /// Population covariance of the strategy & benchmark returns.
+    pub fn covariance(&self) -> f64 {
+        match self.strategy.count {
+            0 => 0.0,
+            count => self.co_moment / count as f64,
+        }
+    }
+
+    /// Sensitivity of the strategy returns to the benchmark returns.
+    pub fn beta(&self) -> f64 {
+        divide(self.covariance(), self.benchmark.dispersion.variance)
+    }
+
+    /// Jensen's alpha per sampling period, being the mean strategy return in excess of the
+    /// return explained by the benchmark.
+    pub fn alpha(&self) -> f64 {
+        (self.strategy.mean - self.risk_free_return)
+            - self.beta() * (self.benchmark.mean - self.risk_free_return)
+    }
+
+    /// Pearson correlation of the strategy & benchmark returns.
+    pub fn correlation(&self) -> f64 {
+        divide(
+            self.covariance(),
+            self.strategy.dispersion.std_dev * self.benchmark.dispersion.std_dev,
+        )
+    }
+
+    /// Standard deviation of the strategy returns in excess of the benchmark returns.
+    pub fn tracking_error(&self) -> f64 {
+        self.active.dispersion.std_dev
+    }
+
+    /// Mean strategy return in excess of the benchmark return per unit of tracking error.
+    pub fn information_ratio(&self) -> f64 {
+        divide(self.active.mean, self.tracking_error())
+    }
+
+    /// Mean strategy return as a fraction of the mean benchmark return in periods where the
+    /// benchmark return was positive.
+    pub fn up_capture(&self) -> f64 {
+        divide(self.up_strategy.mean, self.up_benchmark.mean)
+    }
+
+    /// Mean strategy return as a fraction of the mean benchmark return in periods where the
+    /// benchmark return was negative.
+    pub fn down_capture(&self) -> f64 {
+        divide(self.down_strategy.mean, self.down_benchmark.mean)
+    }
+}
+
+/// Return between two consecutive [`EquityPoint`]s.
+fn period_return(previous: &EquityPoint, next: &EquityPoint) -> f64 {
+    match previous.total == 0.0 {
+        true => 0.0,
+        false => next.total / previous.total - 1.0,
+    }
+}
+
+/// Divides the numerator by the denominator, returning zero if the denominator is zero.
+fn divide(numerator: f64, denominator: f64) -> f64 {
+    match denominator == 0.0 {
+        true => 0.0,
+        false => numerator / denominator,
+    }
+}
+
+impl TableBuilder for BenchmarkSummary {
+    fn titles(&self) -> Row {
+        row![
+            "Periods",
+            "Daily Alpha",
+            "Beta",
+            "Correlation",
+            "Daily Tracking Error",
+            "Daily Information Ratio",
+            "Up Capture",
+            "Down Capture",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.strategy.count,
+            format!("{:.6}", self.alpha() * self.samples_per_day),
+            format!("{:.3}", self.beta()),
+            format!("{:.3}", self.correlation()),
+            format!("{:.6}", self.tracking_error() * self.samples_per_day.sqrt()),
+            format!(
+                "{:.3}",
+                calculate_daily(self.information_ratio(), self.samples_per_day)
+            ),
+            format!("{:.3}", self.up_capture()),
+            format!("{:.3}", self.down_capture()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_instrument::{
+        exchange::ExchangeId, instrument::market_data::kind::MarketDataInstrumentKind,
+    };
+    use barter_integration::Side;
+    use chrono::{Duration, TimeZone};
+
+    fn time(hours: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hours)
+    }
+
+    fn equity(totals: &[f64]) -> EquitySummary {
+        equity_from(0, totals)
+    }
+
+    fn equity_from(start_hours: i64, totals: &[f64]) -> EquitySummary {
+        let config = EquityConfig {
+            interval: Duration::hours(1),
+            risk_free_return: 0.0,
+        };
+
+        let mut summary = EquitySummary::new(
+            config,
+            EquityPoint {
+                time: time(start_hours),
+                total: totals[0],
+            },
+        )
+        .unwrap();
+
+        totals
+            .iter()
+            .enumerate()
+            .skip(1)
+            .for_each(|(hours, total)| {
+                summary.update(EquityPoint {
+                    time: time(start_hours + hours as i64),
+                    total: *total,
+                })
+            });
+
+        summary
+    }
+
+    #[test]
+    fn test_benchmark_summary_generate() {
+        struct TestCase {
+            strategy: Vec<f64>,
+            benchmark: Vec<f64>,
+            expected_alpha: f64,
+            expected_beta: f64,
+            expected_correlation: f64,
+            expected_tracking_error: f64,
+            expected_up_capture: f64,
+            expected_down_capture: f64,
+        }
+
+        let cases = vec![
+            // TC0: strategy identical to the benchmark
+            TestCase {
+                strategy: vec![100.0, 110.0, 99.0, 108.9],
+                benchmark: vec![100.0, 110.0, 99.0, 108.9],
+                expected_alpha: 0.0,
+                expected_beta: 1.0,
+                expected_correlation: 1.0,
+                expected_tracking_error: 0.0,
+                expected_up_capture: 1.0,
+                expected_down_capture: 1.0,
+            },
+            // TC1: strategy returns are double the benchmark returns
+            // benchmark returns: [0.1, -0.1, 0.1], strategy returns: [0.2, -0.2, 0.2]
+            TestCase {
+                strategy: vec![100.0, 120.0, 96.0, 115.2],
+                benchmark: vec![100.0, 110.0, 99.0, 108.9],
+                expected_alpha: 0.0,
+                expected_beta: 2.0,
+                expected_correlation: 1.0,
+                expected_tracking_error: (2.0_f64 / 225.0).sqrt(),
+                expected_up_capture: 2.0,
+                expected_down_capture: 2.0,
+            },
+            // TC2: flat strategy is uncorrelated with the benchmark
+            TestCase {
+                strategy: vec![100.0, 100.0, 100.0, 100.0],
+                benchmark: vec![100.0, 110.0, 99.0, 108.9],
+                expected_alpha: 0.0,
+                expected_beta: 0.0,
+                expected_correlation: 0.0,
+                expected_tracking_error: (2.0_f64 / 225.0).sqrt(),
+                expected_up_capture: 0.0,
+                expected_down_capture: 0.0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                BenchmarkSummary::generate(&equity(&test.strategy), &equity(&test.benchmark));
+
+            assert_eq!(actual.strategy.count, 3, "TC{} failed", index);
+            for (actual, expected) in [
+                (actual.alpha(), test.expected_alpha),
+                (actual.beta(), test.expected_beta),
+                (actual.correlation(), test.expected_correlation),
+                (actual.tracking_error(), test.expected_tracking_error),
+                (actual.up_capture(), test.expected_up_capture),
+                (actual.down_capture(), test.expected_down_capture),
+            ] {
+                assert!(
+                    (actual - expected).abs() < 1e-9,
+                    "TC{} failed: {} != {}",
+                    index,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_benchmark_summary_generate_joins_misaligned_curves() {
+        struct TestCase {
+            strategy: EquitySummary,
+            benchmark: EquitySummary,
+            expected_periods: u64,
+            expected_beta: f64,
+        }
+
+        let cases = vec![
+            // TC0: benchmark curve starts one period after the strategy curve
+            TestCase {
+                strategy: equity(&[100.0, 120.0, 96.0, 115.2]),
+                benchmark: equity_from(1, &[110.0, 99.0, 108.9]),
+                expected_periods: 2,
+                expected_beta: 2.0,
+            },
+            // TC1: strategy curve starts one period after the benchmark curve
+            TestCase {
+                strategy: equity_from(1, &[120.0, 96.0, 115.2]),
+                benchmark: equity(&[100.0, 110.0, 99.0, 108.9]),
+                expected_periods: 2,
+                expected_beta: 2.0,
+            },
+            // TC2: curves do not overlap
+            TestCase {
+                strategy: equity(&[100.0, 120.0]),
+                benchmark: equity_from(5, &[100.0, 110.0]),
+                expected_periods: 0,
+                expected_beta: 0.0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BenchmarkSummary::generate(&test.strategy, &test.benchmark);
+            assert_eq!(
+                actual.strategy.count, test.expected_periods,
+                "TC{} failed",
+                index
+            );
+            assert!(
+                (actual.beta() - test.expected_beta).abs() < 1e-9,
+                "TC{} failed: {} != {}",
+                index,
+                actual.beta(),
+                test.expected_beta
+            );
+        }
+    }
+
+    #[test]
+    fn test_benchmark_summary_alpha_and_information_ratio() {
+        // Strategy outperforms the benchmark by 1% every period, so beta is 1 & alpha is 1%
+        let mut summary = BenchmarkSummary::new(0.0, 24.0);
+        for benchmark_return in [0.02, -0.01, 0.03, -0.02] {
+            summary.update(benchmark_return + 0.01, benchmark_return);
+        }
+
+        assert!((summary.beta() - 1.0).abs() < 1e-12);
+        assert!((summary.alpha() - 0.01).abs() < 1e-12);
+        assert!(summary.tracking_error() < 1e-12);
+        assert!((summary.up_capture() - 0.035 / 0.025).abs() < 1e-12);
+        assert!((summary.down_capture() - -0.005 / -0.015).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_benchmark_update_from_market_and_mark() {
+        let config = EquityConfig {
+            interval: Duration::hours(1),
+            risk_free_return: 0.0,
+        };
+        let mut benchmark = Benchmark::new(
+            Market::new(
+                ExchangeId::BinanceSpot,
+                MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot)),
+            ),
+            config,
+            EquityPoint {
+                time: time(0),
+                total: 1000.0,
+            },
+        )
+        .unwrap();
+
+        // Benchmark equity remains at the starting equity until the first price is observed
+        benchmark.mark(time(1));
+        assert_eq!(benchmark.current_equity(), 1000.0);
+
+        // Unrelated Market is ignored
+        let mut event = market_event_trade(Side::Buy);
+        event.exchange = ExchangeId::Kraken;
+        assert!(!benchmark.update_from_market(&event));
+
+        // First price is the buy price, subsequent prices revalue the holding
+        let mut event = market_event_trade(Side::Buy);
+        event.exchange = ExchangeId::BinanceSpot;
+        event.instrument = benchmark.market.instrument.clone();
+        if let DataKind::Trade(trade) = &mut event.kind {
+            trade.price = 100.0;
+        }
+        assert!(benchmark.update_from_market(&event));
+
+        if let DataKind::Trade(trade) = &mut event.kind {
+            trade.price = 150.0;
+        }
+        assert!(benchmark.update_from_market(&event));
+        benchmark.mark(time(2));
+
+        assert_eq!(benchmark.initial_price, Some(100.0));
+        assert_eq!(benchmark.current_equity(), 1500.0);
+        assert_eq!(
+            benchmark
+                .equity
+                .curve
+                .iter()
+                .map(|point| point.total)
+                .collect::<Vec<_>>(),
+            vec![1000.0, 1000.0, 1500.0]
+        );
+    }
+}
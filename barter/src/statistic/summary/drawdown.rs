@@ -26,11 +26,7 @@ impl PositionSummariser for DrawdownSummary {
             Some(exit_balance) => EquityPoint::from(exit_balance),
         };
 
-        // Updates
-        if let Some(ended_drawdown) = self.current_drawdown.update(equity_point) {
-            self.avg_drawdown.update(&ended_drawdown);
-            self.max_drawdown.update(&ended_drawdown);
-        }
+        self.update_equity(equity_point)
     }
 }
 
@@ -62,4 +58,12 @@ impl DrawdownSummary {
             max_drawdown: MaxDrawdown::init(),
         }
     }
+
+    /// Updates the [`DrawdownSummary`] using the next [`EquityPoint`] of the Portfolio.
+    pub fn update_equity(&mut self, equity_point: EquityPoint) {
+        if let Some(ended_drawdown) = self.current_drawdown.update(equity_point) {
+            self.avg_drawdown.update(&ended_drawdown);
+            self.max_drawdown.update(&ended_drawdown);
+        }
+    }
 }
@@ -0,0 +1,299 @@
+use crate::statistic::{
+    de_duration_from_secs,
+    error::StatisticError,
+    metric::EquityPoint,
+    se_duration_as_secs,
+    summary::{data::DataSummary, drawdown::DrawdownSummary, trading::TearSheet, TableBuilder},
+};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::{Cell, Row};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for constructing an [`EquitySummary`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Fixed interval the mark-to-market equity curve is sampled at (eg/ 1 hour or 1 day).
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub interval: Duration,
+    /// Risk-free return per interval used to calculate the [`TearSheet`] ratios.
+    pub risk_free_return: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            interval: Duration::days(1),
+            risk_free_return: 0.0,
+        }
+    }
+}
+
+/// Portfolio equity curve sampled on a fixed schedule, where the equity is marked-to-market
+/// including the unrealised profit & loss of open Positions.
+///
+/// Returns, ratios & drawdowns are calculated from the fixed interval samples, rather than per
+/// exited Position, so they are independent of trade count and include unrealised losses.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct EquitySummary {
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub interval: Duration,
+    /// Latest mark-to-market equity observed.
+    pub current: EquityPoint,
+    /// Equity sampled at every interval since the starting [`EquityPoint`].
+    pub curve: Vec<EquityPoint>,
+    /// Returns between consecutive samples of the equity curve.
+    pub returns: DataSummary,
+    /// Negative returns between consecutive samples of the equity curve.
+    pub losses: DataSummary,
+    pub drawdown: DrawdownSummary,
+    pub tear_sheet: TearSheet,
+}
+
+impl EquitySummary {
+    /// Constructs a new [`EquitySummary`] with the curve starting at the provided [`EquityPoint`].
+    pub fn new(config: Config, start: EquityPoint) -> Result<Self, StatisticError> {
+        if config.interval <= Duration::zero() {
+            return Err(StatisticError::EquityIntervalNotPositive);
+        }
+
+        let mut drawdown = DrawdownSummary::new(start.total);
+        drawdown.current_drawdown.start_time = start.time;
+        drawdown.max_drawdown.drawdown.start_time = start.time;
+
+        Ok(Self {
+            interval: config.interval,
+            current: start,
+            curve: vec![start],
+            returns: DataSummary::default(),
+            losses: DataSummary::default(),
+            drawdown,
+            tear_sheet: TearSheet::new(config.risk_free_return),
+        })
+    }
+
+    /// Updates the [`EquitySummary`] with the latest mark-to-market [`EquityPoint`], sampling
+    /// the equity held at every interval boundary elapsed since the previous sample.
+    pub fn update(&mut self, equity: EquityPoint) {
+        loop {
+            let next_sample_time = self.next_sample_time();
+
+            if next_sample_time > equity.time {
+                break;
+            }
+
+            // Equity observed exactly on the interval boundary is included in that sample
+            if next_sample_time == equity.time {
+                self.current = equity;
+            }
+
+            self.sample(next_sample_time);
+        }
+
+        self.current = equity;
+    }
+
+    /// Time of the next scheduled sample of the equity curve.
+    pub fn next_sample_time(&self) -> DateTime<Utc> {
+        self.curve
+            .last()
+            .map(|last| last.time + self.interval)
+            .unwrap_or(self.current.time)
+    }
+
+    /// Number of equity curve samples per day, used to calculate daily ratios.
+    pub fn samples_per_day(&self) -> f64 {
+        Duration::days(1).num_seconds() as f64 / self.interval.num_seconds() as f64
+    }
+
+    /// Sample the current equity at the provided time, updating the returns, drawdowns & ratios.
+    fn sample(&mut self, time: DateTime<Utc>) {
+        let sample = EquityPoint {
+            time,
+            total: self.current.total,
+        };
+
+        if let Some(previous) = self.curve.last() {
+            let sample_return = match previous.total == 0.0 {
+                true => 0.0,
+                false => sample.total / previous.total - 1.0,
+            };
+
+            self.returns.update(sample_return);
+            if sample_return < 0.0 {
+                self.losses.update(sample_return);
+            }
+        }
+
+        self.curve.push(sample);
+        self.drawdown.update_equity(sample);
+        self.tear_sheet.update_from_returns(
+            &self.returns,
+            &self.losses,
+            self.drawdown.max_drawdown.drawdown.drawdown,
+            self.samples_per_day(),
+        );
+    }
+}
+
+impl TableBuilder for EquitySummary {
+    fn titles(&self) -> Row {
+        let mut titles = vec![
+            Cell::new("Interval Secs"),
+            Cell::new("Samples"),
+            Cell::new("Equity"),
+            Cell::new("Return Mean"),
+            Cell::new("Return Std. Dev"),
+        ];
+
+        titles.extend(self.tear_sheet.titles().iter().cloned());
+        titles.extend(self.drawdown.titles().iter().cloned());
+
+        Row::new(titles)
+    }
+
+    fn row(&self) -> Row {
+        let mut cells = vec![
+            Cell::new(&self.interval.num_seconds().to_string()),
+            Cell::new(&self.curve.len().to_string()),
+            Cell::new(&format!("{:.3}", self.current.total)),
+            Cell::new(&format!("{:.6}", self.returns.mean)),
+            Cell::new(&format!("{:.6}", self.returns.dispersion.std_dev)),
+        ];
+
+        cells.extend(self.tear_sheet.row().iter().cloned());
+        cells.extend(self.drawdown.row().iter().cloned());
+
+        Row::new(cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn time(hour: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hour)
+    }
+
+    fn point(hour: i64, total: f64) -> EquityPoint {
+        EquityPoint {
+            time: time(hour),
+            total,
+        }
+    }
+
+    fn summary() -> EquitySummary {
+        EquitySummary::new(
+            Config {
+                interval: Duration::hours(1),
+                risk_free_return: 0.0,
+            },
+            point(0, 100.0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_equity_summary_update_samples_fixed_schedule() {
+        struct TestCase {
+            input: EquityPoint,
+            expected_curve: Vec<EquityPoint>,
+        }
+
+        let mut summary = summary();
+
+        let cases = vec![
+            // TC0: update within the first interval is not sampled
+            TestCase {
+                input: EquityPoint {
+                    time: time(0) + Duration::minutes(30),
+                    total: 90.0,
+                },
+                expected_curve: vec![point(0, 100.0)],
+            },
+            // TC1: update on the interval boundary is included in that sample
+            TestCase {
+                input: point(1, 110.0),
+                expected_curve: vec![point(0, 100.0), point(1, 110.0)],
+            },
+            // TC2: update after several intervals forward fills the previous equity
+            TestCase {
+                input: EquityPoint {
+                    time: time(3) + Duration::minutes(30),
+                    total: 99.0,
+                },
+                expected_curve: vec![
+                    point(0, 100.0),
+                    point(1, 110.0),
+                    point(2, 110.0),
+                    point(3, 110.0),
+                ],
+            },
+            // TC3: next boundary samples the latest equity
+            TestCase {
+                input: point(4, 120.0),
+                expected_curve: vec![
+                    point(0, 100.0),
+                    point(1, 110.0),
+                    point(2, 110.0),
+                    point(3, 110.0),
+                    point(4, 120.0),
+                ],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            summary.update(test.input);
+            assert_eq!(summary.curve, test.expected_curve, "TC{} failed", index);
+            assert_eq!(summary.current, test.input, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_equity_summary_returns_and_drawdown() {
+        let mut summary = summary();
+
+        // Unrealised loss at hour 1 is captured, recovering to a new peak at hour 3
+        summary.update(point(1, 80.0));
+        summary.update(point(2, 100.0));
+        summary.update(point(3, 120.0));
+
+        let returns = [-0.2, 0.25, 0.2];
+        let mean = returns.iter().sum::<f64>() / 3.0;
+
+        assert_eq!(summary.returns.count, 3);
+        assert!((summary.returns.mean - mean).abs() < 1e-12);
+        assert_eq!(summary.losses.count, 1);
+        assert!((summary.drawdown.max_drawdown.drawdown.drawdown - -0.2).abs() < 1e-12);
+        assert_eq!(summary.samples_per_day(), 24.0);
+
+        let expected_sharpe = mean / summary.returns.dispersion.std_dev;
+        assert!(
+            (summary.tear_sheet.sharpe_ratio.sharpe_ratio_per_trade - expected_sharpe).abs()
+                < 1e-12
+        );
+        assert!(
+            (summary.tear_sheet.calmar_ratio.calmar_ratio_per_trade - mean / 0.2).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn test_equity_summary_new_invalid_interval() {
+        let config = Config {
+            interval: Duration::zero(),
+            risk_free_return: 0.0,
+        };
+
+        assert!(matches!(
+            EquitySummary::new(config, point(0, 100.0)),
+            Err(StatisticError::EquityIntervalNotPositive)
+        ));
+    }
+}
@@ -1,15 +1,26 @@
+pub mod benchmark;
 pub mod data;
 pub mod drawdown;
+pub mod equity;
 pub mod pnl;
+pub mod position;
+pub mod rolling;
 pub mod trading;
 
 use crate::portfolio::position::Position;
+use chrono::{DateTime, Utc};
 use prettytable::{Cell, Row, Table};
 use smol_str::SmolStr;
 
-pub trait Initialiser {
+pub trait Initialiser: Sized {
     type Config: Copy;
     fn init(config: Self::Config) -> Self;
+
+    /// Initialise at the provided time, typically sourced from a
+    /// [`Clock`](crate::clock::Clock). Defaults to [`Initialiser::init`].
+    fn init_at(config: Self::Config, _time: DateTime<Utc>) -> Self {
+        Self::init(config)
+    }
 }
 
 pub trait PositionSummariser: Copy {
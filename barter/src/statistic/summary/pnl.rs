@@ -29,6 +29,13 @@ impl Initialiser for PnLReturnSummary {
     fn init(_: Self::Config) -> Self {
         Self::default()
     }
+
+    fn init_at(_: Self::Config, time: DateTime<Utc>) -> Self {
+        Self {
+            time,
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for PnLReturnSummary {
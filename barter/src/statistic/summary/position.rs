@@ -0,0 +1,305 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        de_duration_from_secs, se_duration_as_secs,
+        summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::Row;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for initialising a [`PositionSummary`] via the init() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Equity used to normalise the turnover & fee drag.
+    pub starting_equity: f64,
+}
+
+/// Summary of how exited [`Position`]s were held, including their maximum adverse excursion
+/// (MAE) & maximum favourable excursion (MFE), holding time, market exposure, turnover & fee drag.
+///
+/// Excursions are recorded as returns relative to the [`Position`] enter value.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionSummary {
+    pub starting_equity: f64,
+    /// Start of the trading session used to calculate the market exposure.
+    pub start_time: DateTime<Utc>,
+    /// Exit time of the latest exited [`Position`].
+    pub end_time: DateTime<Utc>,
+    pub max_adverse_excursion: DataSummary,
+    pub max_favourable_excursion: DataSummary,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub holding_duration: Duration,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub time_in_profit: Duration,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub time_in_loss: Duration,
+    /// Total gross value traded entering & exiting [`Position`]s.
+    pub traded_value_gross: f64,
+    /// Total fees incurred entering & exiting [`Position`]s.
+    pub fees_total: f64,
+}
+
+impl Initialiser for PositionSummary {
+    type Config = Config;
+
+    fn init(config: Self::Config) -> Self {
+        Self::init_at(config, Utc::now())
+    }
+
+    fn init_at(config: Self::Config, time: DateTime<Utc>) -> Self {
+        Self {
+            starting_equity: config.starting_equity,
+            start_time: time,
+            end_time: time,
+            max_adverse_excursion: DataSummary::default(),
+            max_favourable_excursion: DataSummary::default(),
+            holding_duration: Duration::zero(),
+            time_in_profit: Duration::zero(),
+            time_in_loss: Duration::zero(),
+            traded_value_gross: 0.0,
+            fees_total: 0.0,
+        }
+    }
+}
+
+impl PositionSummariser for PositionSummary {
+    fn update(&mut self, position: &Position) {
+        // Only update PositionSummary with exited Positions
+        let exit_time = match position.meta.exit_balance {
+            None => return,
+            Some(exit_balance) => exit_balance.time,
+        };
+
+        self.end_time = self.end_time.max(exit_time);
+
+        // Excursions as returns relative to the Position enter value
+        self.max_adverse_excursion
+            .update(position.excursion.max_adverse / position.enter_value_gross);
+        self.max_favourable_excursion
+            .update(position.excursion.max_favourable / position.enter_value_gross);
+
+        // Holding durations
+        self.holding_duration += position.holding_duration();
+        self.time_in_profit += position.excursion.time_in_profit;
+        self.time_in_loss += position.excursion.time_in_loss;
+
+        // Turnover & fees
+        self.traded_value_gross += position.enter_value_gross + position.exit_value_gross;
+        self.fees_total += position.enter_fees_total + position.exit_fees_total;
+    }
+}
+
+impl PositionSummary {
+    /// Average duration an exited [`Position`] was held for.
+    pub fn avg_holding_duration(&self) -> Duration {
+        match self.max_adverse_excursion.count {
+            0 => Duration::zero(),
+            count => self.holding_duration / count as i32,
+        }
+    }
+
+    /// Fraction of the trading session spent holding a [`Position`].
+    pub fn exposure(&self) -> f64 {
+        fraction_of(
+            self.holding_duration,
+            self.end_time.signed_duration_since(self.start_time),
+        )
+    }
+
+    /// Fraction of the holding duration spent with a positive gross excursion.
+    pub fn time_in_profit_pct(&self) -> f64 {
+        fraction_of(self.time_in_profit, self.holding_duration)
+    }
+
+    /// Total gross value traded as a multiple of the starting equity.
+    pub fn turnover(&self) -> f64 {
+        match self.starting_equity == 0.0 {
+            true => 0.0,
+            false => self.traded_value_gross / self.starting_equity,
+        }
+    }
+
+    /// Total fees incurred as a fraction of the starting equity.
+    pub fn fee_drag(&self) -> f64 {
+        match self.starting_equity == 0.0 {
+            true => 0.0,
+            false => self.fees_total / self.starting_equity,
+        }
+    }
+}
+
+/// Calculates the fraction of the total [`Duration`] represented by the part [`Duration`].
+fn fraction_of(part: Duration, total: Duration) -> f64 {
+    match total.num_seconds() {
+        0 => 0.0,
+        total => part.num_seconds() as f64 / total as f64,
+    }
+}
+
+impl TableBuilder for PositionSummary {
+    fn titles(&self) -> Row {
+        row![
+            "Avg. MAE",
+            "Worst MAE",
+            "Avg. MFE",
+            "Best MFE",
+            "Avg. Holding Hours",
+            "Time In Profit",
+            "Exposure",
+            "Turnover",
+            "Fee Drag",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.max_adverse_excursion.mean),
+            format!("{:.3}", self.max_adverse_excursion.dispersion.range.low),
+            format!("{:.3}", self.max_favourable_excursion.mean),
+            format!("{:.3}", self.max_favourable_excursion.dispersion.range.high),
+            format!(
+                "{:.3}",
+                self.avg_holding_duration().num_seconds() as f64 / 3600.0
+            ),
+            format!("{:.3}", self.time_in_profit_pct()),
+            format!("{:.3}", self.exposure()),
+            format!("{:.3}", self.turnover()),
+            format!("{:.3}", self.fee_drag()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{position::PositionExcursion, Balance},
+        test_util::position,
+    };
+    use chrono::TimeZone;
+
+    fn time(hours: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hours)
+    }
+
+    fn exited_position(
+        enter: i64,
+        exit: i64,
+        max_adverse: f64,
+        max_favourable: f64,
+        time_in_profit: i64,
+    ) -> Position {
+        let mut position = position();
+        position.meta.enter_time = time(enter);
+        position.meta.update_time = time(exit);
+        position.meta.exit_balance = Some(Balance {
+            time: time(exit),
+            total: 0.0,
+            available: 0.0,
+        });
+        position.enter_value_gross = 100.0;
+        position.exit_value_gross = 110.0;
+        position.enter_fees_total = 1.0;
+        position.exit_fees_total = 1.0;
+        position.excursion = PositionExcursion {
+            max_adverse,
+            max_favourable,
+            time_in_profit: Duration::hours(time_in_profit),
+            time_in_loss: Duration::hours(exit - enter - time_in_profit),
+        };
+        position
+    }
+
+    #[test]
+    fn test_position_summary_update() {
+        struct TestCase {
+            input: Position,
+            expected_avg_mae: f64,
+            expected_avg_mfe: f64,
+            expected_avg_holding_hours: i64,
+            expected_exposure: f64,
+            expected_turnover: f64,
+        }
+
+        let mut summary = PositionSummary::init_at(
+            Config {
+                starting_equity: 1000.0,
+            },
+            time(0),
+        );
+
+        let cases = vec![
+            // TC0: open Position is ignored
+            TestCase {
+                input: position(),
+                expected_avg_mae: 0.0,
+                expected_avg_mfe: 0.0,
+                expected_avg_holding_hours: 0,
+                expected_exposure: 0.0,
+                expected_turnover: 0.0,
+            },
+            // TC1: Position held for the second half of the session
+            TestCase {
+                input: exited_position(2, 4, -5.0, 10.0, 1),
+                expected_avg_mae: -0.05,
+                expected_avg_mfe: 0.1,
+                expected_avg_holding_hours: 2,
+                expected_exposure: 0.5,
+                expected_turnover: 0.21,
+            },
+            // TC2: Position held for 4 of the 10 session hours
+            TestCase {
+                input: exited_position(6, 10, -15.0, 20.0, 3),
+                expected_avg_mae: -0.1,
+                expected_avg_mfe: 0.15,
+                expected_avg_holding_hours: 3,
+                expected_exposure: 0.6,
+                expected_turnover: 0.42,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            summary.update(&test.input);
+            assert!(
+                (summary.max_adverse_excursion.mean - test.expected_avg_mae).abs() < 1e-10,
+                "TC{} failed",
+                index
+            );
+            assert!(
+                (summary.max_favourable_excursion.mean - test.expected_avg_mfe).abs() < 1e-10,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                summary.avg_holding_duration(),
+                Duration::hours(test.expected_avg_holding_hours),
+                "TC{} failed",
+                index
+            );
+            assert!(
+                (summary.exposure() - test.expected_exposure).abs() < 1e-10,
+                "TC{} failed",
+                index
+            );
+            assert!(
+                (summary.turnover() - test.expected_turnover).abs() < 1e-10,
+                "TC{} failed",
+                index
+            );
+        }
+
+        assert!((summary.time_in_profit_pct() - 4.0 / 6.0).abs() < 1e-10);
+        assert!((summary.fee_drag() - 0.004).abs() < 1e-10);
+    }
+}
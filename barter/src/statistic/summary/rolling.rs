@@ -0,0 +1,430 @@
+use crate::{
+    event::{MessageTransmitter, MetricTx},
+    portfolio::position::Position,
+    statistic::{
+        de_duration_from_secs,
+        error::StatisticError,
+        se_duration_as_secs,
+        summary::{data::DataSummary, TableBuilder},
+    },
+};
+use barter_integration::metric::{Field, Metric, Tag};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::Row;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+/// Window of exited [`Position`] returns a [`RollingSummary`] is calculated over.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Window {
+    /// Returns of the last N exited [`Position`]s.
+    Trades(usize),
+    /// Returns of the [`Position`]s exited within the last [`Duration`].
+    Duration(
+        #[serde(
+            deserialize_with = "de_duration_from_secs",
+            serialize_with = "se_duration_as_secs"
+        )]
+        Duration,
+    ),
+}
+
+impl Window {
+    /// Label used to tag the [`Metric`]s published for this [`Window`] (eg/ "20_trades").
+    pub fn label(&self) -> String {
+        match self {
+            Window::Trades(trades) => format!("{trades}_trades"),
+            Window::Duration(duration) => format!("{}s", duration.num_seconds()),
+        }
+    }
+}
+
+/// Configuration for constructing a [`RollingSummary`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub window: Window,
+    /// Risk-free return per trade used to calculate the rolling Sharpe Ratio.
+    pub risk_free_return: f64,
+}
+
+/// Rolling-window statistics of exited [`Position`] returns, including the mean return,
+/// volatility, Sharpe Ratio, win rate & drawdown of the most recent trades.
+///
+/// Unlike the session summaries, returns leaving the [`Window`] no longer contribute, so the
+/// statistics reflect the current performance of a live trading session.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RollingSummary {
+    pub window: Window,
+    pub risk_free_return: f64,
+    /// Exit time & return of every exited [`Position`] within the [`Window`].
+    pub returns: VecDeque<(DateTime<Utc>, f64)>,
+    /// Returns within the [`Window`].
+    pub summary: DataSummary,
+    /// Number of non-negative returns within the [`Window`].
+    pub wins: u64,
+    /// Largest drawdown of the returns compounded across the [`Window`].
+    pub max_drawdown: f64,
+    /// Drawdown of the returns compounded across the [`Window`] at the latest return.
+    pub current_drawdown: f64,
+}
+
+impl RollingSummary {
+    /// Constructs a new [`RollingSummary`] with an empty [`Window`].
+    pub fn new(config: Config) -> Result<Self, StatisticError> {
+        match config.window {
+            Window::Trades(0) => return Err(StatisticError::RollingWindowEmpty),
+            Window::Duration(duration) if duration <= Duration::zero() => {
+                return Err(StatisticError::RollingWindowEmpty)
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            window: config.window,
+            risk_free_return: config.risk_free_return,
+            returns: VecDeque::new(),
+            summary: DataSummary::default(),
+            wins: 0,
+            max_drawdown: 0.0,
+            current_drawdown: 0.0,
+        })
+    }
+
+    /// Updates the [`RollingSummary`] with an exited [`Position`]. Open [`Position`]s are ignored.
+    pub fn update(&mut self, position: &Position) {
+        if let Some(exit_balance) = position.meta.exit_balance {
+            self.update_return(exit_balance.time, position.calculate_profit_loss_return());
+        }
+    }
+
+    /// Updates the [`RollingSummary`] with the return of a trade exited at the provided time,
+    /// evicting any returns that have left the [`Window`].
+    pub fn update_return(&mut self, time: DateTime<Utc>, pnl_return: f64) {
+        self.returns.push_back((time, pnl_return));
+
+        match self.window {
+            Window::Trades(trades) => {
+                while self.returns.len() > trades {
+                    self.returns.pop_front();
+                }
+            }
+            Window::Duration(duration) => {
+                let window_start = time - duration;
+                while let Some((exit_time, _)) = self.returns.front() {
+                    if *exit_time > window_start {
+                        break;
+                    }
+                    self.returns.pop_front();
+                }
+            }
+        }
+
+        self.calculate();
+    }
+
+    /// Re-calculates the statistics of the returns within the [`Window`].
+    fn calculate(&mut self) {
+        self.summary = DataSummary::default();
+        self.wins = 0;
+        self.max_drawdown = 0.0;
+        self.current_drawdown = 0.0;
+
+        let mut equity = 1.0;
+        let mut peak = 1.0_f64;
+
+        for (_, pnl_return) in self.returns.iter() {
+            self.summary.update(*pnl_return);
+            if !pnl_return.is_sign_negative() {
+                self.wins += 1;
+            }
+
+            // Drawdown of the returns compounded from the start of the Window
+            equity *= 1.0 + pnl_return;
+            peak = peak.max(equity);
+            self.current_drawdown = equity / peak - 1.0;
+            self.max_drawdown = self.max_drawdown.min(self.current_drawdown);
+        }
+    }
+
+    /// Standard deviation of the returns within the [`Window`].
+    pub fn volatility(&self) -> f64 {
+        self.summary.dispersion.std_dev
+    }
+
+    /// Sharpe Ratio per trade of the returns within the [`Window`].
+    pub fn sharpe_ratio(&self) -> f64 {
+        match self.volatility() == 0.0 {
+            true => 0.0,
+            false => (self.summary.mean - self.risk_free_return) / self.volatility(),
+        }
+    }
+
+    /// Fraction of the returns within the [`Window`] that are non-negative.
+    pub fn win_rate(&self) -> f64 {
+        match self.summary.count {
+            0 => 0.0,
+            count => self.wins as f64 / count as f64,
+        }
+    }
+
+    /// Generates a rolling statistics [`Metric`] at the provided time, tagged with the
+    /// [`Window`] label & the provided tags.
+    pub fn metric(&self, time: DateTime<Utc>, mut tags: Vec<Tag>) -> Metric {
+        tags.push(Tag::new("window", self.window.label()));
+
+        Metric {
+            name: "rolling_statistics",
+            time: time.timestamp_millis().max(0) as u64,
+            tags,
+            fields: vec![
+                Field::new("trades", self.summary.count),
+                Field::new("mean_return", self.summary.mean),
+                Field::new("volatility", self.volatility()),
+                Field::new("sharpe_ratio", self.sharpe_ratio()),
+                Field::new("win_rate", self.win_rate()),
+                Field::new("max_drawdown", self.max_drawdown),
+                Field::new("current_drawdown", self.current_drawdown),
+            ],
+        }
+    }
+}
+
+impl TableBuilder for RollingSummary {
+    fn titles(&self) -> Row {
+        row![
+            "Window",
+            "Trades",
+            "Mean Return",
+            "Volatility",
+            "Sharpe Ratio",
+            "Win Rate",
+            "Max Drawdown",
+            "Current Drawdown",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.window.label(),
+            self.summary.count,
+            format!("{:.3}", self.summary.mean),
+            format!("{:.3}", self.volatility()),
+            format!("{:.3}", self.sharpe_ratio()),
+            format!("{:.3}", self.win_rate()),
+            format!("{:.3}", self.max_drawdown),
+            format!("{:.3}", self.current_drawdown),
+        ]
+    }
+}
+
+/// Maintains a [`RollingSummary`] for every configured [`Window`], publishing their latest
+/// statistics as [`Metric`]s via a [`MetricTx`] every time a [`Position`] is exited.
+#[derive(Debug)]
+pub struct RollingMonitor {
+    engine_id: Uuid,
+    summaries: Vec<RollingSummary>,
+    metric_tx: MetricTx,
+}
+
+impl RollingMonitor {
+    /// Constructs a new [`RollingMonitor`] with a [`RollingSummary`] for every provided
+    /// [`Config`].
+    pub fn new(
+        engine_id: Uuid,
+        configs: &[Config],
+        metric_tx: MetricTx,
+    ) -> Result<Self, StatisticError> {
+        Ok(Self {
+            engine_id,
+            summaries: configs
+                .iter()
+                .map(|config| RollingSummary::new(*config))
+                .collect::<Result<Vec<_>, _>>()?,
+            metric_tx,
+        })
+    }
+
+    /// Updates every [`RollingSummary`] with an exited [`Position`] & publishes the resulting
+    /// [`Metric`]s. Open [`Position`]s are ignored.
+    pub fn update(&mut self, position: &Position) {
+        let Some(exit_balance) = position.meta.exit_balance else {
+            return;
+        };
+
+        let metrics = self
+            .summaries
+            .iter_mut()
+            .map(|summary| {
+                summary.update(position);
+                summary.metric(
+                    exit_balance.time,
+                    vec![Tag::new("engine_id", self.engine_id.to_string())],
+                )
+            })
+            .collect();
+
+        self.metric_tx.send_many(metrics);
+    }
+
+    /// Returns the [`RollingSummary`] of every configured [`Window`].
+    pub fn summaries(&self) -> &[RollingSummary] {
+        &self.summaries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::metric::Value;
+    use chrono::TimeZone;
+    use tokio::sync::mpsc;
+
+    fn time(hours: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hours)
+    }
+
+    fn summary(window: Window) -> RollingSummary {
+        RollingSummary::new(Config {
+            window,
+            risk_free_return: 0.0,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rolling_summary_update_return() {
+        struct TestCase {
+            input: (i64, f64),
+            expected_trades: u64,
+            expected_mean: f64,
+            expected_win_rate: f64,
+            expected_max_drawdown: f64,
+        }
+
+        let mut trades = summary(Window::Trades(2));
+        let mut duration = summary(Window::Duration(Duration::hours(2)));
+
+        // Both Windows hold the last 2 returns, since returns are exited one hour apart
+        let cases = vec![
+            // TC0: first return
+            TestCase {
+                input: (0, 0.1),
+                expected_trades: 1,
+                expected_mean: 0.1,
+                expected_win_rate: 1.0,
+                expected_max_drawdown: 0.0,
+            },
+            // TC1: loss within the Window
+            TestCase {
+                input: (1, -0.2),
+                expected_trades: 2,
+                expected_mean: -0.05,
+                expected_win_rate: 0.5,
+                expected_max_drawdown: -0.2,
+            },
+            // TC2: first return leaves the Window
+            TestCase {
+                input: (2, -0.5),
+                expected_trades: 2,
+                expected_mean: -0.35,
+                expected_win_rate: 0.0,
+                expected_max_drawdown: -0.6,
+            },
+            // TC3: previous losses leave the Window
+            TestCase {
+                input: (3, 0.3),
+                expected_trades: 2,
+                expected_mean: -0.1,
+                expected_win_rate: 0.5,
+                expected_max_drawdown: -0.5,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            for summary in [&mut trades, &mut duration] {
+                summary.update_return(time(test.input.0), test.input.1);
+
+                assert_eq!(
+                    summary.summary.count, test.expected_trades,
+                    "TC{} failed",
+                    index
+                );
+                assert!(
+                    (summary.summary.mean - test.expected_mean).abs() < 1e-10,
+                    "TC{} failed",
+                    index
+                );
+                assert!(
+                    (summary.win_rate() - test.expected_win_rate).abs() < 1e-10,
+                    "TC{} failed",
+                    index
+                );
+                assert!(
+                    (summary.max_drawdown - test.expected_max_drawdown).abs() < 1e-10,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_summary_new_empty_window() {
+        for window in [Window::Trades(0), Window::Duration(Duration::zero())] {
+            assert!(matches!(
+                RollingSummary::new(Config {
+                    window,
+                    risk_free_return: 0.0
+                }),
+                Err(StatisticError::RollingWindowEmpty)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rolling_monitor_publishes_metrics() {
+        let (metric_tx, mut metric_rx) = mpsc::unbounded_channel();
+        let configs = [
+            Config {
+                window: Window::Trades(10),
+                risk_free_return: 0.0,
+            },
+            Config {
+                window: Window::Duration(Duration::days(1)),
+                risk_free_return: 0.0,
+            },
+        ];
+
+        let mut monitor =
+            RollingMonitor::new(Uuid::new_v4(), &configs, MetricTx::new(metric_tx)).unwrap();
+
+        // Open Position is ignored
+        let mut position = crate::test_util::position();
+        monitor.update(&position);
+        assert!(metric_rx.try_recv().is_err());
+
+        // Exited Position publishes a Metric for every Window
+        position.meta.exit_balance = Some(crate::portfolio::Balance {
+            time: time(1),
+            total: 0.0,
+            available: 0.0,
+        });
+        monitor.update(&position);
+
+        let labels = [metric_rx.try_recv().unwrap(), metric_rx.try_recv().unwrap()]
+            .into_iter()
+            .map(|metric| {
+                assert_eq!(metric.name, "rolling_statistics");
+                assert_eq!(metric.time, 3_600_000);
+                assert!(metric
+                    .fields
+                    .contains(&Field::new("trades", Value::UInt(1))));
+                metric.tags.last().unwrap().value.clone()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(labels, vec!["10_trades", "86400s"]);
+        assert_eq!(monitor.summaries().len(), 2);
+    }
+}
@@ -3,8 +3,8 @@ use crate::{
     statistic::{
         metric::ratio::{CalmarRatio, Ratio, SharpeRatio, SortinoRatio},
         summary::{
-            drawdown::DrawdownSummary, pnl::PnLReturnSummary, Initialiser, PositionSummariser,
-            TableBuilder,
+            data::DataSummary, drawdown::DrawdownSummary, pnl::PnLReturnSummary, Initialiser,
+            PositionSummariser, TableBuilder,
         },
     },
 };
@@ -31,9 +31,17 @@ impl Initialiser for TradingSummary {
     type Config = Config;
 
     fn init(config: Self::Config) -> Self {
+        Self::init_at(config, Utc::now())
+    }
+
+    fn init_at(config: Self::Config, time: DateTime<Utc>) -> Self {
+        let mut drawdown = DrawdownSummary::new(config.starting_equity);
+        drawdown.current_drawdown.start_time = time;
+        drawdown.max_drawdown.drawdown.start_time = time;
+
         Self {
-            pnl_returns: PnLReturnSummary::new(),
-            drawdown: DrawdownSummary::new(config.starting_equity),
+            pnl_returns: PnLReturnSummary::init_at((), time),
+            drawdown,
             tear_sheet: TearSheet::new(config.risk_free_return),
         }
     }
@@ -107,6 +115,24 @@ impl TearSheet {
         self.calmar_ratio
             .update(pnl_returns, drawdown.max_drawdown.drawdown.drawdown);
     }
+
+    /// Updates the [`TearSheet`] ratios using a summary of returns sampled `returns_per_day`
+    /// times a day, such as the fixed period returns of an
+    /// [`EquitySummary`](super::equity::EquitySummary).
+    pub fn update_from_returns(
+        &mut self,
+        returns: &DataSummary,
+        losses: &DataSummary,
+        max_drawdown: f64,
+        returns_per_day: f64,
+    ) {
+        self.sharpe_ratio
+            .update_from_returns(returns, returns_per_day);
+        self.sortino_ratio
+            .update_from_returns(returns, losses, returns_per_day);
+        self.calmar_ratio
+            .update_from_returns(returns, max_drawdown, returns_per_day);
+    }
 }
 
 impl TableBuilder for TearSheet {
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// All errors generated in the barter::strategy module.
+#[derive(Error, Debug)]
+pub enum StrategyError {
+    #[error("Strategy does not support updating it's parameters at runtime")]
+    UpdateUnsupported,
+
+    #[error("Failed to deserialise strategy parameters: {0}")]
+    Deserialise(#[from] serde_json::Error),
+
+    #[error("Invalid strategy parameters: {0}")]
+    InvalidParameters(String),
+}
@@ -1,8 +1,10 @@
-use super::{Decision, Signal, SignalGenerator, SignalStrength};
-use crate::data::MarketMeta;
+use super::{error::StrategyError, Decision, Signal, SignalGenerator, SignalStrength};
+use crate::{
+    clock::{self, SharedClock},
+    data::MarketMeta,
+};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::instrument::market_data::MarketDataInstrument;
-use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ta::{indicators::RelativeStrengthIndex, Next};
@@ -17,6 +19,7 @@ pub struct Config {
 /// Example RSI based strategy that implements [`SignalGenerator`].
 pub struct RSIStrategy {
     rsi: RelativeStrengthIndex,
+    clock: SharedClock,
 }
 
 impl SignalGenerator for RSIStrategy {
@@ -42,7 +45,7 @@ impl SignalGenerator for RSIStrategy {
         }
 
         Some(Signal {
-            time: Utc::now(),
+            time: self.clock.time(),
             exchange: market.exchange,
             instrument: market.instrument.clone(),
             market_meta: MarketMeta {
@@ -52,6 +55,15 @@ impl SignalGenerator for RSIStrategy {
             signals,
         })
     }
+
+    /// Replaces the RSI indicator using the provided [`Config`] parameters. Note that the new
+    /// indicator must warm up again before generating meaningful [`Signal`]s.
+    fn update_parameters(&mut self, parameters: serde_json::Value) -> Result<(), StrategyError> {
+        let config = serde_json::from_value::<Config>(parameters)?;
+        self.rsi = RelativeStrengthIndex::new(config.rsi_period)
+            .map_err(|error| StrategyError::InvalidParameters(error.to_string()))?;
+        Ok(())
+    }
 }
 
 impl RSIStrategy {
@@ -60,7 +72,15 @@ impl RSIStrategy {
         let rsi_indicator = RelativeStrengthIndex::new(config.rsi_period)
             .expect("Failed to construct RSI indicator");
 
-        Self { rsi: rsi_indicator }
+        Self {
+            rsi: rsi_indicator,
+            clock: clock::live(),
+        }
+    }
+
+    /// Replaces the [`SharedClock`] used to timestamp generated [`Signal`]s.
+    pub fn with_clock(self, clock: SharedClock) -> Self {
+        Self { clock, ..self }
     }
 
     /// Given the latest RSI value for a symbol, generates a map containing the [`SignalStrength`] for
@@ -93,3 +113,41 @@ impl RSIStrategy {
         SignalStrength(1.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_rsi_strategy_update_parameters() {
+        struct TestCase {
+            input: serde_json::Value,
+            expected: fn(&Result<(), StrategyError>) -> bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid rsi_period
+                input: json!({ "rsi_period": 7 }),
+                expected: |result| matches!(result, Ok(())),
+            },
+            TestCase {
+                // TC1: rsi_period of zero is rejected by the indicator
+                input: json!({ "rsi_period": 0 }),
+                expected: |result| matches!(result, Err(StrategyError::InvalidParameters(_))),
+            },
+            TestCase {
+                // TC2: parameters that do not deserialise to a Config
+                input: json!({ "period": 7 }),
+                expected: |result| matches!(result, Err(StrategyError::Deserialise(_))),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut strategy = RSIStrategy::new(Config { rsi_period: 14 });
+            let actual = strategy.update_parameters(test.input);
+            assert!((test.expected)(&actual), "TC{} failed: {:?}", index, actual);
+        }
+    }
+}
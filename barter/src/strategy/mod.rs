@@ -1,12 +1,13 @@
-use crate::data::MarketMeta;
+use crate::{data::MarketMeta, strategy::error::StrategyError};
 use barter_data::event::{DataKind, MarketEvent};
-use barter_instrument::{
-    exchange::ExchangeId, instrument::market_data::MarketDataInstrument, market::Market,
-};
+use barter_instrument::{exchange::ExchangeId, instrument::market_data::MarketDataInstrument};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Barter strategy module specific errors.
+pub mod error;
+
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
@@ -17,6 +18,13 @@ pub trait SignalGenerator {
         &mut self,
         market: &MarketEvent<MarketDataInstrument, DataKind>,
     ) -> Option<Signal>;
+
+    /// Update the strategy parameters at runtime (eg/ via a remote
+    /// [`Command::UpdateStrategy`](crate::engine::Command::UpdateStrategy)). By default, runtime
+    /// updates are unsupported.
+    fn update_parameters(&mut self, _parameters: serde_json::Value) -> Result<(), StrategyError> {
+        Err(StrategyError::UpdateUnsupported)
+    }
 }
 
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
@@ -81,27 +89,19 @@ pub struct SignalForceExit {
     pub instrument: MarketDataInstrument,
 }
 
-impl<M> From<M> for SignalForceExit
-where
-    M: Into<Market>,
-{
-    fn from(market: M) -> Self {
-        let market = market.into();
-        Self::new(market.exchange, market.instrument)
-    }
-}
-
 impl SignalForceExit {
     pub const FORCED_EXIT_SIGNAL: &'static str = "SignalForcedExit";
 
-    /// Constructs a new [`Self`] using the configuration provided.
-    pub fn new<E, I>(exchange: E, instrument: I) -> Self
+    /// Constructs a new [`Self`] using the configuration provided, stamped with the provided
+    /// time (eg/ from the [`Trader`](crate::engine::trader::Trader)'s
+    /// [`Clock`](crate::clock::Clock)).
+    pub fn new<E, I>(time: DateTime<Utc>, exchange: E, instrument: I) -> Self
     where
         E: Into<ExchangeId>,
         I: Into<MarketDataInstrument>,
     {
         Self {
-            time: Utc::now(),
+            time,
             exchange: exchange.into(),
             instrument: instrument.into(),
         }
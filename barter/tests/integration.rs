@@ -1,29 +1,42 @@
 use barter::{
-    data::historical,
-    engine::{trader::Trader, Engine},
-    event::EventTx,
+    clock::HistoricalClock,
+    data::{historical, live, MarketMeta},
+    engine::{
+        policy::{ErrorAction, ErrorPolicy, OpenOrderPolicy, Retry},
+        trader::{NewTrader, Trader},
+        Command, Engine,
+    },
+    event::{Event, EventTx},
     execution::{
+        error::ExecutionError,
         simulated::{Config as ExecutionConfig, SimulatedExecution},
-        Fees,
+        ExecutionClient, ExecutionEvent, Fees, FillEvent, OrderStatus,
     },
     portfolio::{
         allocator::DefaultAllocator, portfolio::MetaPortfolio,
-        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        repository::in_memory::InMemoryRepository, risk::DefaultRisk, OrderEvent,
     },
     statistic::summary::{
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
     },
-    strategy::example::{Config as StrategyConfig, RSIStrategy},
+    strategy::{
+        example::{Config as StrategyConfig, RSIStrategy},
+        Decision, Signal, SignalGenerator, SignalStrength,
+    },
     test_util::market_event_trade,
 };
+use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::{
-    exchange::ExchangeId, instrument::market_data::kind::MarketDataInstrumentKind, market::Market,
+    exchange::ExchangeId,
+    instrument::market_data::{kind::MarketDataInstrumentKind, MarketDataInstrument},
+    market::Market,
 };
 use barter_integration::Side;
+use chrono::{TimeZone, Utc};
 use parking_lot::Mutex;
 use std::{collections::HashMap, sync::Arc, time::Duration};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -122,3 +135,645 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
     )
 }
+
+/// Strategy that advises going long on every [`MarketEvent`].
+struct AlwaysLongStrategy;
+
+impl SignalGenerator for AlwaysLongStrategy {
+    fn generate_signal(
+        &mut self,
+        market: &MarketEvent<MarketDataInstrument, DataKind>,
+    ) -> Option<Signal> {
+        Some(Signal {
+            time: market.time_exchange,
+            exchange: market.exchange,
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            market_meta: MarketMeta {
+                close: 100.0,
+                time: market.time_exchange,
+            },
+        })
+    }
+}
+
+/// Execution client that fails to open every [`OrderEvent`].
+struct FailingExecution;
+
+impl ExecutionClient for FailingExecution {
+    fn open_order(&mut self, _: &OrderEvent) -> Result<Vec<ExecutionEvent>, ExecutionError> {
+        Err(ExecutionError::BuilderIncomplete("exchange unavailable"))
+    }
+}
+
+#[test]
+fn trader_emits_error_event_and_continues_when_execution_fails() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", MarketDataInstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new([
+            market_event_trade(Side::Buy),
+            market_event_trade(Side::Buy),
+        ]))
+        .strategy(AlwaysLongStrategy)
+        .execution(FailingExecution)
+        .error_policy(ErrorPolicy {
+            retry: Some(Retry {
+                max_retries: 3,
+                initial_backoff: Duration::ZERO,
+                max_backoff: Duration::ZERO,
+            }),
+            action: ErrorAction::Pause,
+            open_order: OpenOrderPolicy::default(),
+        })
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    let mut failures = 0;
+    let mut rejections = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::Error(failure) => {
+                // Opening an OrderEvent is skipped without retries by default
+                assert_eq!(failure.event_type, "OrderNew");
+                assert_eq!(failure.action, ErrorAction::Skip);
+                assert_eq!(failure.attempts, 1);
+                failures += 1;
+            }
+            Event::OrderUpdate(update) => {
+                let OrderStatus::Rejected(reason) = update.status else {
+                    panic!("expected Rejected OrderUpdate, found: {:?}", update.status);
+                };
+                assert!(reason.contains("exchange unavailable"), "{reason}");
+                rejections += 1;
+            }
+            _ => {}
+        }
+    }
+
+    // Rejected OrderEvent releases it's in-flight reservation, so the second Signal is actioned
+    assert_eq!(failures, 2);
+    assert_eq!(rejections, 2);
+    assert_eq!(portfolio.lock().in_flight_orders().count(), 0);
+}
+
+/// Execution client that fills every [`OrderEvent`] with a FillEvent the Portfolio cannot apply.
+struct MalformedFillExecution;
+
+impl ExecutionClient for MalformedFillExecution {
+    fn open_order(&mut self, order: &OrderEvent) -> Result<Vec<ExecutionEvent>, ExecutionError> {
+        Ok(vec![ExecutionEvent::Fill(FillEvent {
+            time: order.time,
+            cid: order.cid,
+            exchange: order.exchange,
+            instrument: order.instrument.clone(),
+            market_meta: order.market_meta,
+            decision: order.decision,
+            // Long entry FillEvent with a negative quantity cannot enter a Position
+            quantity: -order.quantity,
+            fill_value_gross: order.quantity.abs() * order.market_meta.close,
+            fees: Fees::default(),
+        })])
+    }
+}
+
+#[test]
+fn trader_releases_in_flight_order_when_fill_cannot_be_applied() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", MarketDataInstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new([
+            market_event_trade(Side::Buy),
+            market_event_trade(Side::Buy),
+        ]))
+        .strategy(AlwaysLongStrategy)
+        .execution(MalformedFillExecution)
+        .error_policy(ErrorPolicy {
+            retry: None,
+            ..ErrorPolicy::default()
+        })
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    let mut orders = 0;
+    let mut failures = 0;
+    let mut rejections = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::OrderNew(_) => orders += 1,
+            Event::Error(failure) => {
+                assert_eq!(failure.event_type, "Fill");
+                failures += 1;
+            }
+            Event::OrderUpdate(update) => {
+                let OrderStatus::Rejected(reason) = update.status else {
+                    panic!("expected Rejected OrderUpdate, found: {:?}", update.status);
+                };
+                assert!(reason.starts_with("failed to apply FillEvent"), "{reason}");
+                rejections += 1;
+            }
+            _ => {}
+        }
+    }
+
+    // Released InFlightOrder no longer blocks the market, so the second Signal is actioned
+    assert_eq!(orders, 2);
+    assert_eq!(failures, 2);
+    assert_eq!(rejections, 2);
+    assert_eq!(portfolio.lock().in_flight_orders().count(), 0);
+}
+
+#[test]
+fn trader_stamps_events_with_historical_clock() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", MarketDataInstrumentKind::Spot),
+    );
+
+    // HistoricalClock shared by the Trader's components, driven by each MarketEvent::time_exchange
+    let start = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+    let clock = HistoricalClock::shared(start);
+    let first_time = start + chrono::Duration::minutes(1);
+    let second_time = start + chrono::Duration::minutes(2);
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .clock(Arc::clone(&clock))
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new([first_time, second_time].map(
+            |time| MarketEvent {
+                time_exchange: time,
+                time_received: time,
+                ..market_event_trade(Side::Buy)
+            },
+        )))
+        .strategy(AlwaysLongStrategy)
+        .execution(SimulatedExecution::new(ExecutionConfig::default()))
+        .clock(Arc::clone(&clock))
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    let mut orders = 0;
+    let mut fills = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::OrderNew(order) => {
+                assert_eq!(order.time, first_time);
+                orders += 1;
+            }
+            Event::Fill(fill) => {
+                assert_eq!(fill.time, first_time);
+                fills += 1;
+            }
+            _ => {}
+        }
+    }
+
+    assert_eq!(orders, 1);
+    assert_eq!(fills, 1);
+    assert_eq!(clock.time(), second_time);
+}
+
+#[test]
+fn concurrent_traders_stamp_events_with_their_own_historical_clock() {
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+    let start = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+
+    // Each Market's MarketEvents are hours apart, so a Trader observing the other Trader's
+    // MarketEvents would stamp it's Events with the wrong time
+    let markets = [
+        (("btc", "usdt"), start + chrono::Duration::minutes(1)),
+        (("eth", "usdt"), start + chrono::Duration::hours(12)),
+    ]
+    .map(|((base, quote), time)| {
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            (base, quote, MarketDataInstrumentKind::Spot),
+        );
+        (market, time)
+    });
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.iter().map(|(market, _)| market.clone()).collect())
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .clock(HistoricalClock::shared(start))
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let mut command_txs = Vec::new();
+    let traders = markets
+        .iter()
+        .map(|(market, time)| {
+            let (command_tx, command_rx) = mpsc::channel(20);
+            command_txs.push(command_tx);
+
+            let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+                .engine_id(engine_id)
+                .market(market.clone())
+                .command_rx(command_rx)
+                .event_tx(EventTx::new(event_tx.clone()))
+                .portfolio(Arc::clone(&portfolio))
+                .data(historical::MarketFeed::new(
+                    (0..50)
+                        .map(|index| *time + chrono::Duration::seconds(index))
+                        .map(|time| MarketEvent {
+                            time_exchange: time,
+                            time_received: time,
+                            instrument: market.instrument.clone(),
+                            ..market_event_trade(Side::Buy)
+                        })
+                        .collect::<Vec<_>>(),
+                ))
+                .strategy(AlwaysLongStrategy)
+                .execution(SimulatedExecution::new(ExecutionConfig::default()))
+                .clock(HistoricalClock::shared(start))
+                .build()
+                .expect("failed to build trader");
+            trader
+        })
+        .collect::<Vec<_>>();
+
+    std::thread::scope(|scope| {
+        for trader in traders {
+            scope.spawn(move || trader.run());
+        }
+    });
+
+    let mut orders = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        if let Event::OrderNew(order) = event {
+            let (_, first_time) = markets
+                .iter()
+                .find(|(market, _)| market.instrument == order.instrument)
+                .unwrap();
+            assert_eq!(order.time, *first_time);
+            orders += 1;
+        }
+    }
+
+    assert_eq!(orders, markets.len());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn engine_run_async_trades_many_markets_on_single_thread() {
+    const NUM_MARKETS: usize = 100;
+
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+
+    let markets = (0..NUM_MARKETS)
+        .map(|index| {
+            Market::new(
+                ExchangeId::BinanceSpot,
+                (
+                    format!("coin{index}").as_str(),
+                    "usdt",
+                    MarketDataInstrumentKind::Spot,
+                ),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.clone())
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let mut traders = Vec::with_capacity(NUM_MARKETS);
+    let mut trader_command_txs = HashMap::with_capacity(NUM_MARKETS);
+    let mut market_txs = Vec::with_capacity(NUM_MARKETS);
+
+    for market in markets {
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        market_txs.push(market_tx);
+
+        traders.push(
+            Trader::builder()
+                .engine_id(engine_id)
+                .market(market.clone())
+                .command_rx(trader_command_rx)
+                .event_tx(event_tx.clone())
+                .portfolio(Arc::clone(&portfolio))
+                .data(live::MarketFeed::new(market_rx))
+                .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+                .execution(SimulatedExecution::new(ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: 0.1,
+                        slippage: 0.05,
+                        network: 0.0,
+                    },
+                }))
+                .build()
+                .expect("failed to build trader"),
+        );
+
+        trader_command_txs.insert(market, trader_command_tx);
+    }
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(traders)
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    // Feed each Trader a MarketEvent from a separate task, then finish every MarketFeed
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        for market_tx in market_txs {
+            let _ = market_tx.send(market_event_trade(Side::Buy));
+        }
+    });
+
+    // Traders blocking or busy-waiting on their MarketFeed would starve the single runtime
+    // thread, preventing the MarketEvents from ever being sent & the Engine from stopping
+    let actual = tokio::time::timeout(Duration::from_secs(5), engine.run_async()).await;
+
+    assert!(
+        actual.is_ok(),
+        "failed because async Traders prevented the runtime from making progress"
+    )
+}
+
+#[tokio::test]
+async fn engine_actions_runtime_commands() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+    let statistic_config = StatisticConfig {
+        starting_equity: 10_000.0,
+        trading_days_per_year: 365,
+        risk_free_return: 0.0,
+    };
+
+    let market_a = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", MarketDataInstrumentKind::Spot),
+    );
+    let market_b = Market::new(
+        ExchangeId::BinanceSpot,
+        ("eth", "usdt", MarketDataInstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market_a.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(statistic_config)
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let build_trader = |market: Market, command_rx, market_rx| {
+        Trader::builder()
+            .engine_id(engine_id)
+            .market(market)
+            .command_rx(command_rx)
+            .event_tx(event_tx.clone())
+            .portfolio(Arc::clone(&portfolio))
+            .data(live::MarketFeed::new(market_rx))
+            .strategy(AlwaysLongStrategy)
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees {
+                    exchange: 0.0,
+                    slippage: 0.0,
+                    network: 0.0,
+                },
+            }))
+            .build()
+            .expect("failed to build trader")
+    };
+
+    let (trader_a_command_tx, trader_a_command_rx) = mpsc::channel(10);
+    let (market_a_tx, market_a_rx) = mpsc::unbounded_channel();
+    let trader_a = build_trader(market_a.clone(), trader_a_command_rx, market_a_rx);
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(Arc::clone(&portfolio))
+        .traders(vec![trader_a])
+        .trader_command_txs(HashMap::from([(market_a.clone(), trader_a_command_tx)]))
+        .statistics_summary(TradingSummary::init(statistic_config))
+        .build()
+        .expect("failed to build engine");
+
+    let engine = tokio::spawn(engine.run_async());
+
+    // Pause market_a, and wait for the Pause to be routed by fetching the Balance
+    command_tx
+        .send(Command::Pause(market_a.clone()))
+        .await
+        .unwrap();
+    let (balance_tx, balance_rx) = oneshot::channel();
+    command_tx
+        .send(Command::FetchBalance(balance_tx))
+        .await
+        .unwrap();
+    let balance = balance_rx.await.unwrap().unwrap();
+    assert_eq!(balance.total, 10_000.0);
+
+    // Paused Trader generates no Signal for the first MarketEvent, but does once resumed
+    market_a_tx.send(market_event_trade(Side::Buy)).unwrap();
+    let mut markets_before_signal = 0;
+    loop {
+        match event_rx.recv().await.unwrap() {
+            Event::Market(_) => {
+                markets_before_signal += 1;
+                if markets_before_signal == 1 {
+                    command_tx
+                        .send(Command::Resume(market_a.clone()))
+                        .await
+                        .unwrap();
+                    let (balance_tx, balance_rx) = oneshot::channel();
+                    command_tx
+                        .send(Command::FetchBalance(balance_tx))
+                        .await
+                        .unwrap();
+                    balance_rx.await.unwrap().unwrap();
+                    market_a_tx.send(market_event_trade(Side::Buy)).unwrap();
+                }
+            }
+            Event::Signal(_) => break,
+            _ => {}
+        }
+    }
+    assert_eq!(markets_before_signal, 2);
+
+    // Add a Trader for market_b at runtime
+    portfolio
+        .lock()
+        .init_statistics([&market_b], statistic_config)
+        .unwrap();
+    let (trader_b_command_tx, trader_b_command_rx) = mpsc::channel(10);
+    let (_market_b_tx, market_b_rx) = mpsc::unbounded_channel();
+    let trader_b = build_trader(market_b.clone(), trader_b_command_rx, market_b_rx);
+    command_tx
+        .send(Command::AddTrader(NewTrader::new(
+            trader_b,
+            trader_b_command_tx.clone(),
+        )))
+        .await
+        .unwrap();
+
+    // Fetch per-market statistics
+    let (statistics_tx, statistics_rx) = oneshot::channel();
+    command_tx
+        .send(Command::FetchStatistics(market_b.clone(), statistics_tx))
+        .await
+        .unwrap();
+    let statistics = statistics_rx.await.unwrap().unwrap();
+    assert!(statistics.get("pnl_returns").is_some());
+
+    // Remove market_b Trader, which stops once it actions the Command::Terminate
+    command_tx
+        .send(Command::RemoveTrader(market_b))
+        .await
+        .unwrap();
+    tokio::time::timeout(Duration::from_secs(1), trader_b_command_tx.closed())
+        .await
+        .expect("removed Trader failed to stop");
+
+    // Engine stops once the remote Command transmitter is dropped
+    drop(command_tx);
+    tokio::time::timeout(Duration::from_secs(1), engine)
+        .await
+        .expect("Engine failed to stop")
+        .unwrap();
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "postgres")]
+
+use barter::{
+    portfolio::{
+        ledger::{ExchangeAsset, ExchangeAssetBalance},
+        repository::{
+            error::RepositoryError,
+            sql::postgres::{Config, PostgresRepository},
+            BalanceHandler, TransactionHandler,
+        },
+        Balance,
+    },
+    statistic::summary::pnl::PnLReturnSummary,
+};
+use barter_execution::model::balance::Balance as ExchangeBalance;
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Duration};
+use uuid::Uuid;
+
+/// Environment variable holding the connection string of the Postgres database used by the
+/// Postgres integration tests (eg/ `postgresql://postgres@localhost/barter`).
+const POSTGRES_URI: &str = "BARTER_TEST_POSTGRES_URI";
+
+#[tokio::test]
+async fn postgres_repository_connect_failure_within_tokio_runtime_returns_error() {
+    let config = Config {
+        uri: "postgresql://postgres@127.0.0.1:1/barter?connect_timeout=1".to_owned(),
+    };
+
+    let actual = PostgresRepository::<PnLReturnSummary>::connect(&config, Uuid::new_v4());
+    assert!(matches!(actual, Err(RepositoryError::SqlError(_))));
+}
+
+#[tokio::test]
+#[ignore = "requires a Postgres database via the BARTER_TEST_POSTGRES_URI environment variable"]
+async fn postgres_repository_persists_balances_within_tokio_runtime() {
+    let config = Config {
+        uri: std::env::var(POSTGRES_URI).expect("BARTER_TEST_POSTGRES_URI is not set"),
+    };
+    let engine_id = Uuid::new_v4();
+    let mut repository =
+        PostgresRepository::<PnLReturnSummary>::connect(&config, engine_id).unwrap();
+    assert_eq!(repository.migrate().unwrap(), 1);
+
+    let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    assert!(matches!(
+        repository.get_balance(engine_id),
+        Err(RepositoryError::ExpectedDataNotPresentError)
+    ));
+
+    // Latest Balance is the last one set, regardless of it's time
+    for balance in [
+        Balance::new(start, 1000.0, 1000.0),
+        Balance::new(start, 1010.0, 900.0),
+        Balance::new(start - Duration::minutes(1), 990.0, 990.0),
+    ] {
+        repository.set_balance(engine_id, balance).unwrap();
+        assert_eq!(repository.get_balance(engine_id).unwrap(), balance);
+    }
+
+    assert_eq!(
+        repository
+            .balance_history(
+                engine_id,
+                start - Duration::hours(1),
+                start + Duration::hours(1)
+            )
+            .unwrap(),
+        vec![
+            Balance::new(start - Duration::minutes(1), 990.0, 990.0),
+            Balance::new(start, 1000.0, 1000.0),
+            Balance::new(start, 1010.0, 900.0),
+        ]
+    );
+
+    // Asset balances are upserted by exchange & asset
+    let usdt = ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt");
+    for balance in [
+        ExchangeBalance::new(1000.0, 1000.0),
+        ExchangeBalance::new(900.0, 800.0),
+    ] {
+        repository
+            .set_asset_balance(engine_id, ExchangeAssetBalance::new(usdt.clone(), balance))
+            .unwrap();
+    }
+    let usdt_balance = ExchangeAssetBalance::new(usdt.clone(), ExchangeBalance::new(900.0, 800.0));
+    assert_eq!(
+        repository.get_asset_balances(engine_id).unwrap(),
+        vec![usdt_balance.clone()]
+    );
+
+    // Rolled back writes are discarded
+    repository.begin().unwrap();
+    repository
+        .set_balance(engine_id, Balance::new(start, 0.0, 0.0))
+        .unwrap();
+    repository
+        .set_asset_balance(
+            engine_id,
+            ExchangeAssetBalance::new(usdt, ExchangeBalance::new(0.0, 0.0)),
+        )
+        .unwrap();
+    repository.rollback().unwrap();
+    assert_eq!(
+        repository.get_balance(engine_id).unwrap(),
+        Balance::new(start - Duration::minutes(1), 990.0, 990.0)
+    );
+    assert_eq!(
+        repository.get_asset_balances(engine_id).unwrap(),
+        vec![usdt_balance]
+    );
+}
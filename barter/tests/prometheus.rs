@@ -0,0 +1,81 @@
+#![cfg(feature = "server")]
+
+use barter::{
+    clock,
+    event::{Event, MessageTransmitter, MetricTx},
+    metric::{
+        event::{EventMetrics, MeteredEventTx},
+        MetricRegistry,
+    },
+    portfolio::Balance,
+    server::prometheus::PrometheusExporter,
+    test_util,
+};
+use barter_instrument::market::Market;
+use barter_integration::metric::{Field, Metric, Tag};
+use chrono::Utc;
+use common::request;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::{net::TcpListener, sync::mpsc};
+use uuid::Uuid;
+
+mod common;
+
+#[tokio::test]
+async fn prometheus_exporter_serves_collected_metrics() {
+    let registry = MetricRegistry::new();
+    let (metric_tx, metric_rx) = mpsc::unbounded_channel();
+    let consumer = tokio::spawn(registry.clone().consume(metric_rx));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(PrometheusExporter::new(registry).serve(listener));
+
+    // RestClient latency Metric
+    metric_tx
+        .send(Metric {
+            name: "http_request_duration",
+            time: 0,
+            tags: vec![Tag::new("path", "/api/v3/order")],
+            fields: vec![Field::new("duration", 42_u64)],
+        })
+        .unwrap();
+
+    // Trader Events metered via a MeteredEventTx
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let order = test_util::order_event();
+    let mut event_tx = MeteredEventTx::new(
+        barter::event::EventTx::new(event_tx),
+        MetricTx::new(metric_tx),
+        Arc::new(Mutex::new(EventMetrics::new(Uuid::nil(), clock::live()))),
+        Market::new(order.exchange, order.instrument.clone()),
+    );
+    event_tx.send(Event::OrderNew(order));
+    event_tx.send(Event::Balance(Balance {
+        time: Utc::now(),
+        total: 1_000.0,
+        available: 800.0,
+    }));
+
+    // Wait for every Metric to be collected
+    drop(event_tx);
+    consumer.await.unwrap();
+
+    let response = request(address, "GET", "/metrics", &[], "").await;
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+    assert!(response.contains("text/plain; version=0.0.4"), "{response}");
+
+    let body = response.split("\r\n\r\n").nth(1).unwrap();
+    for expected in [
+        "# TYPE barter_http_request_duration gauge\n",
+        "barter_http_request_duration{path=\"/api/v3/order\"} 42\n",
+        "# TYPE barter_trader_events_total counter\n",
+        "barter_trader_events_total{engine_id=\"00000000-0000-0000-0000-000000000000\",\
+         exchange=\"binance_spot\",instrument=\"(eth_usdt, spot)\",kind=\"order_new\"} 1\n",
+        "barter_portfolio_equity{engine_id=\"00000000-0000-0000-0000-000000000000\"} 1000\n",
+        "barter_portfolio_available_cash{engine_id=\"00000000-0000-0000-0000-000000000000\"} 800\n",
+    ] {
+        assert!(body.contains(expected), "missing {expected:?} in {body}");
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "server")]
+
+use barter::{
+    engine::Command,
+    event::Event,
+    portfolio::Balance,
+    server::{error::ServerError, Server, TerminateRequest},
+};
+use chrono::Utc;
+use common::request;
+use futures::StreamExt;
+use tokio::{net::TcpListener, sync::mpsc};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+mod common;
+
+const BEARER_TOKEN: &str = "test-token";
+const AUTHORIZATION: (&str, &str) = ("Authorization", "Bearer test-token");
+
+#[tokio::test]
+async fn server_routes_commands_and_streams_events() {
+    let (command_tx, mut command_rx) = mpsc::channel(10);
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::new(command_tx, event_rx, BEARER_TOKEN)
+            .unwrap()
+            .serve(listener),
+    );
+
+    let balance = Balance {
+        time: Utc::now(),
+        total: 10_000.0,
+        available: 9_000.0,
+    };
+
+    // Mock Engine that actions the Commands routed by the Server
+    let (terminated_tx, mut terminated_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(command) = command_rx.recv().await {
+            match command {
+                Command::FetchBalance(balance_tx) => {
+                    balance_tx.send(Ok(balance)).unwrap();
+                }
+                Command::Terminate(message) => {
+                    terminated_tx.send(message).unwrap();
+                }
+                _ => {}
+            }
+        }
+    });
+
+    // Requests without a valid bearer token are rejected
+    let response = request(address, "GET", "/balance", &[], "").await;
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+    let invalid = [("Authorization", "Bearer invalid")];
+    let response = request(address, "POST", "/positions/exit_all", &invalid, "").await;
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+
+    // GET /balance
+    let response = request(address, "GET", "/balance", &[AUTHORIZATION], "").await;
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+    let body = response.split("\r\n\r\n").nth(1).unwrap();
+    assert_eq!(serde_json::from_str::<Balance>(body).unwrap(), balance);
+
+    // POST /terminate
+    let body = serde_json::to_string(&TerminateRequest {
+        message: "on-call".to_owned(),
+    })
+    .unwrap();
+    let response = request(address, "POST", "/terminate", &[AUTHORIZATION], &body).await;
+    assert!(response.starts_with("HTTP/1.1 202"), "{response}");
+    assert_eq!(terminated_rx.recv().await.unwrap(), "on-call");
+
+    // GET /events websocket
+    let mut events_request = format!("ws://{address}/events")
+        .into_client_request()
+        .unwrap();
+    events_request
+        .headers_mut()
+        .insert(AUTHORIZATION.0, AUTHORIZATION.1.parse().unwrap());
+    let (mut events, _) = tokio_tungstenite::connect_async(events_request)
+        .await
+        .unwrap();
+
+    // Wait for the websocket client to subscribe to the Event feed before sending an Event
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    event_tx.send(Event::Balance(balance)).unwrap();
+
+    let message = events.next().await.unwrap().unwrap();
+    let actual = serde_json::from_str::<Event>(message.to_text().unwrap()).unwrap();
+    assert_eq!(actual, Event::Balance(balance));
+}
+
+#[test]
+fn server_rejects_empty_bearer_token() {
+    let (command_tx, _command_rx) = mpsc::channel(10);
+    let (_event_tx, event_rx) = mpsc::unbounded_channel();
+
+    let actual = Server::new(command_tx, event_rx, "");
+    assert!(matches!(actual, Err(ServerError::EmptyBearerToken)));
+}